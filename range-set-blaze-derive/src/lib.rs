@@ -0,0 +1,118 @@
+//! A derive macro for [`range-set-blaze`](https://docs.rs/range-set-blaze)'s `Element` trait.
+//!
+//! Enable it with the `derive` feature of `range-set-blaze` and use it as `range_set_blaze::Element`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Index, Member};
+
+/// Derives `range_set_blaze::Element` for a `#[repr(transparent)]` struct with one field whose type
+/// implements `Element`.
+///
+/// The implementation forwards to the field's type, so the newtype has the same values, order, and
+/// length type. The struct must also implement `Copy`, `Ord`, and `Debug`.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{Element, RangeSetBlaze};
+/// # use range_set_blaze_derive::Element;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Element)]
+/// #[repr(transparent)]
+/// struct Day(i32);
+///
+/// let holidays = RangeSetBlaze::from_iter([Day(1)..=Day(3), Day(4)..=Day(4)]);
+/// assert_eq!(holidays.to_string(), "Day(1)..=Day(4)");
+/// ```
+#[proc_macro_derive(Element)]
+pub fn derive_element(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !is_repr_transparent(input)? {
+        return Err(Error::new(
+            input.ident.span(),
+            "#[derive(Element)] requires #[repr(transparent)]",
+        ));
+    }
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "#[derive(Element)] supports only structs",
+        ));
+    };
+    let mut fields = data.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(Error::new(
+            data.fields.span(),
+            "#[derive(Element)] requires exactly one field",
+        ));
+    };
+
+    let name = &input.ident;
+    let inner = &field.ty;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+    let wrap = |value: TokenStream2| match &data.fields {
+        Fields::Named(_) => quote!(Self { #member: #value }),
+        _ => quote!(Self(#value)),
+    };
+    let wrap_min = wrap(quote!(<#inner as ::range_set_blaze::Element>::min_value()));
+    let wrap_max = wrap(quote!(<#inner as ::range_set_blaze::Element>::max_value()));
+    let wrap_safe_max = wrap(quote!(<#inner as ::range_set_blaze::Element>::safe_max_value()));
+    let wrap_add_one = wrap(quote!(<#inner as ::range_set_blaze::Element>::add_one(self.#member)));
+    let wrap_sub_one = wrap(quote!(<#inner as ::range_set_blaze::Element>::sub_one(self.#member)));
+
+    Ok(quote! {
+        impl #impl_generics ::range_set_blaze::Element for #name #ty_generics #where_clause {
+            type SafeLen = <#inner as ::range_set_blaze::Element>::SafeLen;
+
+            fn safe_len(range: &::core::ops::RangeInclusive<Self>) -> Self::SafeLen {
+                <#inner as ::range_set_blaze::Element>::safe_len(
+                    &(range.start().#member..=range.end().#member),
+                )
+            }
+            fn min_value() -> Self {
+                #wrap_min
+            }
+            fn max_value() -> Self {
+                #wrap_max
+            }
+            fn safe_max_value() -> Self {
+                #wrap_safe_max
+            }
+            fn add_one(self) -> Self {
+                #wrap_add_one
+            }
+            fn sub_one(self) -> Self {
+                #wrap_sub_one
+            }
+        }
+    })
+}
+
+fn is_repr_transparent(input: &DeriveInput) -> syn::Result<bool> {
+    let mut transparent = false;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("transparent") {
+                transparent = true;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(transparent)
+}
@@ -0,0 +1,47 @@
+use range_set_blaze::{Element, RangeSetBlaze, UIntPlusOne};
+use range_set_blaze_derive::Element;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Element)]
+#[repr(transparent)]
+struct UserId(u32);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Element)]
+#[repr(transparent)]
+struct Day {
+    index: i32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Element)]
+#[repr(transparent)]
+struct Big(u128);
+
+#[test]
+fn derived_newtypes_forward_to_their_field() {
+    let set = RangeSetBlaze::from_iter([
+        UserId(3)..=UserId(5),
+        UserId(6)..=UserId(9),
+        UserId(20)..=UserId(20),
+    ]);
+    assert_eq!(
+        set.to_string(),
+        "UserId(3)..=UserId(9), UserId(20)..=UserId(20)"
+    );
+    assert_eq!(set.len(), 8);
+    assert!(set.contains(UserId(4)));
+    assert_eq!(
+        (!set).to_string(),
+        "UserId(0)..=UserId(2), UserId(10)..=UserId(19), UserId(21)..=UserId(4294967295)"
+    );
+
+    let days = RangeSetBlaze::from_iter([Day { index: -3 }, Day { index: -2 }, Day { index: 0 }]);
+    assert_eq!(days.ranges_len(), 2);
+    assert_eq!(days.first(), Some(Day { index: -3 }));
+    assert_eq!(Day::min_value(), Day { index: i32::MIN });
+    assert_eq!(Day { index: 7 }.add_one(), Day { index: 8 });
+    assert_eq!(Day { index: 7 }.sub_one(), Day { index: 6 });
+
+    // The length type is forwarded too, so `u128`'s full range carries over.
+    assert_eq!(Big::safe_max_value(), Big(u128::MAX));
+    let everything = RangeSetBlaze::from_iter([Big(0)..=Big::safe_max_value()]);
+    assert_eq!(everything.len(), UIntPlusOne::MaxPlusOne);
+}
@@ -0,0 +1,340 @@
+use alloc::collections::BTreeSet;
+use core::{
+    cmp::{max, min},
+    fmt,
+    ops::{Bound, RangeInclusive},
+};
+
+use num_traits::{One, Zero};
+
+use crate::{max_len_index::MaxLenIndex, Integer, RangeSetBlaze};
+
+/// The policy a [`RangeAllocator`] uses to choose among the free ranges that can satisfy a request.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FitPolicy {
+    /// Use the free range with the lowest start.
+    #[default]
+    FirstFit,
+    /// Use the shortest free range, breaking ties by lowest start.
+    BestFit,
+    /// Like [`FitPolicy::FirstFit`], but start looking just after the previous allocation,
+    /// wrapping around to the lowest start if needed.
+    NextFit,
+}
+
+/// Allocates ranges of integers, for example, addresses or IDs, from a free list kept in a [`RangeSetBlaze`].
+///
+/// Requests give a length and an alignment. Freed ranges are merged back into the free list,
+/// and freeing a range that is already (partly) free is reported as an error.
+///
+/// # Performance
+///
+/// Besides the free list, the allocator keeps two indexes of the free ranges: one ordered by start in which each
+/// subtree records its longest free range, and one ordered by length. With *n* free ranges, finding the first
+/// (or next) free range of at least a given length is O(log *n*), as is finding the shortest such range.
+/// Allocating and freeing are also O(log *n*).
+///
+/// With an alignment greater than `1`, a free range may be long enough but not fit once aligned. The allocator then
+/// moves on to the next candidate, at a cost of O(log *n*) for each candidate skipped. Only free ranges shorter
+/// than `len + align - 1` can be skipped, as any longer free range fits wherever it starts.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{FitPolicy, RangeAllocator, RangeSetBlaze};
+///
+/// let free = RangeSetBlaze::from_iter([0u64..=9, 100..=199, 1000..=1999]);
+/// let mut allocator = RangeAllocator::new(free, FitPolicy::FirstFit);
+///
+/// assert_eq!(allocator.allocate(5, 1), Some(0..=4));
+/// assert_eq!(allocator.allocate(50, 64), Some(128..=177));
+/// assert_eq!(allocator.allocate(500, 1), Some(1000..=1499));
+/// assert_eq!(allocator.allocate(5000, 1), None);
+/// assert_eq!(allocator.free_ranges().to_string(), "5..=9, 100..=127, 178..=199, 1500..=1999");
+///
+/// allocator.free(128..=177).unwrap();
+/// assert_eq!(allocator.free_ranges().to_string(), "5..=9, 100..=199, 1500..=1999");
+/// assert!(allocator.free(150..=160).is_err()); // double free
+/// ```
+#[derive(Clone, Debug)]
+pub struct RangeAllocator<T: Integer> {
+    free: RangeSetBlaze<T>,
+    by_start: MaxLenIndex<T, T::SafeLen>,
+    by_len: BTreeSet<(T::SafeLen, T)>,
+    policy: FitPolicy,
+    next_fit: T,
+}
+
+impl<T: Integer> RangeAllocator<T> {
+    /// Creates a [`RangeAllocator`] whose free list is `free`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{FitPolicy, RangeAllocator, RangeSetBlaze};
+    ///
+    /// let allocator = RangeAllocator::new(RangeSetBlaze::from_iter([0u32..=1023]), FitPolicy::BestFit);
+    /// assert_eq!(allocator.free_ranges().len(), 1024);
+    /// ```
+    #[must_use]
+    pub fn new(free: RangeSetBlaze<T>, policy: FitPolicy) -> Self {
+        let mut by_start = MaxLenIndex::new();
+        let mut by_len = BTreeSet::new();
+        for range in free.ranges() {
+            let len = T::safe_len(&range);
+            by_start.insert(*range.start(), len);
+            by_len.insert((len, *range.start()));
+        }
+        Self {
+            free,
+            by_start,
+            by_len,
+            policy,
+            next_fit: T::min_value(),
+        }
+    }
+
+    /// Returns the free list.
+    #[must_use]
+    pub const fn free_ranges(&self) -> &RangeSetBlaze<T> {
+        &self.free
+    }
+
+    /// Returns the [`FitPolicy`] used by [`RangeAllocator::allocate`].
+    #[must_use]
+    pub const fn policy(&self) -> FitPolicy {
+        self.policy
+    }
+
+    /// Sets the [`FitPolicy`] used by [`RangeAllocator::allocate`].
+    pub fn set_policy(&mut self, policy: FitPolicy) {
+        self.policy = policy;
+    }
+
+    /// Allocates a range of `len` integers whose start is aligned to `align`, removing it from the free list.
+    /// Returns `None` if no free range can satisfy the request.
+    ///
+    /// A start is aligned if its distance from `T::min_value()` is a multiple of `align`. For unsigned types, and for
+    /// power-of-two alignments of signed types, this is the same as the start being a multiple of `align`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` or `align` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{FitPolicy, RangeAllocator, RangeSetBlaze};
+    ///
+    /// let free = RangeSetBlaze::from_iter([0u8..=99, 150..=169]);
+    /// let mut allocator = RangeAllocator::new(free.clone(), FitPolicy::BestFit);
+    /// assert_eq!(allocator.allocate(10, 1), Some(150..=159));
+    ///
+    /// let mut allocator = RangeAllocator::new(free.clone(), FitPolicy::NextFit);
+    /// assert_eq!(allocator.allocate(10, 1), Some(0..=9));
+    /// allocator.free(0..=9).unwrap();
+    /// assert_eq!(allocator.allocate(10, 1), Some(10..=19));
+    /// ```
+    pub fn allocate(&mut self, len: T::SafeLen, align: T::SafeLen) -> Option<RangeInclusive<T>> {
+        assert!(!len.is_zero(), "len must be greater than 0");
+        assert!(!align.is_zero(), "align must be greater than 0");
+        let (gap, start) = match self.policy {
+            FitPolicy::FirstFit => self.first_fit(T::min_value(), len, align),
+            FitPolicy::BestFit => self.best_fit(len, align),
+            FitPolicy::NextFit => self
+                .first_fit(self.next_fit, len, align)
+                .or_else(|| self.first_fit(T::min_value(), len, align)),
+        }?;
+        let end = T::add_len_less_one(start, len);
+        self.remove_gap(gap.clone());
+        if *gap.start() < start {
+            self.add_gap(*gap.start()..=start - T::one());
+        }
+        if end < *gap.end() {
+            self.add_gap(end + T::one()..=*gap.end());
+        }
+        self.next_fit = if end < T::safe_max_value() {
+            end + T::one()
+        } else {
+            T::min_value()
+        };
+        Some(start..=end)
+    }
+
+    /// Returns a range to the free list, merging it with any free neighbors. An empty range is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DoubleFreeError`] and leaves the free list unchanged if any part of the range is already free.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of the range is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{FitPolicy, RangeAllocator, RangeSetBlaze};
+    ///
+    /// let mut allocator = RangeAllocator::new(RangeSetBlaze::from_iter([0u16..=99]), FitPolicy::FirstFit);
+    /// let a = allocator.allocate(10, 1).unwrap();
+    /// allocator.free(a.clone()).unwrap();
+    /// let err = allocator.free(a).unwrap_err();
+    /// assert_eq!(err.range(), 0..=9);
+    /// ```
+    pub fn free(&mut self, range: RangeInclusive<T>) -> Result<(), DoubleFreeError<T>> {
+        let (start, end) = range.into_inner();
+        assert!(
+            end <= T::safe_max_value(),
+            "end must be <= T::safe_max_value()"
+        );
+        if end < start {
+            return Ok(());
+        }
+        // The first free range that ends at or after `start`, if it starts at or before `end`, overlaps.
+        let overlap = match self.free.btree_map.range(..=start).next_back() {
+            Some((&free_start, &free_end)) if start <= free_end => Some(free_start..=free_end),
+            _ => self
+                .free
+                .btree_map
+                .range((Bound::Excluded(start), Bound::Included(end)))
+                .next()
+                .map(|(&free_start, &free_end)| free_start..=free_end),
+        };
+        if let Some(overlap) = overlap {
+            return Err(DoubleFreeError {
+                range: max(start, *overlap.start())..=min(end, *overlap.end()),
+            });
+        }
+
+        let (mut merged_start, mut merged_end) = (start, end);
+        if let Some((&prev_start, &prev_end)) = self.free.btree_map.range(..start).next_back() {
+            if prev_end + T::one() == start {
+                self.remove_gap(prev_start..=prev_end);
+                merged_start = prev_start;
+            }
+        }
+        if end < T::safe_max_value() {
+            if let Some(&next_end) = self.free.btree_map.get(&(end + T::one())) {
+                self.remove_gap(end + T::one()..=next_end);
+                merged_end = next_end;
+            }
+        }
+        self.add_gap(merged_start..=merged_end);
+        Ok(())
+    }
+
+    // Returns the free range with the lowest start at or after `from` that fits the request, and the aligned start.
+    fn first_fit(
+        &self,
+        from: T,
+        len: T::SafeLen,
+        align: T::SafeLen,
+    ) -> Option<(RangeInclusive<T>, T)> {
+        // A free range that contains `from` counts, but only its part at or after `from`.
+        if let Some((&gap_start, &gap_end)) = self.free.btree_map.range(..from).next_back() {
+            if from <= gap_end {
+                if let Some(start) = aligned_start(from..=gap_end, len, align) {
+                    return Some((gap_start..=gap_end, start));
+                }
+            }
+        }
+        let mut from = from;
+        loop {
+            let (gap_start, gap_len) = self.by_start.first_at_or_after(from, len)?;
+            let gap_end = T::add_len_less_one(gap_start, gap_len);
+            if let Some(start) = aligned_start(gap_start..=gap_end, len, align) {
+                return Some((gap_start..=gap_end, start));
+            }
+            from = gap_start.checked_add(&T::one())?;
+        }
+    }
+
+    // Returns the shortest free range (lowest start among equals) that fits the request, and the aligned start.
+    fn best_fit(&self, len: T::SafeLen, align: T::SafeLen) -> Option<(RangeInclusive<T>, T)> {
+        self.by_len
+            .range((len, T::min_value())..)
+            .find_map(|&(gap_len, gap_start)| {
+                let gap = gap_start..=T::add_len_less_one(gap_start, gap_len);
+                aligned_start(gap.clone(), len, align).map(|start| (gap, start))
+            })
+    }
+
+    fn add_gap(&mut self, gap: RangeInclusive<T>) {
+        let len = T::safe_len(&gap);
+        let (start, end) = gap.into_inner();
+        let was_there = self.free.btree_map.insert(start, end);
+        debug_assert!(was_there.is_none()); // real assert
+        self.free.len += len;
+        self.by_start.insert(start, len);
+        self.by_len.insert((len, start));
+    }
+
+    fn remove_gap(&mut self, gap: RangeInclusive<T>) {
+        let len = T::safe_len(&gap);
+        let start = *gap.start();
+        self.free.btree_map.remove(&start);
+        self.free.len -= len;
+        self.by_start.remove(start);
+        self.by_len.remove(&(len, start));
+    }
+}
+
+// Returns the first start in `gap` that is aligned and leaves room for `len` integers, if any.
+fn aligned_start<T: Integer>(
+    gap: RangeInclusive<T>,
+    len: T::SafeLen,
+    align: T::SafeLen,
+) -> Option<T> {
+    let gap_len = T::safe_len(&gap);
+    let start = *gap.start();
+    let offset = T::safe_len(&(T::min_value()..=start)) - T::SafeLen::one();
+    let remainder = offset % align;
+    let padding = if remainder.is_zero() {
+        remainder
+    } else {
+        align - remainder
+    };
+    if padding >= gap_len || gap_len - padding < len {
+        return None;
+    }
+    if padding.is_zero() {
+        Some(start)
+    } else {
+        Some(T::add_len_less_one(start, padding) + T::one())
+    }
+}
+
+/// The error type returned by [`RangeAllocator::free`] when part of the range is already free.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{FitPolicy, RangeAllocator, RangeSetBlaze};
+///
+/// let mut allocator = RangeAllocator::new(RangeSetBlaze::from_iter([10u8..=19]), FitPolicy::FirstFit);
+/// let err = allocator.free(0..=12).unwrap_err();
+/// assert_eq!(err.range(), 10..=12);
+/// assert_eq!(err.to_string(), "range 10..=12 is already free");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoubleFreeError<T: Integer> {
+    range: RangeInclusive<T>,
+}
+
+impl<T: Integer> DoubleFreeError<T> {
+    /// Returns the first part of the freed range that was already free.
+    #[must_use]
+    pub fn range(&self) -> RangeInclusive<T> {
+        self.range.clone()
+    }
+}
+
+impl<T: Integer> fmt::Display for DoubleFreeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "range {:?} is already free", self.range)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Integer> std::error::Error for DoubleFreeError<T> {}
@@ -0,0 +1,415 @@
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec::Vec,
+};
+use core::{
+    fmt,
+    iter::{FusedIterator, Zip},
+    ops::{self, RangeInclusive},
+    slice,
+};
+
+use arrow_array::{
+    types::{
+        Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+    },
+    Array, ArrayRef, ArrowNativeTypeOp, ArrowPrimitiveType, PrimitiveArray, RecordBatch,
+    StructArray,
+};
+use arrow_schema::{Field, Fields};
+
+use crate::{
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, CheckSortedDisjoint, Integer, NotIter,
+    RangeSetBlaze, SortedDisjoint, SortedStarts,
+};
+
+/// The integer types that have an Arrow primitive array type: `i8` to `i64` and `u8` to `u64`.
+pub trait ArrowInteger: Integer + ArrowNativeTypeOp {
+    /// The Arrow primitive type whose native values are `Self`, for example `UInt32Type` for `u32`.
+    type ArrowType: ArrowPrimitiveType<Native = Self>;
+}
+
+macro_rules! impl_arrow_integer {
+    ($($t:ty => $arrow_type:ty),*) => {$(
+        impl ArrowInteger for $t {
+            type ArrowType = $arrow_type;
+        }
+    )*};
+}
+
+impl_arrow_integer!(
+    i8 => Int8Type, i16 => Int16Type, i32 => Int32Type, i64 => Int64Type,
+    u8 => UInt8Type, u16 => UInt16Type, u32 => UInt32Type, u64 => UInt64Type
+);
+
+/// What [`RangeSetBlaze::from_arrow_arrays`] and [`RangeSetBlaze::from_arrow_struct_array`] do with a row
+/// whose start or end is null.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NullPolicy {
+    /// Return [`ArrowRangeError::Null`].
+    #[default]
+    Error,
+    /// Leave the row out of the set.
+    Skip,
+    /// Treat a null start as `T::min_value()` and a null end as `T::safe_max_value()`.
+    Unbounded,
+}
+
+/// The error type returned when Arrow columns can't be read as ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrowRangeError {
+    /// The start and end columns have different lengths.
+    LengthMismatch {
+        /// The length of the start column.
+        starts: usize,
+        /// The length of the end column.
+        ends: usize,
+    },
+    /// A row has a null start or end, and nulls aren't allowed.
+    Null {
+        /// The index of the row.
+        row: usize,
+    },
+    /// The record batch or struct array has no column with this name.
+    MissingColumn(String),
+    /// The column with this name doesn't hold the expected integer type.
+    ColumnType(String),
+}
+
+impl fmt::Display for ArrowRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch { starts, ends } => write!(
+                f,
+                "the start column has {starts} rows, but the end column has {ends}"
+            ),
+            Self::Null { row } => write!(f, "row {row} has a null start or end"),
+            Self::MissingColumn(name) => write!(f, "there is no column named {name:?}"),
+            Self::ColumnType(name) => {
+                write!(f, "column {name:?} doesn't hold the expected integer type")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArrowRangeError {}
+
+impl<T: ArrowInteger> RangeSetBlaze<T> {
+    /// Returns the set's ranges as a pair of Arrow arrays, one of starts and one of inclusive ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::UInt32Array;
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10u32..=20, 1..=3]);
+    /// let (starts, ends) = set.to_arrow_arrays();
+    /// assert_eq!(starts, UInt32Array::from(vec![1, 10]));
+    /// assert_eq!(ends, UInt32Array::from(vec![3, 20]));
+    /// ```
+    pub fn to_arrow_arrays(&self) -> (PrimitiveArray<T::ArrowType>, PrimitiveArray<T::ArrowType>) {
+        (
+            PrimitiveArray::from_iter_values(self.btree_map.keys().copied()),
+            PrimitiveArray::from_iter_values(self.btree_map.values().copied()),
+        )
+    }
+
+    /// Returns the set's ranges as an Arrow struct array with non-nullable `start` and `end` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::{Array, Int64Array};
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([-5i64..=5]);
+    /// let array = set.to_arrow_struct_array();
+    /// assert_eq!(array.len(), 1);
+    /// assert_eq!(array.column_names(), ["start", "end"]);
+    /// assert_eq!(array.column(1).as_ref(), &Int64Array::from(vec![5]));
+    /// ```
+    pub fn to_arrow_struct_array(&self) -> StructArray {
+        let (starts, ends) = self.to_arrow_arrays();
+        let data_type = T::ArrowType::DATA_TYPE;
+        let fields = Fields::from(Vec::from([
+            Field::new("start", data_type.clone(), false),
+            Field::new("end", data_type, false),
+        ]));
+        let columns: Vec<ArrayRef> = Vec::from([Arc::new(starts) as ArrayRef, Arc::new(ends)]);
+        StructArray::new(fields, columns, None)
+    }
+
+    /// Creates a [`RangeSetBlaze`] from Arrow arrays of starts and inclusive ends.
+    ///
+    /// The rows may be in any order and may overlap. A row whose start is greater than its end
+    /// adds nothing. Rows with a null start or end are handled according to `nulls`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowRangeError::LengthMismatch`] if the arrays have different lengths, and
+    /// [`ArrowRangeError::Null`] if a row has a null and `nulls` is [`NullPolicy::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow_array::UInt8Array;
+    /// use range_set_blaze::{ArrowRangeError, NullPolicy, RangeSetBlaze};
+    ///
+    /// let starts = UInt8Array::from(vec![Some(10), None, Some(1)]);
+    /// let ends = UInt8Array::from(vec![Some(20), Some(5), None]);
+    ///
+    /// let set = RangeSetBlaze::<u8>::from_arrow_arrays(&starts, &ends, NullPolicy::Skip)?;
+    /// assert_eq!(set.to_string(), "10..=20");
+    /// let set = RangeSetBlaze::<u8>::from_arrow_arrays(&starts, &ends, NullPolicy::Unbounded)?;
+    /// assert_eq!(set.to_string(), "0..=255");
+    /// let error = RangeSetBlaze::<u8>::from_arrow_arrays(&starts, &ends, NullPolicy::Error);
+    /// assert_eq!(error, Err(ArrowRangeError::Null { row: 1 }));
+    /// # Ok::<(), ArrowRangeError>(())
+    /// ```
+    pub fn from_arrow_arrays(
+        starts: &PrimitiveArray<T::ArrowType>,
+        ends: &PrimitiveArray<T::ArrowType>,
+        nulls: NullPolicy,
+    ) -> Result<Self, ArrowRangeError> {
+        from_rows(starts, ends, |_| true, nulls)
+    }
+
+    /// Creates a [`RangeSetBlaze`] from an Arrow struct array with `start` and `end` columns.
+    ///
+    /// It works like [`RangeSetBlaze::from_arrow_arrays`]; a null row of the struct array counts as a null
+    /// start and a null end.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowRangeError::MissingColumn`] or [`ArrowRangeError::ColumnType`] if the struct array
+    /// doesn't have `start` and `end` columns of the right type, and [`ArrowRangeError::Null`] if a row has
+    /// a null and `nulls` is [`NullPolicy::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{NullPolicy, RangeSetBlaze};
+    ///
+    /// let set = RangeSetBlaze::from_iter([1i16..=3, 100..=200]);
+    /// let array = set.to_arrow_struct_array();
+    /// assert_eq!(RangeSetBlaze::from_arrow_struct_array(&array, NullPolicy::Error), Ok(set));
+    /// ```
+    pub fn from_arrow_struct_array(
+        array: &StructArray,
+        nulls: NullPolicy,
+    ) -> Result<Self, ArrowRangeError> {
+        let starts = primitive_column::<T>(array.column_by_name("start"), "start")?;
+        let ends = primitive_column::<T>(array.column_by_name("end"), "end")?;
+        from_rows(starts, ends, |row| array.is_valid(row), nulls)
+    }
+}
+
+// Collects the rows into a set, treating a row for which `row_is_valid` is false as all nulls.
+fn from_rows<T: ArrowInteger>(
+    starts: &PrimitiveArray<T::ArrowType>,
+    ends: &PrimitiveArray<T::ArrowType>,
+    row_is_valid: impl Fn(usize) -> bool,
+    nulls: NullPolicy,
+) -> Result<RangeSetBlaze<T>, ArrowRangeError> {
+    check_lengths(starts, ends)?;
+    let mut ranges = Vec::with_capacity(starts.len());
+    for row in 0..starts.len() {
+        let row_is_valid = row_is_valid(row);
+        let start = (row_is_valid && starts.is_valid(row)).then(|| starts.value(row));
+        let end = (row_is_valid && ends.is_valid(row)).then(|| ends.value(row));
+        match (start, end, nulls) {
+            (Some(start), Some(end), _) => ranges.push(start..=end),
+            (_, _, NullPolicy::Error) => return Err(ArrowRangeError::Null { row }),
+            (_, _, NullPolicy::Skip) => {}
+            (start, end, NullPolicy::Unbounded) => ranges
+                .push(start.unwrap_or_else(T::min_value)..=end.unwrap_or_else(T::safe_max_value)),
+        }
+    }
+    Ok(RangeSetBlaze::from_iter(ranges))
+}
+
+fn check_lengths<P: ArrowPrimitiveType>(
+    starts: &PrimitiveArray<P>,
+    ends: &PrimitiveArray<P>,
+) -> Result<(), ArrowRangeError> {
+    if starts.len() == ends.len() {
+        Ok(())
+    } else {
+        Err(ArrowRangeError::LengthMismatch {
+            starts: starts.len(),
+            ends: ends.len(),
+        })
+    }
+}
+
+fn primitive_column<'a, T: ArrowInteger>(
+    column: Option<&'a ArrayRef>,
+    name: &str,
+) -> Result<&'a PrimitiveArray<T::ArrowType>, ArrowRangeError> {
+    column
+        .ok_or_else(|| ArrowRangeError::MissingColumn(name.to_string()))?
+        .as_any()
+        .downcast_ref::<PrimitiveArray<T::ArrowType>>()
+        .ok_or_else(|| ArrowRangeError::ColumnType(name.to_string()))
+}
+
+/// A [`SortedDisjoint`] iterator over the rows of a pair of Arrow start and end columns.
+///
+/// It reads the columns' value buffers in place, so ranges stored in a [`RecordBatch`] can take part in
+/// set expressions with [`RangeSetBlaze`]'s without being copied into a set first.
+///
+/// # Panics
+///
+/// Like [`CheckSortedDisjoint`], iterating panics if the rows aren't sorted, disjoint, and non-touching.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow_array::{ArrayRef, RecordBatch, UInt64Array};
+/// use range_set_blaze::{prelude::*, ArrowRangesIter};
+///
+/// let batch = RecordBatch::try_from_iter([
+///     ("lo", Arc::new(UInt64Array::from(vec![1, 10])) as ArrayRef),
+///     ("hi", Arc::new(UInt64Array::from(vec![3, 20])) as ArrayRef),
+/// ])
+/// .unwrap();
+/// let rows = ArrowRangesIter::<u64>::from_record_batch(&batch, "lo", "hi")?;
+/// let set = RangeSetBlaze::from_iter([2u64..=12]);
+/// assert_eq!((rows & set.ranges()).to_string(), "2..=3, 10..=12");
+/// # Ok::<(), range_set_blaze::ArrowRangeError>(())
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrowRangesIter<'a, T: ArrowInteger> {
+    iter: CheckSortedDisjoint<T, ArrowRows<'a, T>>,
+}
+
+struct ArrowRows<'a, T> {
+    rows: Zip<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+}
+
+impl<T: ArrowInteger> Iterator for ArrowRows<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        self.rows.next().map(|(&start, &end)| start..=end)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<'a, T: ArrowInteger> ArrowRangesIter<'a, T> {
+    /// Creates an iterator over Arrow arrays of starts and inclusive ends.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowRangeError::LengthMismatch`] if the arrays have different lengths and
+    /// [`ArrowRangeError::Null`] if either array has a null.
+    pub fn try_new(
+        starts: &'a PrimitiveArray<T::ArrowType>,
+        ends: &'a PrimitiveArray<T::ArrowType>,
+    ) -> Result<Self, ArrowRangeError> {
+        check_lengths(starts, ends)?;
+        if let Some(row) = (0..starts.len()).find(|&row| starts.is_null(row) || ends.is_null(row)) {
+            return Err(ArrowRangeError::Null { row });
+        }
+        let rows = ArrowRows {
+            rows: starts.values().iter().zip(ends.values().iter()),
+        };
+        Ok(Self {
+            iter: CheckSortedDisjoint::new(rows),
+        })
+    }
+
+    /// Creates an iterator over two columns of a [`RecordBatch`], found by name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowRangeError::MissingColumn`] or [`ArrowRangeError::ColumnType`] if the batch doesn't
+    /// have the columns with the right type, and [`ArrowRangeError::Null`] if either column has a null.
+    pub fn from_record_batch(
+        batch: &'a RecordBatch,
+        start_column: &str,
+        end_column: &str,
+    ) -> Result<Self, ArrowRangeError> {
+        Self::try_new(
+            primitive_column::<T>(batch.column_by_name(start_column), start_column)?,
+            primitive_column::<T>(batch.column_by_name(end_column), end_column)?,
+        )
+    }
+}
+
+impl<T: ArrowInteger> FusedIterator for ArrowRangesIter<'_, T> {}
+
+impl<T: ArrowInteger> Iterator for ArrowRangesIter<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: ArrowInteger> SortedStarts<T> for ArrowRangesIter<'_, T> {}
+impl<T: ArrowInteger> SortedDisjoint<T> for ArrowRangesIter<'_, T> {}
+
+impl<T: ArrowInteger> ops::Not for ArrowRangesIter<'_, T> {
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: ArrowInteger, R> ops::BitOr<R> for ArrowRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T: ArrowInteger, R> ops::BitAnd<R> for ArrowRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T: ArrowInteger, R> ops::Sub<R> for ArrowRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T: ArrowInteger, R> ops::BitXor<R> for ArrowRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
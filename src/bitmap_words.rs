@@ -0,0 +1,222 @@
+use alloc::{vec, vec::Vec};
+use core::ops::{Bound, RangeBounds, RangeInclusive};
+
+#[cfg(feature = "bitvec")]
+use bitvec::{
+    order::{BitOrder, Lsb0},
+    slice::BitSlice,
+    store::BitStore,
+    vec::BitVec,
+};
+
+use crate::{
+    elias_fano::{from_ordinal, ordinal},
+    CheckSortedDisjoint, Integer, RangeSetBlaze,
+};
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Creates a [`RangeSetBlaze`] from a packed bitmap, where bit `i` of `words[j]` (least significant
+    /// bit first) stands for the value `base + 64 * j + i`.
+    ///
+    /// Each word is scanned with `trailing_zeros` and `trailing_ones`, so the work grows with the number
+    /// of words and runs, not the number of set bits. Runs that continue across words become one range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a set bit stands for a value greater than `T::safe_max_value()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let words = [0b1110, u64::MAX << 60, 0b11];
+    /// let set = RangeSetBlaze::from_bitmap_words(&words, 100u32);
+    /// assert_eq!(set.to_string(), "101..=103, 224..=229");
+    /// ```
+    pub fn from_bitmap_words(words: &[u64], base: T) -> Self {
+        let base = ordinal(base);
+        let max = ordinal(T::safe_max_value());
+        let to_value = |offset: usize| {
+            let room = max.checked_sub(base);
+            assert!(
+                room.is_some_and(|room| offset as u128 <= room),
+                "value must be <= T::safe_max_value()"
+            );
+            from_ordinal::<T>(base + offset as u128)
+        };
+        let mut ranges: Vec<RangeInclusive<T>> = Vec::new();
+        // The start of a run that may continue into the next word.
+        let mut open: Option<usize> = None;
+        for (index, &word) in words.iter().enumerate() {
+            let mut bit = 0;
+            while bit < 64 {
+                let rest = word >> bit;
+                let zeros = rest.trailing_zeros();
+                if zeros > 0 {
+                    if let Some(start) = open.take() {
+                        ranges.push(to_value(start)..=to_value(index * 64 + bit as usize - 1));
+                    }
+                    if rest == 0 {
+                        break;
+                    }
+                    bit += zeros;
+                }
+                open.get_or_insert(index * 64 + bit as usize);
+                bit += (word >> bit).trailing_ones();
+            }
+        }
+        if let Some(start) = open {
+            ranges.push(to_value(start)..=to_value(words.len() * 64 - 1));
+        }
+        Self::from_sorted_disjoint(CheckSortedDisjoint::new(ranges))
+    }
+
+    /// Returns the values of the set that lie within `bounds` as a packed bitmap, where bit `i` of
+    /// word `j` (least significant bit first) stands for the value `start + 64 * j + i` and `start`
+    /// is the first value of `bounds`.
+    ///
+    /// The bitmap has just enough words to cover `bounds`; any bits past the end of `bounds` are zero.
+    /// Each range is written with word-level masks rather than bit by bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if `bounds` holds more values than there are bits in a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([101u32..=103, 224..=229, 1000..=1001]);
+    /// let words = set.to_bitmap_words(100..292);
+    /// assert_eq!(words, vec![0b1110, u64::MAX << 60, 0b11]);
+    /// assert_eq!(RangeSetBlaze::from_bitmap_words(&words, 100), &set & &RangeSetBlaze::from_iter([100..=291]));
+    /// ```
+    pub fn to_bitmap_words<R>(&self, bounds: R) -> Vec<u64>
+    where
+        R: RangeBounds<T>,
+    {
+        let (start, end) = bounds_to_inclusive(&bounds);
+        let base = ordinal(start);
+        let bit_len = bit_len(start, end);
+        let mut words = vec![0u64; bit_len.div_ceil(64)];
+        let bounds = CheckSortedDisjoint::from([start..=end]);
+        for range in self.ranges() & bounds {
+            // Both offsets are at most `bit_len - 1`, so they fit in a `usize`.
+            let first = (ordinal(*range.start()) - base) as usize;
+            let last = (ordinal(*range.end()) - base) as usize;
+            let (first_word, last_word) = (first / 64, last / 64);
+            let low = u64::MAX << (first % 64);
+            let high = u64::MAX >> (63 - last % 64);
+            if first_word == last_word {
+                words[first_word] |= low & high;
+            } else {
+                words[first_word] |= low;
+                words[first_word + 1..last_word].fill(u64::MAX);
+                words[last_word] |= high;
+            }
+        }
+        words
+    }
+
+    /// Creates a [`RangeSetBlaze`] from a [`bitvec::slice::BitSlice`], where bit `i` stands for the value `base + i`.
+    ///
+    /// Runs are found with `first_one` and `first_zero`, which work a word at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a set bit stands for a value greater than `T::safe_max_value()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitvec::prelude::*;
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let bits = bits![0, 1, 1, 1, 0, 0, 1];
+    /// assert_eq!(RangeSetBlaze::from_bit_slice(bits, -3i8).to_string(), "-2..=0, 3..=3");
+    /// ```
+    #[cfg(feature = "bitvec")]
+    pub fn from_bit_slice<S, O>(bits: &BitSlice<S, O>, base: T) -> Self
+    where
+        S: BitStore,
+        O: BitOrder,
+    {
+        let base = ordinal(base);
+        let max = ordinal(T::safe_max_value());
+        let to_value = |offset: usize| {
+            let room = max.checked_sub(base);
+            assert!(
+                room.is_some_and(|room| offset as u128 <= room),
+                "value must be <= T::safe_max_value()"
+            );
+            from_ordinal::<T>(base + offset as u128)
+        };
+        let mut ranges: Vec<RangeInclusive<T>> = Vec::new();
+        let mut position = 0;
+        while let Some(ones) = bits[position..].first_one() {
+            let start = position + ones;
+            let end = bits[start..]
+                .first_zero()
+                .map_or(bits.len(), |zeros| start + zeros);
+            ranges.push(to_value(start)..=to_value(end - 1));
+            position = end;
+        }
+        Self::from_sorted_disjoint(CheckSortedDisjoint::new(ranges))
+    }
+
+    /// Returns the values of the set that lie within `bounds` as a [`bitvec::vec::BitVec`], where bit `i`
+    /// stands for the value `start + i` and `start` is the first value of `bounds`.
+    ///
+    /// The bit vector's length is the number of values in `bounds`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if range `start > end`.
+    /// Panics if `bounds` holds more values than there are bits in a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bitvec::prelude::*;
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([-2i8..=0, 3..=3]);
+    /// assert_eq!(set.to_bit_vec(-3..=3), bits![0, 1, 1, 1, 0, 0, 1]);
+    /// ```
+    #[cfg(feature = "bitvec")]
+    pub fn to_bit_vec<R>(&self, bounds: R) -> BitVec<u64, Lsb0>
+    where
+        R: RangeBounds<T>,
+    {
+        let (start, end) = bounds_to_inclusive(&bounds);
+        let mut bits = BitVec::from_vec(self.to_bitmap_words(start..=end));
+        bits.truncate(bit_len(start, end));
+        bits
+    }
+}
+
+// The number of values in `start..=end`.
+fn bit_len<T: Integer>(start: T, end: T) -> usize {
+    (ordinal(end) - ordinal(start))
+        .checked_add(1)
+        .and_then(|len| usize::try_from(len).ok())
+        .expect("bounds must hold at most usize::MAX values")
+}
+
+fn bounds_to_inclusive<T: Integer, R: RangeBounds<T>>(bounds: &R) -> (T, T) {
+    let start = match bounds.start_bound() {
+        Bound::Included(n) => *n,
+        Bound::Excluded(n) => *n + T::one(),
+        Bound::Unbounded => T::min_value(),
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(n) => *n,
+        Bound::Excluded(n) => *n - T::one(),
+        Bound::Unbounded => T::safe_max_value(),
+    };
+    assert!(start <= end);
+    (start, end)
+}
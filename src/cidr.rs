@@ -0,0 +1,424 @@
+use core::{
+    fmt,
+    iter::FusedIterator,
+    net::{Ipv4Addr, Ipv6Addr},
+    ops::{BitAnd, BitOr, RangeInclusive},
+    str::FromStr,
+};
+
+#[cfg(feature = "std")]
+use crate::{Element, SortedStarts};
+use crate::{Integer, RangeSetBlaze, RangesIter, SortedDisjoint};
+
+/// The integer types that hold IP addresses: `u32` for IPv4 and `u128` for IPv6.
+pub trait CidrInteger: Integer + BitAnd<Output = Self> + BitOr<Output = Self> {
+    /// The address type, [`Ipv4Addr`] or [`Ipv6Addr`].
+    type Addr: fmt::Display + FromStr + From<Self> + Into<Self>;
+
+    /// The number of bits in an address.
+    const BITS: u32;
+
+    /// Returns the mask of the bits after the first `prefix_len` bits.
+    fn host_mask(prefix_len: u32) -> Self;
+
+    /// Returns the number of host bits of the largest CIDR block that starts at `start` and ends at or before `end`.
+    fn largest_block(start: Self, end: Self) -> u32;
+}
+
+macro_rules! impl_cidr_integer {
+    ($t:ty, $addr:ty) => {
+        impl CidrInteger for $t {
+            type Addr = $addr;
+
+            const BITS: u32 = <$t>::BITS;
+
+            fn host_mask(prefix_len: u32) -> Self {
+                <$t>::MAX.checked_shr(prefix_len).unwrap_or(0)
+            }
+
+            fn largest_block(start: Self, end: Self) -> u32 {
+                // The block is limited both by the alignment of `start` and by the length of the range.
+                let len_less_one = end - start;
+                let fits = if len_less_one == <$t>::MAX {
+                    <$t>::BITS
+                } else {
+                    (len_less_one + 1).ilog2()
+                };
+                start.trailing_zeros().min(fits)
+            }
+        }
+    };
+}
+
+impl_cidr_integer!(u32, Ipv4Addr);
+impl_cidr_integer!(u128, Ipv6Addr);
+
+/// A CIDR block: the addresses that share the first `prefix_len` bits of `network`, for example `10.0.0.0/8`.
+///
+/// `Cidr<u32>` holds IPv4 blocks and `Cidr<u128>` holds IPv6 blocks. Both display and parse in the usual
+/// `address/prefix` notation.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::Cidr;
+///
+/// let cidr: Cidr<u32> = "10.0.0.0/8".parse()?;
+/// assert_eq!(cidr.range(), 0x0A00_0000..=0x0AFF_FFFF);
+/// assert_eq!(cidr.to_string(), "10.0.0.0/8");
+///
+/// let cidr: Cidr<u128> = "2001:db8::/32".parse()?;
+/// assert_eq!(cidr.prefix_len(), 32);
+/// # Ok::<(), range_set_blaze::CidrError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cidr<T> {
+    network: T,
+    prefix_len: u32,
+}
+
+/// The error type returned when a [`Cidr`] can't be created or parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CidrError {
+    /// The text isn't an address of the right kind followed by `/` and a prefix length.
+    Syntax,
+    /// The prefix length is longer than the address.
+    PrefixTooLong {
+        /// The prefix length given.
+        prefix_len: u32,
+    },
+    /// The network address has bits set after the prefix.
+    HostBitsSet,
+}
+
+impl fmt::Display for CidrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax => write!(f, "expected an address, '/', and a prefix length"),
+            Self::PrefixTooLong { prefix_len } => {
+                write!(f, "prefix length {prefix_len} is longer than the address")
+            }
+            Self::HostBitsSet => write!(f, "the network address has bits set after the prefix"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CidrError {}
+
+/// An iterator that decomposes [`SortedDisjoint`] ranges into the fewest [`Cidr`] blocks.
+///
+/// It works through one range at a time, each time taking the largest block that starts at the
+/// range's start and fits within it. This struct is created by [`RangeSetBlaze::to_cidrs`]; use
+/// [`CidrIter::new`] to decompose any [`SortedDisjoint`] iterator.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{prelude::*, CidrIter};
+///
+/// let ranges = CheckSortedDisjoint::from([10u32..=20]);
+/// let cidrs: Vec<String> = CidrIter::new(ranges).map(|cidr| cidr.to_string()).collect();
+/// assert_eq!(cidrs, ["0.0.0.10/31", "0.0.0.12/30", "0.0.0.16/30", "0.0.0.20/32"]);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CidrIter<T, I> {
+    iter: I,
+    range: Option<RangeInclusive<T>>,
+}
+
+impl<T, I> CidrIter<T, I>
+where
+    I: SortedDisjoint<T>,
+    T: CidrInteger,
+{
+    /// Creates an iterator of the [`Cidr`] blocks that exactly cover the given ranges.
+    pub fn new<J: IntoIterator<IntoIter = I>>(ranges: J) -> Self {
+        Self {
+            iter: ranges.into_iter(),
+            range: None,
+        }
+    }
+}
+
+impl<T: CidrInteger> Cidr<T> {
+    /// Creates a CIDR block from its network address and prefix length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CidrError::PrefixTooLong`] if `prefix_len` is longer than the address, and
+    /// [`CidrError::HostBitsSet`] if `network` has bits set after the prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{Cidr, CidrError};
+    ///
+    /// assert_eq!(Cidr::new(0x0A00_0000u32, 8)?.to_string(), "10.0.0.0/8");
+    /// assert_eq!(Cidr::new(0x0A00_0001u32, 8), Err(CidrError::HostBitsSet));
+    /// assert_eq!(Cidr::new(0u32, 33), Err(CidrError::PrefixTooLong { prefix_len: 33 }));
+    /// # Ok::<(), CidrError>(())
+    /// ```
+    pub fn new(network: T, prefix_len: u32) -> Result<Self, CidrError> {
+        if prefix_len > T::BITS {
+            return Err(CidrError::PrefixTooLong { prefix_len });
+        }
+        if network & T::host_mask(prefix_len) != T::zero() {
+            return Err(CidrError::HostBitsSet);
+        }
+        Ok(Self {
+            network,
+            prefix_len,
+        })
+    }
+
+    /// Returns the network address as an integer.
+    #[must_use]
+    pub const fn network(&self) -> T {
+        self.network
+    }
+
+    /// Returns the network address, as an [`Ipv4Addr`] or [`Ipv6Addr`].
+    #[must_use]
+    pub fn network_addr(&self) -> T::Addr {
+        T::Addr::from(self.network)
+    }
+
+    /// Returns the prefix length.
+    #[must_use]
+    pub const fn prefix_len(&self) -> u32 {
+        self.prefix_len
+    }
+
+    /// Returns the addresses in the block as an inclusive range of integers.
+    #[must_use]
+    pub fn range(&self) -> RangeInclusive<T> {
+        self.network..=self.network | T::host_mask(self.prefix_len)
+    }
+}
+
+impl<T: CidrInteger> fmt::Display for Cidr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.network_addr(), self.prefix_len)
+    }
+}
+
+impl<T: CidrInteger> FromStr for Cidr<T> {
+    type Err = CidrError;
+
+    fn from_str(s: &str) -> Result<Self, CidrError> {
+        let (addr, prefix_len) = s.split_once('/').ok_or(CidrError::Syntax)?;
+        let addr: T::Addr = addr.parse().map_err(|_| CidrError::Syntax)?;
+        // Allow only plain decimal digits, as `u32::from_str` would also accept a leading `+`.
+        if prefix_len.is_empty() || !prefix_len.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(CidrError::Syntax);
+        }
+        let prefix_len = prefix_len.parse().map_err(|_| CidrError::Syntax)?;
+        Self::new(addr.into(), prefix_len)
+    }
+}
+
+impl<T, I> FusedIterator for CidrIter<T, I>
+where
+    T: CidrInteger,
+    I: SortedDisjoint<T>,
+{
+}
+
+impl<T, I> Iterator for CidrIter<T, I>
+where
+    T: CidrInteger,
+    I: SortedDisjoint<T>,
+{
+    type Item = Cidr<T>;
+
+    fn next(&mut self) -> Option<Cidr<T>> {
+        let (start, end) = match self.range.take() {
+            Some(range) => range.into_inner(),
+            None => self.iter.next()?.into_inner(),
+        };
+        let cidr = Cidr {
+            network: start,
+            prefix_len: T::BITS - T::largest_block(start, end),
+        };
+        let block_end = *cidr.range().end();
+        if block_end < end {
+            self.range = Some(block_end + T::one()..=end);
+        }
+        Some(cidr)
+    }
+}
+
+impl<T: CidrInteger> RangeSetBlaze<T> {
+    /// Returns the fewest CIDR blocks that exactly cover the set, in order.
+    ///
+    /// The blocks are computed lazily as the iterator walks the set's ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let start = u32::from(Ipv4Addr::new(10, 0, 0, 0));
+    /// let end = u32::from(Ipv4Addr::new(10, 0, 2, 255));
+    /// let set = RangeSetBlaze::from_iter([start..=end]);
+    /// let cidrs: Vec<String> = set.to_cidrs().map(|cidr| cidr.to_string()).collect();
+    /// assert_eq!(cidrs, ["10.0.0.0/23", "10.0.2.0/24"]);
+    /// ```
+    pub fn to_cidrs(&self) -> CidrIter<T, RangesIter<'_, T>> {
+        CidrIter::new(self.ranges())
+    }
+
+    /// Creates a [`RangeSetBlaze`] from CIDR blocks, which may be in any order and may overlap.
+    ///
+    /// Sets of [`Ipv4Addr`] and [`Ipv6Addr`] have a `from_cidrs` too, so name the element type, as in
+    /// `RangeSetBlaze::<u32>::from_cidrs`, when it can't be inferred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{Cidr, CidrError, RangeSetBlaze};
+    ///
+    /// let cidrs = ["10.0.0.0/24", "10.0.1.0/24", "192.168.0.0/16"]
+    ///     .iter()
+    ///     .map(|cidr| cidr.parse::<Cidr<u32>>())
+    ///     .collect::<Result<Vec<_>, CidrError>>()?;
+    /// let set = RangeSetBlaze::<u32>::from_cidrs(cidrs);
+    /// let cidrs: Vec<String> = set.to_cidrs().map(|cidr| cidr.to_string()).collect();
+    /// assert_eq!(cidrs, ["10.0.0.0/23", "192.168.0.0/16"]);
+    /// # Ok::<(), CidrError>(())
+    /// ```
+    pub fn from_cidrs<I>(cidrs: I) -> Self
+    where
+        I: IntoIterator<Item = Cidr<T>>,
+    {
+        cidrs.into_iter().map(|cidr| cidr.range()).collect()
+    }
+}
+
+/// An iterator over the ranges of a [`RangeSetBlaze`] of [`Ipv4Addr`] or [`Ipv6Addr`], as ranges of
+/// the matching integer type.
+///
+/// This struct is created by [`RangeSetBlaze::to_cidrs`] on address sets, which feeds it to a [`CidrIter`].
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AddrRangesIter<'a, T>
+where
+    T: CidrInteger,
+    T::Addr: Element,
+{
+    iter: RangesIter<'a, T::Addr>,
+}
+
+#[cfg(feature = "std")]
+impl<T> FusedIterator for AddrRangesIter<'_, T>
+where
+    T: CidrInteger,
+    T::Addr: Element,
+{
+}
+
+#[cfg(feature = "std")]
+impl<T> Iterator for AddrRangesIter<'_, T>
+where
+    T: CidrInteger,
+    T::Addr: Element,
+{
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        let (start, end) = self.iter.next()?.into_inner();
+        Some(start.into()..=end.into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+// Converting addresses to integers keeps their order, so the ranges stay sorted and disjoint.
+#[cfg(feature = "std")]
+impl<T> SortedStarts<T> for AddrRangesIter<'_, T>
+where
+    T: CidrInteger,
+    T::Addr: Element,
+{
+}
+#[cfg(feature = "std")]
+impl<T> SortedDisjoint<T> for AddrRangesIter<'_, T>
+where
+    T: CidrInteger,
+    T::Addr: Element,
+{
+}
+
+macro_rules! impl_addr_cidrs {
+    ($addr:ty, $t:ty, $example:literal) => {
+        #[cfg(feature = "std")]
+        impl RangeSetBlaze<$addr> {
+            /// Returns the fewest CIDR blocks that exactly cover the set of addresses, in order.
+            ///
+            /// The blocks are computed lazily as the iterator walks the set's ranges.
+            ///
+            /// # Examples
+            ///
+            #[doc = $example]
+            pub fn to_cidrs(&self) -> CidrIter<$t, AddrRangesIter<'_, $t>> {
+                CidrIter::new(AddrRangesIter {
+                    iter: self.ranges(),
+                })
+            }
+
+            /// Creates a set of addresses from CIDR blocks, which may be in any order and may overlap.
+            ///
+            /// See [`RangeSetBlaze::to_cidrs`] for an example.
+            pub fn from_cidrs<I>(cidrs: I) -> Self
+            where
+                I: IntoIterator<Item = Cidr<$t>>,
+            {
+                cidrs
+                    .into_iter()
+                    .map(|cidr| {
+                        let (start, end) = cidr.range().into_inner();
+                        <$addr>::from(start)..=<$addr>::from(end)
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+impl_addr_cidrs!(
+    Ipv4Addr,
+    u32,
+    r#"```
+use range_set_blaze::{Cidr, RangeSetBlaze};
+use std::net::Ipv4Addr;
+
+let set = RangeSetBlaze::from_iter([Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 0, 2, 255)]);
+let cidrs: Vec<Cidr<u32>> = set.to_cidrs().collect();
+let strings: Vec<String> = cidrs.iter().map(|cidr| cidr.to_string()).collect();
+assert_eq!(strings, ["10.0.0.0/23", "10.0.2.0/24"]);
+assert_eq!(RangeSetBlaze::<Ipv4Addr>::from_cidrs(cidrs), set);
+```"#
+);
+impl_addr_cidrs!(
+    Ipv6Addr,
+    u128,
+    r#"```
+use range_set_blaze::{Cidr, RangeSetBlaze};
+use std::net::Ipv6Addr;
+
+let start: Ipv6Addr = "2001:db8::".parse()?;
+let end: Ipv6Addr = "2001:db8::2:ffff".parse()?;
+let set = RangeSetBlaze::from_iter([start..=end]);
+let cidrs: Vec<Cidr<u128>> = set.to_cidrs().collect();
+let strings: Vec<String> = cidrs.iter().map(|cidr| cidr.to_string()).collect();
+assert_eq!(strings, ["2001:db8::/111", "2001:db8::2:0/112"]);
+assert_eq!(RangeSetBlaze::<Ipv6Addr>::from_cidrs(cidrs), set);
+# Ok::<(), std::net::AddrParseError>(())
+```"#
+);
@@ -0,0 +1,273 @@
+use core::{
+    iter::{FusedIterator, Peekable},
+    ops::Bound,
+};
+
+use alloc::collections::btree_map;
+
+use crate::{Integer, RangeSetBlaze};
+
+// How many ranges the galloping mode steps over one at a time before it jumps with a tree lookup instead.
+const GALLOP_STEPS: usize = 4;
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Answers [`RangeSetBlaze::contains`] for each of a sorted sequence of values.
+    ///
+    /// # Performance
+    ///
+    /// The answers come from one merge-walk over the ranges, so *m* queries on a set of *n* ranges take
+    /// O(*n* + *m*) time, rather than O(*m* log *n*). When the queries are sparse relative to the ranges,
+    /// call [`ContainsSorted::galloping`] to skip ahead with tree lookups instead.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the values are not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// let answers: Vec<_> = set.contains_sorted([5, 10, 19, 20, 35, 35, 40]).collect();
+    /// assert_eq!(answers, vec![false, true, true, false, true, true, false]);
+    /// ```
+    pub fn contains_sorted<I>(&self, values: I) -> ContainsSorted<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        ContainsSorted {
+            values: values.into_iter(),
+            walk: MembershipWalk::new(self),
+        }
+    }
+
+    /// Keeps the values of a sorted sequence that are in the set.
+    /// See [`RangeSetBlaze::contains_sorted`] for performance.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the values are not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let blocked = RangeSetBlaze::from_iter([100u32..=199, 500..=599]);
+    /// let ids = [50, 150, 160, 300, 599, 600];
+    /// let hits: Vec<_> = blocked.filter_members(ids).collect();
+    /// assert_eq!(hits, vec![150, 160, 599]);
+    /// ```
+    pub fn filter_members<I>(&self, values: I) -> FilterMembers<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        FilterMembers {
+            values: values.into_iter(),
+            walk: MembershipWalk::new(self),
+        }
+    }
+
+    /// Keeps the values of a sorted sequence that are *not* in the set.
+    /// See [`RangeSetBlaze::contains_sorted`] for performance.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the values are not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let blocked = RangeSetBlaze::from_iter([100u32..=199, 500..=599]);
+    /// let ids = [50, 150, 160, 300, 599, 600];
+    /// let misses: Vec<_> = blocked.filter_non_members(ids).galloping().collect();
+    /// assert_eq!(misses, vec![50, 300, 600]);
+    /// ```
+    pub fn filter_non_members<I>(&self, values: I) -> FilterNonMembers<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        FilterNonMembers {
+            values: values.into_iter(),
+            walk: MembershipWalk::new(self),
+        }
+    }
+}
+
+// Answers membership for increasing values by walking the ranges once.
+#[derive(Clone, Debug)]
+struct MembershipWalk<'a, T: Integer> {
+    set: &'a RangeSetBlaze<T>,
+    // The last range that starts at or before the previous value.
+    current: Option<(T, T)>,
+    rest: Peekable<btree_map::Range<'a, T, T>>,
+    prev_value: Option<T>,
+    galloping: bool,
+}
+
+impl<'a, T: Integer> MembershipWalk<'a, T> {
+    fn new(set: &'a RangeSetBlaze<T>) -> Self {
+        Self {
+            set,
+            current: None,
+            rest: set.btree_map.range(..).peekable(),
+            prev_value: None,
+            galloping: false,
+        }
+    }
+
+    fn contains(&mut self, value: T) -> bool {
+        if let Some(prev_value) = self.prev_value {
+            assert!(prev_value <= value, "values must be sorted");
+        }
+        self.prev_value = Some(value);
+        let mut steps = 0;
+        while let Some((&start, &end)) = self.rest.next_if(|(&start, _)| start <= value) {
+            self.current = Some((start, end));
+            steps += 1;
+            if self.galloping && steps == GALLOP_STEPS {
+                self.jump_to(value);
+                break;
+            }
+        }
+        matches!(self.current, Some((_, end)) if value <= end)
+    }
+
+    // Re-seats the walk at `value` with two tree lookups.
+    fn jump_to(&mut self, value: T) {
+        let btree_map = &self.set.btree_map;
+        if let Some((&start, &end)) = btree_map.range(..=value).next_back() {
+            self.current = Some((start, end));
+        }
+        self.rest = btree_map
+            .range((Bound::Excluded(value), Bound::Unbounded))
+            .peekable();
+    }
+}
+
+/// An iterator that answers [`RangeSetBlaze::contains`] for each of a sorted sequence of values.
+///
+/// This `struct` is created by [`RangeSetBlaze::contains_sorted`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ContainsSorted<'a, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    values: I,
+    walk: MembershipWalk<'a, T>,
+}
+
+/// An iterator over the values of a sorted sequence that are in a [`RangeSetBlaze`].
+///
+/// This `struct` is created by [`RangeSetBlaze::filter_members`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterMembers<'a, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    values: I,
+    walk: MembershipWalk<'a, T>,
+}
+
+/// An iterator over the values of a sorted sequence that are not in a [`RangeSetBlaze`].
+///
+/// This `struct` is created by [`RangeSetBlaze::filter_non_members`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterNonMembers<'a, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    values: I,
+    walk: MembershipWalk<'a, T>,
+}
+
+macro_rules! impl_galloping {
+    ($name:ident) => {
+        impl<'a, T, I> $name<'a, T, I>
+        where
+            T: Integer,
+            I: Iterator<Item = T>,
+        {
+            /// Switches to galloping mode, which suits values that are sparse relative to the ranges.
+            ///
+            /// Rather than stepping over every range between two consecutive values, the iterator
+            /// steps over a few and then jumps ahead with a tree lookup, so each value costs at most
+            /// O(log *n*), where *n* is the number of ranges.
+            pub fn galloping(mut self) -> Self {
+                self.walk.galloping = true;
+                self
+            }
+        }
+
+        impl<T, I> FusedIterator for $name<'_, T, I>
+        where
+            T: Integer,
+            I: Iterator<Item = T> + FusedIterator,
+        {
+        }
+    };
+}
+
+impl_galloping!(ContainsSorted);
+impl_galloping!(FilterMembers);
+impl_galloping!(FilterNonMembers);
+
+impl<T, I> Iterator for ContainsSorted<'_, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        let value = self.values.next()?;
+        Some(self.walk.contains(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T, I> Iterator for FilterMembers<'_, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let walk = &mut self.walk;
+        self.values.find(|&value| walk.contains(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.values.size_hint().1)
+    }
+}
+
+impl<T, I> Iterator for FilterNonMembers<'_, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let walk = &mut self.walk;
+        self.values.find(|&value| !walk.contains(value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.values.size_hint().1)
+    }
+}
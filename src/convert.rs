@@ -0,0 +1,232 @@
+use core::{
+    cmp::{max, min},
+    fmt,
+    ops::RangeInclusive,
+};
+
+use num_traits::NumCast;
+
+use crate::{CheckSortedDisjoint, Integer, RangeSetBlaze, SortedDisjoint};
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Converts a [`RangeSetBlaze`] to a [`RangeSetBlaze`] of a wider integer type, for example,
+    /// from `u32` to `u64` or from `u8` to `i16`.
+    ///
+    /// The conversion is lossless, so the compiler only allows it when `U` implements `From<T>`.
+    /// Also see [`RangeSetBlaze::try_convert`], [`RangeSetBlaze::clamp_convert`] and
+    /// [`RangeSetBlaze::bias_convert`].
+    ///
+    /// # Performance
+    ///
+    /// The conversion is range-wise, so it is O(*n*) in the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1u32..=3, 4_000_000_000..=u32::MAX]);
+    /// let b: RangeSetBlaze<u64> = a.convert();
+    /// assert_eq!(b.to_string(), "1..=3, 4000000000..=4294967295");
+    /// assert_eq!(b.len(), a.len() as u128);
+    /// ```
+    pub fn convert<U>(&self) -> RangeSetBlaze<U>
+    where
+        U: Integer + From<T>,
+    {
+        CheckSortedDisjoint::new(self.ranges().map(|range| {
+            let (start, end) = range.into_inner();
+            <U as From<T>>::from(start)..=<U as From<T>>::from(end)
+        }))
+        .into_range_set_blaze()
+    }
+
+    /// Converts a [`RangeSetBlaze`] to a [`RangeSetBlaze`] of another integer type, returning an error
+    /// if any integer can't be represented in the new type.
+    ///
+    /// Also see [`RangeSetBlaze::convert`] and [`RangeSetBlaze::clamp_convert`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`TryConvertError`] describing the first range that contains an integer that is
+    /// out of range for `U` (or above `U`'s [`Element::safe_max_value`](crate::Element::safe_max_value)).
+    ///
+    /// # Performance
+    ///
+    /// The conversion is range-wise, so it is O(*n*) in the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1u64..=3, 100..=200]);
+    /// let b = a.try_convert::<u8>().unwrap();
+    /// assert_eq!(b.to_string(), "1..=3, 100..=200");
+    ///
+    /// let a = RangeSetBlaze::from_iter([-5i32..=3, 100..=200]);
+    /// let err = a.try_convert::<u8>().unwrap_err();
+    /// assert_eq!(err.range(), -5..=3);
+    /// ```
+    pub fn try_convert<U: Integer>(&self) -> Result<RangeSetBlaze<U>, TryConvertError<T>> {
+        let mut ranges = alloc::vec::Vec::with_capacity(self.ranges_len());
+        for range in self.ranges() {
+            let (start, end) = range.clone().into_inner();
+            match (<U as NumCast>::from(start), <U as NumCast>::from(end)) {
+                (Some(start), Some(end)) if end <= U::safe_max_value() => ranges.push(start..=end),
+                _ => return Err(TryConvertError { range }),
+            }
+        }
+        Ok(CheckSortedDisjoint::new(ranges).into_range_set_blaze())
+    }
+
+    /// Converts a [`RangeSetBlaze`] to a [`RangeSetBlaze`] of another integer type, dropping
+    /// the integers that are out of range for the new type.
+    ///
+    /// In other words, the set is first clipped to `U::min_value()..=U::safe_max_value()`.
+    /// Also see [`RangeSetBlaze::convert`] and [`RangeSetBlaze::try_convert`].
+    ///
+    /// # Performance
+    ///
+    /// The conversion is range-wise, so it is O(*n*) in the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::from_iter([-5i32..=3, 100..=200, 250..=300, 1000..=1000]);
+    /// let b: RangeSetBlaze<u8> = a.clamp_convert();
+    /// assert_eq!(b.to_string(), "0..=3, 100..=200, 250..=255");
+    /// ```
+    pub fn clamp_convert<U: Integer>(&self) -> RangeSetBlaze<U> {
+        // U's bounds, expressed in T. If a bound can't be expressed in T, T's own bound is tighter.
+        let lower = <T as NumCast>::from(U::min_value()).unwrap_or_else(T::min_value);
+        let upper = <T as NumCast>::from(U::safe_max_value()).unwrap_or_else(T::safe_max_value);
+        CheckSortedDisjoint::new(self.ranges().filter_map(move |range| {
+            let (start, end) = range.into_inner();
+            let (start, end) = (max(start, lower), min(end, upper));
+            if start > end {
+                return None;
+            }
+            // These can't fail because start and end are now within U's bounds.
+            Some(<U as NumCast>::from(start)?..=<U as NumCast>::from(end)?)
+        }))
+        .into_range_set_blaze()
+    }
+
+    /// Converts a [`RangeSetBlaze`] of signed integers to unsigned integers of the same size, or vice versa,
+    /// with the order-preserving mapping of [`SignBias`].
+    ///
+    /// For example, `i8::MIN` maps to `0u8`, `-1i8` maps to `127u8`, `0i8` maps to `128u8`, and `i8::MAX` maps to `u8::MAX`.
+    /// Because the mapping preserves order, ranges map to ranges.
+    ///
+    /// # Performance
+    ///
+    /// The conversion is range-wise, so it is O(*n*) in the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::from_iter([-128i8..=-127, -1..=1]);
+    /// let b = a.bias_convert();
+    /// assert_eq!(b.to_string(), "0..=1, 127..=129");
+    /// assert_eq!(b.bias_convert(), a);
+    /// ```
+    pub fn bias_convert(&self) -> RangeSetBlaze<T::Biased>
+    where
+        T: SignBias,
+    {
+        CheckSortedDisjoint::new(self.ranges().map(|range| {
+            let (start, end) = range.into_inner();
+            start.bias()..=end.bias()
+        }))
+        .into_range_set_blaze()
+    }
+}
+
+/// The error type returned by [`RangeSetBlaze::try_convert`] when some integers can't be represented in the target type.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::RangeSetBlaze;
+///
+/// let a = RangeSetBlaze::from_iter([1u16..=3, 200..=300]);
+/// let err = a.try_convert::<u8>().unwrap_err();
+/// assert_eq!(err.range(), 200..=300);
+/// assert_eq!(err.to_string(), "range 200..=300 can't be represented in the target type");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryConvertError<T: Integer> {
+    range: RangeInclusive<T>,
+}
+
+impl<T: Integer> TryConvertError<T> {
+    /// Returns the first range that contains an integer that can't be represented in the target type.
+    #[must_use]
+    pub fn range(&self) -> RangeInclusive<T> {
+        self.range.clone()
+    }
+}
+
+impl<T: Integer> fmt::Display for TryConvertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "range {:?} can't be represented in the target type",
+            self.range
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Integer> std::error::Error for TryConvertError<T> {}
+
+/// An order-preserving mapping between a signed integer type and the unsigned integer type of the same size.
+///
+/// The mapping flips the sign bit, so `T::min_value()` maps to `0` and `T::max_value()` maps to the unsigned maximum.
+/// Applying it twice gives back the original value. See [`RangeSetBlaze::bias_convert`].
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::SignBias;
+///
+/// assert_eq!(i16::MIN.bias(), 0u16);
+/// assert_eq!(0i16.bias(), 32768u16);
+/// assert_eq!(32768u16.bias(), 0i16);
+/// ```
+pub trait SignBias: Integer {
+    /// The integer type of the same size, but with the other signedness.
+    type Biased: Integer + SignBias<Biased = Self>;
+
+    /// Maps a value to [`SignBias::Biased`], preserving order.
+    fn bias(self) -> Self::Biased;
+}
+
+macro_rules! impl_sign_bias {
+    ($($signed:ty => $unsigned:ty),*) => {$(
+        impl SignBias for $signed {
+            type Biased = $unsigned;
+
+            #[inline]
+            fn bias(self) -> $unsigned {
+                (self as $unsigned) ^ (1 << (<$unsigned>::BITS - 1))
+            }
+        }
+
+        impl SignBias for $unsigned {
+            type Biased = $signed;
+
+            #[inline]
+            fn bias(self) -> $signed {
+                (self ^ (1 << (<$unsigned>::BITS - 1))) as $signed
+            }
+        }
+    )*};
+}
+
+impl_sign_bias!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
@@ -0,0 +1,371 @@
+use core::ops::{Bound, RangeInclusive};
+
+use alloc::collections::{btree_map, BTreeMap};
+
+use crate::{Integer, RangeSetBlaze};
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Returns a [`Cursor`] positioned at the range containing `value` or, if no range contains it,
+    /// at the first range after `value`. If there is no such range, the cursor is at the "ghost" position
+    /// past the last range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([1..=3, 10..=12, 20..=22]);
+    /// let mut cursor = set.cursor_at(5);
+    /// assert_eq!(cursor.peek_range(), Some(10..=12));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_range(), Some(20..=22));
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_range(), None); // the ghost position
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_range(), Some(1..=3));
+    /// ```
+    pub fn cursor_at(&self, value: T) -> Cursor<'_, T> {
+        let current = start_at(&self.btree_map, value).map(|start| (start, self.btree_map[&start]));
+        Cursor {
+            set: self,
+            current,
+            after: None,
+            before: None,
+        }
+    }
+
+    /// Returns a [`CursorMut`] positioned at the range containing `value` or, if no range contains it,
+    /// at the first range after `value`. If there is no such range, the cursor is at the "ghost" position
+    /// past the last range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// // Take 3 integers from each range that starts at 10 or later.
+    /// let mut set = RangeSetBlaze::from_iter([1..=3, 10..=12, 20..=29]);
+    /// let mut taken = RangeSetBlaze::new();
+    /// let mut cursor = set.cursor_mut_at(10);
+    /// while let Some(range) = cursor.peek_range() {
+    ///     if range.end() - range.start() < 3 {
+    ///         taken.ranges_insert(cursor.remove_current_range().unwrap());
+    ///     } else {
+    ///         taken.ranges_insert(cursor.split_current(range.start() + 3).unwrap());
+    ///         cursor.move_next();
+    ///     }
+    /// }
+    /// assert_eq!(set.to_string(), "1..=3, 23..=29");
+    /// assert_eq!(taken.to_string(), "10..=12, 20..=22");
+    /// ```
+    pub fn cursor_mut_at(&mut self, value: T) -> CursorMut<'_, T> {
+        CursorMut {
+            current: start_at(&self.btree_map, value),
+            set: self,
+        }
+    }
+}
+
+/// A cursor over the ranges of a [`RangeSetBlaze`].
+///
+/// A cursor is always at one of the ranges or at a "ghost" position past the last range and before
+/// the first range. Moving past the last range goes to the ghost position, and moving again goes
+/// to the first range.
+///
+/// Created with [`RangeSetBlaze::cursor_at`]. See it for an example.
+///
+/// # Performance
+///
+/// The cursor keeps its range and an iterator over the underlying [`BTreeMap`], so moving repeatedly in one
+/// direction is amortized O(1). The first move after changing direction, or after wrapping around the ghost
+/// position, is an O(log *n*) seek, where *n* is the number of ranges.
+///
+/// [`BTreeMap`]: alloc::collections::BTreeMap
+#[derive(Clone, Debug)]
+pub struct Cursor<'a, T: Integer> {
+    set: &'a RangeSetBlaze<T>,
+    current: Option<(T, T)>,
+    // The ranges after `current`, if the last move was forward.
+    after: Option<btree_map::Range<'a, T, T>>,
+    // The ranges before `current`, if the last move was backward.
+    before: Option<btree_map::Range<'a, T, T>>,
+}
+
+impl<'a, T: Integer> Cursor<'a, T> {
+    /// Returns the range at the cursor, or `None` if the cursor is at the ghost position.
+    #[must_use]
+    pub fn peek_range(&self) -> Option<RangeInclusive<T>> {
+        self.current.map(|(start, end)| start..=end)
+    }
+
+    /// Moves the cursor to the next range. If the cursor is at the last range, it moves to the ghost
+    /// position. If it is at the ghost position, it moves to the first range.
+    pub fn move_next(&mut self) {
+        let map = &self.set.btree_map;
+        let current = self.current.map(|(start, _)| start);
+        let mut after = self.after.take().unwrap_or_else(|| match current {
+            Some(current) => map.range((Bound::Excluded(current), Bound::Unbounded)),
+            None => map.range(..),
+        });
+        self.current = after.next().map(|(start, end)| (*start, *end));
+        self.after = self.current.is_some().then_some(after);
+        self.before = None;
+    }
+
+    /// Moves the cursor to the previous range. If the cursor is at the first range, it moves to the ghost
+    /// position. If it is at the ghost position, it moves to the last range.
+    pub fn move_prev(&mut self) {
+        let map = &self.set.btree_map;
+        let current = self.current.map(|(start, _)| start);
+        let mut before = self.before.take().unwrap_or_else(|| match current {
+            Some(current) => map.range(..current),
+            None => map.range(..),
+        });
+        self.current = before.next_back().map(|(start, end)| (*start, *end));
+        self.before = self.current.is_some().then_some(before);
+        self.after = None;
+    }
+}
+
+/// A cursor over the ranges of a [`RangeSetBlaze`] that can also edit the set.
+///
+/// Edits keep the ranges sorted, disjoint, and non-adjacent, and keep [`RangeSetBlaze::len`] up to date.
+/// Like [`Cursor`], a [`CursorMut`] is always at one of the ranges or at a "ghost" position past the
+/// last range and before the first range.
+///
+/// Created with [`RangeSetBlaze::cursor_mut_at`]. See it for an example.
+///
+/// # Performance
+///
+/// Each move or edit is an O(log *n*) seek in the underlying [`BTreeMap`], where *n* is the number of ranges.
+/// The standard [`BTreeMap`] has no stable cursor that can edit the map, so, unlike [`Cursor`], a
+/// [`CursorMut`] seeks its place again on every move.
+///
+/// [`BTreeMap`]: alloc::collections::BTreeMap
+#[derive(Debug)]
+pub struct CursorMut<'a, T: Integer> {
+    set: &'a mut RangeSetBlaze<T>,
+    current: Option<T>,
+}
+
+impl<'a, T: Integer> CursorMut<'a, T> {
+    /// Returns the range at the cursor, or `None` if the cursor is at the ghost position.
+    #[must_use]
+    pub fn peek_range(&self) -> Option<RangeInclusive<T>> {
+        peek(&self.set.btree_map, self.current)
+    }
+
+    /// Moves the cursor to the next range. If the cursor is at the last range, it moves to the ghost
+    /// position. If it is at the ghost position, it moves to the first range.
+    pub fn move_next(&mut self) {
+        self.current = next_start(&self.set.btree_map, self.current);
+    }
+
+    /// Moves the cursor to the previous range. If the cursor is at the first range, it moves to the ghost
+    /// position. If it is at the ghost position, it moves to the last range.
+    pub fn move_prev(&mut self) {
+        self.current = prev_start(&self.set.btree_map, self.current);
+    }
+
+    /// Inserts a range between the previous range and the range at the cursor. At the ghost position,
+    /// inserts it after the last range.
+    ///
+    /// The new range may touch its neighbors, in which case they are merged. The cursor stays at the
+    /// (possibly merged) range it was at. An empty range is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range overlaps the previous range or the range at the cursor, or if its end
+    /// is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let mut set = RangeSetBlaze::from_iter([1..=3, 10..=12]);
+    /// let mut cursor = set.cursor_mut_at(10);
+    /// cursor.insert_before(6..=9);
+    /// assert_eq!(cursor.peek_range(), Some(6..=12));
+    /// assert_eq!(set.to_string(), "1..=3, 6..=12");
+    /// assert_eq!(set.len(), 10usize);
+    /// ```
+    pub fn insert_before(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let map = &self.set.btree_map;
+        if let Some(current) = self.current {
+            assert!(
+                *range.end() < current,
+                "range must end before the range at the cursor"
+            );
+        }
+        if let Some(prev) = prev_start(map, self.current) {
+            assert!(
+                map[&prev] < *range.start(),
+                "range must start after the previous range"
+            );
+        }
+        self.insert(range);
+    }
+
+    /// Inserts a range between the range at the cursor and the next range. At the ghost position,
+    /// inserts it before the first range.
+    ///
+    /// The new range may touch its neighbors, in which case they are merged. The cursor stays at the
+    /// (possibly merged) range it was at. An empty range is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range overlaps the range at the cursor or the next range, or if its end
+    /// is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let mut set = RangeSetBlaze::from_iter([1..=3, 10..=12]);
+    /// let mut cursor = set.cursor_mut_at(1);
+    /// cursor.insert_after(5..=6);
+    /// cursor.move_next();
+    /// assert_eq!(cursor.peek_range(), Some(5..=6));
+    /// assert_eq!(set.to_string(), "1..=3, 5..=6, 10..=12");
+    /// ```
+    pub fn insert_after(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let map = &self.set.btree_map;
+        if let Some(current) = self.current {
+            assert!(
+                map[&current] < *range.start(),
+                "range must start after the range at the cursor"
+            );
+        }
+        if let Some(next) = next_start(map, self.current) {
+            assert!(*range.end() < next, "range must end before the next range");
+        }
+        self.insert(range);
+    }
+
+    // Inserts a range known not to overlap any other range, then finds the cursor's range again,
+    // in case the new range merged with it.
+    fn insert(&mut self, range: RangeInclusive<T>) {
+        self.set.internal_add(range);
+        if let Some(current) = self.current {
+            self.current = self
+                .set
+                .btree_map
+                .range(..=current)
+                .next_back()
+                .map(|(start, _)| *start);
+        }
+    }
+
+    /// Removes the range at the cursor and returns it. The cursor moves to the next range (or the ghost position).
+    ///
+    /// Returns `None`, and does nothing, if the cursor is at the ghost position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let mut set = RangeSetBlaze::from_iter([1..=3, 10..=12, 20..=22]);
+    /// let mut cursor = set.cursor_mut_at(10);
+    /// assert_eq!(cursor.remove_current_range(), Some(10..=12));
+    /// assert_eq!(cursor.peek_range(), Some(20..=22));
+    /// assert_eq!(set.to_string(), "1..=3, 20..=22");
+    /// assert_eq!(set.len(), 6usize);
+    /// ```
+    pub fn remove_current_range(&mut self) -> Option<RangeInclusive<T>> {
+        let start = self.current?;
+        let end = self
+            .set
+            .btree_map
+            .remove(&start)
+            .expect("real assert: the cursor is at a range");
+        self.set.len -= T::safe_len(&(start..=end));
+        self.current = next_start(&self.set.btree_map, Some(start));
+        Some(start..=end)
+    }
+
+    /// Removes the integers before `at` from the range at the cursor and returns them as a range.
+    /// The cursor stays at what remains of its range, `at..=end`.
+    ///
+    /// Returns `None`, and does nothing, if `at` is the start of the range at the cursor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cursor is at the ghost position or if `at` is not in the range at the cursor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let mut set = RangeSetBlaze::from_iter([1..=3, 10..=19]);
+    /// let mut cursor = set.cursor_mut_at(10);
+    /// assert_eq!(cursor.split_current(14), Some(10..=13));
+    /// assert_eq!(cursor.peek_range(), Some(14..=19));
+    /// assert_eq!(set.to_string(), "1..=3, 14..=19");
+    /// assert_eq!(set.len(), 9usize);
+    /// ```
+    pub fn split_current(&mut self, at: T) -> Option<RangeInclusive<T>> {
+        let start = self
+            .current
+            .expect("the cursor must be at a range, not the ghost position");
+        let end = self.set.btree_map[&start];
+        assert!(
+            start <= at && at <= end,
+            "at must be in the range at the cursor"
+        );
+        if at == start {
+            return None;
+        }
+        self.set.btree_map.remove(&start);
+        self.set.btree_map.insert(at, end);
+        let removed = start..=at - T::one();
+        self.set.len -= T::safe_len(&removed);
+        self.current = Some(at);
+        Some(removed)
+    }
+}
+
+// The start of the range containing `value` or, if none, of the first range after `value`.
+fn start_at<T: Integer>(btree_map: &BTreeMap<T, T>, value: T) -> Option<T> {
+    if let Some((start, end)) = btree_map.range(..=value).next_back() {
+        if value <= *end {
+            return Some(*start);
+        }
+    }
+    btree_map
+        .range((Bound::Excluded(value), Bound::Unbounded))
+        .next()
+        .map(|(start, _)| *start)
+}
+
+fn peek<T: Integer>(btree_map: &BTreeMap<T, T>, current: Option<T>) -> Option<RangeInclusive<T>> {
+    let start = current?;
+    Some(start..=btree_map[&start])
+}
+
+fn next_start<T: Integer>(btree_map: &BTreeMap<T, T>, current: Option<T>) -> Option<T> {
+    match current {
+        Some(current) => btree_map
+            .range((Bound::Excluded(current), Bound::Unbounded))
+            .next(),
+        None => btree_map.iter().next(),
+    }
+    .map(|(start, _)| *start)
+}
+
+fn prev_start<T: Integer>(btree_map: &BTreeMap<T, T>, current: Option<T>) -> Option<T> {
+    match current {
+        Some(current) => btree_map.range(..current).next_back(),
+        None => btree_map.iter().next_back(),
+    }
+    .map(|(start, _)| *start)
+}
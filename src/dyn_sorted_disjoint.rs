@@ -1,6 +1,6 @@
 use core::ops::RangeInclusive;
 
-use crate::{Integer, SortedDisjoint, SortedStarts};
+use crate::{Element, SortedDisjoint, SortedStarts};
 use alloc::boxed::Box;
 
 #[must_use = "iterators are lazy and do nothing unless consumed"]
@@ -26,11 +26,11 @@ use alloc::boxed::Box;
 /// assert_eq!(union.to_string(), "0..=6, 8..=9, 11..=17, 30..=255");
 /// ```
 
-pub struct DynSortedDisjoint<'a, T: Integer> {
+pub struct DynSortedDisjoint<'a, T: Element> {
     iter: Box<dyn SortedDisjoint<T> + 'a>,
 }
 
-impl<'a, T: Integer> DynSortedDisjoint<'a, T> {
+impl<'a, T: Element> DynSortedDisjoint<'a, T> {
     /// Create a [`DynSortedDisjoint`] from any [`SortedDisjoint`] iterator. See [`DynSortedDisjoint`] for an example.
     pub fn new<I>(iter: I) -> Self
     where
@@ -43,10 +43,10 @@ impl<'a, T: Integer> DynSortedDisjoint<'a, T> {
 }
 
 // All DynSortedDisjoint's are SortedDisjoint's
-impl<'a, T: Integer> SortedStarts<T> for DynSortedDisjoint<'a, T> {}
-impl<'a, T: Integer> SortedDisjoint<T> for DynSortedDisjoint<'a, T> {}
+impl<'a, T: Element> SortedStarts<T> for DynSortedDisjoint<'a, T> {}
+impl<'a, T: Element> SortedDisjoint<T> for DynSortedDisjoint<'a, T> {}
 
-impl<'a, T: Integer> Iterator for DynSortedDisjoint<'a, T> {
+impl<'a, T: Element> Iterator for DynSortedDisjoint<'a, T> {
     type Item = RangeInclusive<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -0,0 +1,631 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::min,
+    fmt,
+    iter::FusedIterator,
+    ops::{self, RangeInclusive},
+};
+
+use num_traits::{NumCast, One, Zero};
+
+use crate::{
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Element, Integer, NotIter, RangeSetBlaze,
+    SortedDisjoint, SortedStarts,
+};
+
+// The number of words in each block of the high bits. Each block records how many ones come before it,
+// so `select` scans at most this many words after a binary search.
+const BLOCK_WORDS: usize = 8;
+
+/// A static, compressed set of integers that stores its ranges with Elias-Fano encoding.
+///
+/// It encodes the range starts, and the running total of the range lengths, as two monotone sequences.
+/// Each value takes about 2 + log2(u/n) bits, where n is the number of ranges and u is the span of the
+/// sequence. That suits huge, sparse sets, for example, ones that are mostly singletons, where
+/// [`RangeSetBlaze`] and [`crate::FrozenRangeSet`] store two whole integers per range.
+///
+/// Create one with [`RangeSetBlaze::to_elias_fano`]. Use [`RangeSetBlaze::compressed_size_estimate`] to decide
+/// whether the encoding pays off before building it.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::RangeSetBlaze;
+///
+/// let set = RangeSetBlaze::from_iter([3u64, 17, 1000, 5000, 5001, 5002, 1 << 40]);
+/// let compressed = set.to_elias_fano();
+/// assert!(compressed.contains(5001));
+/// assert!(!compressed.contains(4));
+/// assert_eq!(compressed.rank(1000), 2); // 3 and 17 are less than 1000
+/// assert_eq!(compressed.select(3), Some(5000));
+/// assert_eq!(compressed.ranges_len(), 5);
+/// assert_eq!(compressed.to_range_set_blaze(), set);
+/// assert_eq!(compressed.size_in_bytes(), set.compressed_size_estimate());
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct EliasFanoRangeSet<T: Integer> {
+    len: <T as Element>::SafeLen,
+    // The ordinals (distances from `T::min_value()`) of the range starts.
+    starts: EliasFano,
+    // The total length of the ranges up to and including each range, less one. Storing one less lets
+    // a `u128` hold the total of a set with 2^128 elements.
+    ends_last: EliasFano,
+}
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Builds an [`EliasFanoRangeSet`], a static compressed copy of the set.
+    ///
+    /// # Performance
+    ///
+    /// This takes O(n) time, where n is the number of ranges.
+    #[must_use]
+    pub fn to_elias_fano(&self) -> EliasFanoRangeSet<T> {
+        EliasFanoRangeSet::from(self)
+    }
+
+    /// Estimates the heap size, in bytes, of the set as an [`EliasFanoRangeSet`], without building it.
+    ///
+    /// Compare it to `self.ranges_len() * 2 * size_of::<T>()`, the size of the same ranges as sorted arrays
+    /// (see [`RangeSetBlaze::freeze`]), to choose a representation.
+    ///
+    /// # Performance
+    ///
+    /// This is O(log n), where n is the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// // Mostly singletons, spread over a wide span: the encoding pays off.
+    /// let sparse: RangeSetBlaze<u64> = (0..10_000u64).map(|i| i * 1_000).collect();
+    /// assert!(sparse.compressed_size_estimate() < sparse.ranges_len() * 2 * 8 / 4);
+    /// ```
+    #[must_use]
+    pub fn compressed_size_estimate(&self) -> usize {
+        let Some((&last_start, _)) = self.btree_map.last_key_value() else {
+            return 0;
+        };
+        let ranges_len = self.ranges_len();
+        EliasFano::size_in_bytes_for(ranges_len, ordinal(last_start))
+            + EliasFano::size_in_bytes_for(
+                ranges_len,
+                safe_len_to_u128::<T>(self.len - <T as Element>::SafeLen::one()),
+            )
+    }
+}
+
+impl<T: Integer> From<&RangeSetBlaze<T>> for EliasFanoRangeSet<T> {
+    fn from(set: &RangeSetBlaze<T>) -> Self {
+        let starts: Vec<u128> = set.btree_map.keys().map(|&start| ordinal(start)).collect();
+        let mut before = 0u128;
+        let ends_last: Vec<u128> = set
+            .ranges()
+            .map(|range| {
+                let last = before + (ordinal(*range.end()) - ordinal(*range.start()));
+                // Only the last range of a set with 2^128 elements leaves no room for another.
+                before = last.wrapping_add(1);
+                last
+            })
+            .collect();
+        Self {
+            len: set.len(),
+            starts: EliasFano::new(&starts),
+            ends_last: EliasFano::new(&ends_last),
+        }
+    }
+}
+
+impl<T: Integer> EliasFanoRangeSet<T> {
+    /// Returns `true` if the set contains `value`.
+    ///
+    /// # Performance
+    ///
+    /// This is O(log n), where n is the number of ranges.
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        assert!(
+            value <= T::safe_max_value(),
+            "value must be <= T::safe_max_value()"
+        );
+        let value = ordinal(value);
+        self.starts.predecessor(value).map_or(false, |index| {
+            value - self.starts.get(index) <= self.range_len_less_one(index)
+        })
+    }
+
+    /// Returns the number of elements in the set that are less than `value`.
+    ///
+    /// # Performance
+    ///
+    /// This is O(log n), where n is the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let compressed = RangeSetBlaze::from_iter([-5i8..=5, 100..=100]).to_elias_fano();
+    /// assert_eq!(compressed.rank(-10), 0);
+    /// assert_eq!(compressed.rank(0), 5);
+    /// assert_eq!(compressed.rank(101), 12);
+    /// ```
+    #[must_use]
+    pub fn rank(&self, value: T) -> <T as Element>::SafeLen {
+        let value = ordinal(value);
+        let rank = self.starts.predecessor(value).map_or(0, |index| {
+            let offset = value - self.starts.get(index);
+            if offset <= self.range_len_less_one(index) {
+                self.before(index) + offset
+            } else {
+                // `value` is past the end of the range, so the set can't hold every value.
+                self.ends_last.get(index) + 1
+            }
+        });
+        <<T as Element>::SafeLen as NumCast>::from(rank).unwrap()
+    }
+
+    /// Returns the element of the set with the given 0-based position in sorted order, if any.
+    ///
+    /// # Performance
+    ///
+    /// This is O(log n), where n is the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let compressed = RangeSetBlaze::from_iter([10u16..=12, 20..=20]).to_elias_fano();
+    /// assert_eq!(compressed.select(0), Some(10));
+    /// assert_eq!(compressed.select(3), Some(20));
+    /// assert_eq!(compressed.select(4), None);
+    /// ```
+    #[must_use]
+    pub fn select(&self, position: <T as Element>::SafeLen) -> Option<T> {
+        if position >= self.len {
+            return None;
+        }
+        let position = safe_len_to_u128::<T>(position);
+        // The range holding `position` is the first whose running total exceeds it.
+        let (index, before) = match position
+            .checked_sub(1)
+            .and_then(|position_less_one| self.ends_last.predecessor(position_less_one))
+        {
+            None => (0, 0),
+            Some(index) => (index + 1, self.ends_last.get(index) + 1),
+        };
+        Some(from_ordinal(self.starts.get(index) + (position - before)))
+    }
+
+    /// Returns the number of elements in the set.
+    #[must_use]
+    pub const fn len(&self) -> <T as Element>::SafeLen {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len.is_zero()
+    }
+
+    /// Returns the number of sorted & disjoint ranges in the set.
+    #[must_use]
+    pub const fn ranges_len(&self) -> usize {
+        self.starts.len
+    }
+
+    /// Returns the heap size of the encoding in bytes.
+    #[must_use]
+    pub fn size_in_bytes(&self) -> usize {
+        self.starts.size_in_bytes() + self.ends_last.size_in_bytes()
+    }
+
+    /// Gets a [`SortedDisjoint`] iterator over the ranges in the set, decoding them as it goes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1u32..=3, 10..=12]).to_elias_fano();
+    /// let b = RangeSetBlaze::from_iter([3u32..=10]);
+    /// assert_eq!((a.ranges() | b.ranges()).to_string(), "1..=12");
+    /// ```
+    pub fn ranges(&self) -> EliasFanoRangesIter<'_, T> {
+        EliasFanoRangesIter {
+            starts: self.starts.iter(),
+            ends_last: self.ends_last.iter(),
+            before: 0,
+            phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Decodes the set into a new [`RangeSetBlaze`].
+    #[must_use]
+    pub fn to_range_set_blaze(&self) -> RangeSetBlaze<T> {
+        RangeSetBlaze {
+            len: self.len,
+            btree_map: self.ranges().map(RangeInclusive::into_inner).collect(),
+        }
+    }
+
+    // The number of elements in the ranges before the range at `index`.
+    fn before(&self, index: usize) -> u128 {
+        if index == 0 {
+            0
+        } else {
+            self.ends_last.get(index - 1) + 1
+        }
+    }
+
+    fn range_len_less_one(&self, index: usize) -> u128 {
+        self.ends_last.get(index) - self.before(index)
+    }
+}
+
+impl<T: Integer> fmt::Debug for EliasFanoRangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+impl<T: Integer> fmt::Display for EliasFanoRangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+// The distance of `value` from `T::min_value()`.
+pub(crate) fn ordinal<T: Integer>(value: T) -> u128 {
+    safe_len_to_u128::<T>(T::safe_len(&(T::min_value()..=value)) - <T as Element>::SafeLen::one())
+}
+
+pub(crate) fn from_ordinal<T: Integer>(ordinal: u128) -> T {
+    if T::min_value().is_zero() {
+        <T as NumCast>::from(ordinal).unwrap()
+    } else {
+        // Two's complement: wrapping `ordinal` into the signed range and adding the minimum is exact.
+        let min = <i128 as NumCast>::from(T::min_value()).unwrap();
+        <T as NumCast>::from((ordinal as i128).wrapping_add(min)).unwrap()
+    }
+}
+
+pub(crate) fn safe_len_to_u128<T: Integer>(len: <T as Element>::SafeLen) -> u128 {
+    <u128 as NumCast>::from(len).unwrap()
+}
+
+// An Elias-Fano encoding of a non-decreasing sequence of `u128`'s. Each value is split into `low_width`
+// low bits, stored packed, and the remaining high bits, stored in unary as the gaps between ones in `high`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct EliasFano {
+    len: usize,
+    low_width: u32,
+    lows: Vec<u64>,
+    high: BitVec,
+}
+
+impl EliasFano {
+    fn low_width(len: usize, last: u128) -> u32 {
+        let len = len as u128;
+        if last < len {
+            0
+        } else {
+            127 - (last / len).leading_zeros()
+        }
+    }
+
+    fn size_in_bytes_for(len: usize, last: u128) -> usize {
+        if len == 0 {
+            return 0;
+        }
+        let low_width = Self::low_width(len, last);
+        let low_words = (len * low_width as usize).div_ceil(64);
+        let high_words = (len + (last >> low_width) as usize + 1).div_ceil(64);
+        8 * (low_words + high_words + high_words.div_ceil(BLOCK_WORDS))
+    }
+
+    fn new(values: &[u128]) -> Self {
+        let len = values.len();
+        let Some(&last) = values.last() else {
+            return Self {
+                len,
+                low_width: 0,
+                lows: Vec::new(),
+                high: BitVec::new(Vec::new(), 0),
+            };
+        };
+        let low_width = Self::low_width(len, last);
+        let mut lows = alloc::vec![0; (len * low_width as usize).div_ceil(64)];
+        let high_len = len + (last >> low_width) as usize + 1;
+        let mut high = alloc::vec![0u64; high_len.div_ceil(64)];
+        for (index, &value) in values.iter().enumerate() {
+            write_bits(&mut lows, index * low_width as usize, low_width, value);
+            let position = (value >> low_width) as usize + index;
+            high[position / 64] |= 1 << (position % 64);
+        }
+        Self {
+            len,
+            low_width,
+            lows,
+            high: BitVec::new(high, high_len),
+        }
+    }
+
+    fn size_in_bytes(&self) -> usize {
+        8 * (self.lows.len() + self.high.words.len() + self.high.block_ones.len())
+    }
+
+    fn low(&self, index: usize) -> u128 {
+        read_bits(&self.lows, index * self.low_width as usize, self.low_width)
+    }
+
+    fn get(&self, index: usize) -> u128 {
+        let high = (self.high.select1(index) - index) as u128;
+        (high << self.low_width) | self.low(index)
+    }
+
+    // Returns the index of the last value that is less than or equal to `value`.
+    fn predecessor(&self, value: u128) -> Option<usize> {
+        let zeros = self.high.len - self.len;
+        let bucket = value >> self.low_width;
+        if bucket >= zeros as u128 {
+            return self.len.checked_sub(1);
+        }
+        let bucket = bucket as usize;
+        // Values with high part `bucket` have indexes in `begin..end`.
+        let end = self.high.select0(bucket) - bucket;
+        let begin = if bucket == 0 {
+            0
+        } else {
+            self.high.select0(bucket - 1) - (bucket - 1)
+        };
+        // They share the high part, so comparing low parts is enough and avoids a `select1` per step.
+        let value_low = value - ((bucket as u128) << self.low_width);
+        let (mut low, mut high) = (begin, end);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.low(mid) <= value_low {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low.checked_sub(1)
+    }
+
+    fn iter(&self) -> EliasFanoIter<'_> {
+        EliasFanoIter {
+            elias_fano: self,
+            index: 0,
+            position: 0,
+        }
+    }
+}
+
+// Decodes an `EliasFano` in order by walking its high bits once.
+#[derive(Clone, Debug)]
+struct EliasFanoIter<'a> {
+    elias_fano: &'a EliasFano,
+    index: usize,
+    position: usize,
+}
+
+impl Iterator for EliasFanoIter<'_> {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        if self.index == self.elias_fano.len {
+            return None;
+        }
+        let words = &self.elias_fano.high.words;
+        let mut word_index = self.position / 64;
+        let mut word = words[word_index] & (u64::MAX << (self.position % 64));
+        while word == 0 {
+            word_index += 1;
+            word = words[word_index];
+        }
+        let position = word_index * 64 + word.trailing_zeros() as usize;
+        let high = (position - self.index) as u128;
+        let value = (high << self.elias_fano.low_width) | self.elias_fano.low(self.index);
+        self.index += 1;
+        self.position = position + 1;
+        Some(value)
+    }
+}
+
+// A bit vector with `select` support.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct BitVec {
+    words: Vec<u64>,
+    len: usize,
+    // The number of ones before each block of `BLOCK_WORDS` words.
+    block_ones: Vec<u64>,
+}
+
+impl BitVec {
+    fn new(words: Vec<u64>, len: usize) -> Self {
+        let mut ones = 0;
+        let block_ones = words
+            .chunks(BLOCK_WORDS)
+            .map(|block| {
+                let before = ones;
+                ones += block
+                    .iter()
+                    .map(|word| <u64 as From<u32>>::from(word.count_ones()))
+                    .sum::<u64>();
+                before
+            })
+            .collect();
+        Self {
+            words,
+            len,
+            block_ones,
+        }
+    }
+
+    // Returns the position of the one with the given 0-based rank. It must exist.
+    fn select1(&self, rank: usize) -> usize {
+        let block = self.block_ones.partition_point(|&ones| ones <= rank as u64) - 1;
+        self.select_from(block, rank - self.block_ones[block] as usize, |word| word)
+    }
+
+    // Returns the position of the zero with the given 0-based rank. It must exist.
+    fn select0(&self, rank: usize) -> usize {
+        let zeros_before =
+            |block: usize| (block * BLOCK_WORDS * 64) as u64 - self.block_ones[block];
+        // Find the last block with at most `rank` zeros before it.
+        let (mut low, mut high) = (0, self.block_ones.len());
+        while low + 1 < high {
+            let mid = low + (high - low) / 2;
+            if zeros_before(mid) <= rank as u64 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let block = low;
+        self.select_from(block, rank - zeros_before(block) as usize, |word| !word)
+    }
+
+    // Scans from the start of `block` for the set bit with rank `rank` in the words, as mapped by `map`.
+    fn select_from(&self, block: usize, mut rank: usize, map: impl Fn(u64) -> u64) -> usize {
+        let mut word_index = block * BLOCK_WORDS;
+        loop {
+            let mut word = map(self.words[word_index]);
+            let ones = word.count_ones() as usize;
+            if rank < ones {
+                for _ in 0..rank {
+                    word &= word - 1;
+                }
+                return word_index * 64 + word.trailing_zeros() as usize;
+            }
+            rank -= ones;
+            word_index += 1;
+        }
+    }
+}
+
+fn read_bits(words: &[u64], position: usize, width: u32) -> u128 {
+    let (mut value, mut done) = (0u128, 0);
+    while done < width {
+        let bit = position + done as usize;
+        let offset = (bit % 64) as u32;
+        let take = min(64 - offset, width - done);
+        let chunk = (words[bit / 64] >> offset) & low_mask(take);
+        value |= <u128 as From<u64>>::from(chunk) << done;
+        done += take;
+    }
+    value
+}
+
+fn write_bits(words: &mut [u64], position: usize, width: u32, value: u128) {
+    let mut done = 0;
+    while done < width {
+        let bit = position + done as usize;
+        let offset = (bit % 64) as u32;
+        let take = min(64 - offset, width - done);
+        let chunk = (value >> done) as u64 & low_mask(take);
+        words[bit / 64] |= chunk << offset;
+        done += take;
+    }
+}
+
+const fn low_mask(width: u32) -> u64 {
+    if width == 64 {
+        u64::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// An iterator over the ranges of an [`EliasFanoRangeSet`].
+///
+/// This `struct` is created by [`EliasFanoRangeSet::ranges`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EliasFanoRangesIter<'a, T: Integer> {
+    starts: EliasFanoIter<'a>,
+    ends_last: EliasFanoIter<'a>,
+    before: u128,
+    phantom: core::marker::PhantomData<T>,
+}
+
+impl<T: Integer> FusedIterator for EliasFanoRangesIter<'_, T> {}
+
+impl<T: Integer> Iterator for EliasFanoRangesIter<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        let start = self.starts.next()?;
+        let last = self.ends_last.next()?;
+        let end = start + (last - self.before);
+        // This wraps only after the last range of a set with 2^128 elements.
+        self.before = last.wrapping_add(1);
+        Some(from_ordinal(start)..=from_ordinal(end))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.starts.elias_fano.len - self.starts.index;
+        (len, Some(len))
+    }
+}
+
+impl<T: Integer> ExactSizeIterator for EliasFanoRangesIter<'_, T> {}
+
+impl<T: Integer> SortedStarts<T> for EliasFanoRangesIter<'_, T> {}
+impl<T: Integer> SortedDisjoint<T> for EliasFanoRangesIter<'_, T> {}
+
+impl<T: Integer> ops::Not for EliasFanoRangesIter<'_, T> {
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: Integer, R> ops::BitOr<R> for EliasFanoRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitAnd<R> for EliasFanoRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::Sub<R> for EliasFanoRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitXor<R> for EliasFanoRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
@@ -0,0 +1,441 @@
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    iter::{FusedIterator, Zip},
+    ops::{self, RangeInclusive},
+    slice,
+};
+
+use gen_ops::gen_ops_ex;
+
+use crate::{
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Element, Integer, NotIter, RangeSetBlaze,
+    SortedDisjoint, SortedStarts,
+};
+
+/// A read-only set of integers, stored as parallel arrays of range starts and ends.
+///
+/// Create one with [`RangeSetBlaze::freeze`] and turn it back into a [`RangeSetBlaze`] with
+/// [`FrozenRangeSet::thaw`].
+///
+/// Compared to a [`RangeSetBlaze`], a `FrozenRangeSet` uses less memory and is friendlier to the cache,
+/// which suits lookup tables that are built once and then only queried. Lookups binary search the
+/// sorted starts. Calling [`FrozenRangeSet::eytzinger`] adds a copy of the ranges in Eytzinger (breadth-first)
+/// order, which makes lookups in large sets faster at the cost of twice the memory.
+///
+/// Set operations (`|`, `&`, `^`, `-`, and `!`) between `FrozenRangeSet`'s return a new [`RangeSetBlaze`].
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::RangeSetBlaze;
+///
+/// let reserved = RangeSetBlaze::from_iter([0u16..=1023, 6000..=6063]).freeze();
+/// assert!(reserved.contains(80));
+/// assert!(!reserved.contains(8080));
+/// assert_eq!(reserved.ranges_len(), 2);
+/// assert_eq!(reserved.len(), 1088);
+///
+/// let mut set = reserved.thaw();
+/// set.insert(8080);
+/// assert_eq!(set.to_string(), "0..=1023, 6000..=6063, 8080..=8080");
+/// ```
+#[derive(Clone)]
+pub struct FrozenRangeSet<T: Integer> {
+    len: <T as Element>::SafeLen,
+    starts: Vec<T>,
+    ends: Vec<T>,
+    // Either empty or the ranges in Eytzinger order, 1-indexed. Slot 0 is unused.
+    eytzinger: Vec<(T, T)>,
+}
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Copies the set into a read-only [`FrozenRangeSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let frozen = RangeSetBlaze::from_iter([1..=3, 10..=12]).freeze();
+    /// assert_eq!(frozen.to_string(), "1..=3, 10..=12");
+    /// ```
+    #[must_use]
+    pub fn freeze(&self) -> FrozenRangeSet<T> {
+        FrozenRangeSet {
+            len: self.len(),
+            starts: self.btree_map.keys().copied().collect(),
+            ends: self.btree_map.values().copied().collect(),
+            eytzinger: Vec::new(),
+        }
+    }
+}
+
+impl<T: Integer> FrozenRangeSet<T> {
+    /// Adds a copy of the ranges in Eytzinger (breadth-first) order, so that [`FrozenRangeSet::contains`]
+    /// walks an implicit binary tree whose top levels share cache lines.
+    ///
+    /// # Performance
+    ///
+    /// This takes O(n) time and doubles the memory used, where n is the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let frozen = RangeSetBlaze::from_iter([1..=3, 10..=12, 20..=22]).freeze().eytzinger();
+    /// assert!(frozen.is_eytzinger());
+    /// assert!(frozen.contains(11));
+    /// assert!(!frozen.contains(13));
+    /// ```
+    pub fn eytzinger(mut self) -> Self {
+        if !self.is_eytzinger() {
+            let ranges: Vec<(T, T)> = self
+                .starts
+                .iter()
+                .copied()
+                .zip(self.ends.iter().copied())
+                .collect();
+            let mut eytzinger = Vec::with_capacity(ranges.len() + 1);
+            // Slot 0 is never read, so any value will do.
+            eytzinger.resize(ranges.len() + 1, (T::zero(), T::zero()));
+            fill_eytzinger(&ranges, &mut eytzinger, &mut 0, 1);
+            self.eytzinger = eytzinger;
+        }
+        self
+    }
+
+    /// Returns `true` if the set keeps a copy of its ranges in Eytzinger order. See [`FrozenRangeSet::eytzinger`].
+    #[must_use]
+    pub fn is_eytzinger(&self) -> bool {
+        !self.eytzinger.is_empty()
+    }
+
+    /// Returns `true` if the set contains `value`.
+    ///
+    /// # Performance
+    ///
+    /// This is O(log n), where n is the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let frozen = RangeSetBlaze::from_iter([1..=3, 10..=12]).freeze();
+    /// assert!(frozen.contains(3));
+    /// assert!(!frozen.contains(4));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        assert!(
+            value <= T::safe_max_value(),
+            "value must be <= T::safe_max_value()"
+        );
+        if self.is_eytzinger() {
+            // Find the first range whose end is at least `value`.
+            let mut k = 1;
+            while k < self.eytzinger.len() {
+                k = 2 * k + usize::from(self.eytzinger[k].1 < value);
+            }
+            k >>= k.trailing_ones() + 1;
+            k != 0 && self.eytzinger[k].0 <= value
+        } else {
+            let index = self.starts.partition_point(|&start| start <= value);
+            index != 0 && value <= self.ends[index - 1]
+        }
+    }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// The number is allowed to be very, very large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let frozen = RangeSetBlaze::from_iter([i64::MIN..=i64::MAX]).freeze();
+    /// assert_eq!(frozen.len(), 18_446_744_073_709_551_616u128);
+    /// ```
+    #[must_use]
+    pub const fn len(&self) -> <T as Element>::SafeLen {
+        self.len
+    }
+
+    /// Returns `true` if the set contains no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    /// Returns the number of sorted & disjoint ranges in the set.
+    #[must_use]
+    pub fn ranges_len(&self) -> usize {
+        self.starts.len()
+    }
+
+    /// Gets an iterator that visits the ranges in the set in ascending (or, with `.rev()`, descending) order.
+    ///
+    /// The iterator is a [`SortedDisjoint`], so it supports the usual set operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1..=3, 10..=12]).freeze();
+    /// let b = RangeSetBlaze::from_iter([2..=11]);
+    /// assert_eq!((a.ranges() & b.ranges()).to_string(), "2..=3, 10..=11");
+    /// ```
+    pub fn ranges(&self) -> FrozenRangesIter<'_, T> {
+        FrozenRangesIter {
+            iter: self.starts.iter().zip(self.ends.iter()),
+        }
+    }
+
+    /// Turns the set back into a [`RangeSetBlaze`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([1..=3, 10..=12]);
+    /// assert_eq!(set.freeze().thaw(), set);
+    /// ```
+    #[must_use]
+    pub fn thaw(self) -> RangeSetBlaze<T> {
+        RangeSetBlaze {
+            len: self.len,
+            btree_map: self.starts.into_iter().zip(self.ends).collect(),
+        }
+    }
+}
+
+// Copies `sorted` into `eytzinger` so that the children of slot `k` are slots `2k` and `2k + 1`.
+fn fill_eytzinger<T: Copy>(sorted: &[T], eytzinger: &mut [T], next: &mut usize, k: usize) {
+    if k < eytzinger.len() {
+        fill_eytzinger(sorted, eytzinger, next, 2 * k);
+        eytzinger[k] = sorted[*next];
+        *next += 1;
+        fill_eytzinger(sorted, eytzinger, next, 2 * k + 1);
+    }
+}
+
+impl<T: Integer> From<&RangeSetBlaze<T>> for FrozenRangeSet<T> {
+    fn from(set: &RangeSetBlaze<T>) -> Self {
+        set.freeze()
+    }
+}
+
+impl<T: Integer> From<FrozenRangeSet<T>> for RangeSetBlaze<T> {
+    fn from(frozen: FrozenRangeSet<T>) -> Self {
+        frozen.thaw()
+    }
+}
+
+impl<T: Integer> PartialEq for FrozenRangeSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.starts == other.starts && self.ends == other.ends
+    }
+}
+
+impl<T: Integer> Eq for FrozenRangeSet<T> {}
+
+impl<T: Integer> fmt::Debug for FrozenRangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+impl<T: Integer> fmt::Display for FrozenRangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+gen_ops_ex!(
+    <T>;
+    types ref FrozenRangeSet<T>, ref FrozenRangeSet<T> => RangeSetBlaze<T>;
+
+    /// Unions the contents of two [`FrozenRangeSet`]'s into a new [`RangeSetBlaze`].
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1..=2, 5..=100]).freeze();
+    /// let b = RangeSetBlaze::from_iter([2..=6]).freeze();
+    /// let result = &a | &b; // Alternatively, 'a | b'.
+    /// assert_eq!(result.to_string(), "1..=100");
+    /// ```
+    for | call |a: &FrozenRangeSet<T>, b: &FrozenRangeSet<T>| {
+        (a.ranges() | b.ranges()).into_range_set_blaze()
+    };
+
+    /// Intersects the contents of two [`FrozenRangeSet`]'s into a new [`RangeSetBlaze`].
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1..=2, 5..=100]).freeze();
+    /// let b = RangeSetBlaze::from_iter([2..=6]).freeze();
+    /// let result = &a & &b; // Alternatively, 'a & b'.
+    /// assert_eq!(result.to_string(), "2..=2, 5..=6");
+    /// ```
+    for & call |a: &FrozenRangeSet<T>, b: &FrozenRangeSet<T>| {
+        (a.ranges() & b.ranges()).into_range_set_blaze()
+    };
+
+    /// Symmetric difference the contents of two [`FrozenRangeSet`]'s into a new [`RangeSetBlaze`].
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1..=2, 5..=100]).freeze();
+    /// let b = RangeSetBlaze::from_iter([2..=6]).freeze();
+    /// let result = &a ^ &b; // Alternatively, 'a ^ b'.
+    /// assert_eq!(result.to_string(), "1..=1, 3..=4, 7..=100");
+    /// ```
+    for ^ call |a: &FrozenRangeSet<T>, b: &FrozenRangeSet<T>| {
+        ((a.ranges() - b.ranges()) | (b.ranges() - a.ranges())).into_range_set_blaze()
+    };
+
+    /// Difference the contents of two [`FrozenRangeSet`]'s into a new [`RangeSetBlaze`].
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1..=2, 5..=100]).freeze();
+    /// let b = RangeSetBlaze::from_iter([2..=6]).freeze();
+    /// let result = &a - &b; // Alternatively, 'a - b'.
+    /// assert_eq!(result.to_string(), "1..=1, 7..=100");
+    /// ```
+    for - call |a: &FrozenRangeSet<T>, b: &FrozenRangeSet<T>| {
+        (a.ranges() - b.ranges()).into_range_set_blaze()
+    };
+    where T: Integer //Where clause for all impl's
+);
+
+gen_ops_ex!(
+    <T>;
+    types ref FrozenRangeSet<T> => RangeSetBlaze<T>;
+
+    /// Complement the contents of a [`FrozenRangeSet`] into a new [`RangeSetBlaze`].
+    ///
+    /// The input may be borrowed or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    ///
+    /// let a = RangeSetBlaze::from_iter([1u8..=2, 5..=100]).freeze();
+    /// let result = !&a; // Alternatively, '!a'.
+    /// assert_eq!(result.to_string(), "0..=0, 3..=4, 101..=255");
+    /// ```
+    for ! call |a: &FrozenRangeSet<T>| {
+        (!a.ranges()).into_range_set_blaze()
+    };
+
+    where T: Integer //Where clause for all impl's
+);
+
+/// An iterator over the ranges of a [`FrozenRangeSet`].
+///
+/// This `struct` is created by [`FrozenRangeSet::ranges`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FrozenRangesIter<'a, T: Integer> {
+    iter: Zip<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+}
+
+impl<T: Integer> FusedIterator for FrozenRangesIter<'_, T> {}
+
+impl<T: Integer> Iterator for FrozenRangesIter<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        self.iter.next().map(|(&start, &end)| start..=end)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: Integer> DoubleEndedIterator for FrozenRangesIter<'_, T> {
+    fn next_back(&mut self) -> Option<RangeInclusive<T>> {
+        self.iter.next_back().map(|(&start, &end)| start..=end)
+    }
+}
+
+impl<T: Integer> ExactSizeIterator for FrozenRangesIter<'_, T> {}
+
+impl<T: Integer> SortedStarts<T> for FrozenRangesIter<'_, T> {}
+impl<T: Integer> SortedDisjoint<T> for FrozenRangesIter<'_, T> {}
+
+impl<T: Integer> ops::Not for FrozenRangesIter<'_, T> {
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: Integer, R> ops::BitOr<R> for FrozenRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitAnd<R> for FrozenRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::Sub<R> for FrozenRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitXor<R> for FrozenRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
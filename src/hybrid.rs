@@ -0,0 +1,782 @@
+use alloc::{boxed::Box, collections::btree_map, collections::BTreeMap, vec};
+use core::{
+    fmt,
+    iter::{FusedIterator, Peekable},
+    marker::PhantomData,
+    ops::{self, RangeInclusive},
+};
+
+use gen_ops::gen_ops_ex;
+
+use crate::{
+    elias_fano::{from_ordinal, ordinal},
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Element, Integer, NotIter, RangeSetBlaze,
+    RangesIter, SortedDisjoint, SortedStarts, UIntPlusOne,
+};
+
+// Each chunk covers 2^16 consecutive integers.
+const CHUNK_BITS: u32 = 16;
+const CHUNK_LEN: u32 = 1 << CHUNK_BITS;
+const CHUNK_MAX: u32 = CHUNK_LEN - 1;
+const CHUNK_WORDS: usize = (CHUNK_LEN / 64) as usize;
+// A chunk with more ranges than this becomes a bitmap, which takes 8 KiB.
+const TO_BITMAP: usize = 1024;
+// A bitmap chunk with fewer runs than this goes back to ranges. The gap between the two thresholds keeps
+// a chunk from switching back and forth on every edit.
+const TO_RANGES: u32 = 512;
+
+/// A set of integers that partitions the integers into chunks of 2^16 and stores each chunk as either
+/// ranges or a dense bitmap, switching adaptively.
+///
+/// [`RangeSetBlaze`] is at its best on clumpy data, but on random, sparse data every integer becomes its own
+/// range. `HybridRangeSet` stores a chunk as a bitmap once it holds more than 1024 ranges, and goes back to
+/// ranges when it holds fewer than 512. Runs of completely full chunks take constant space, so clumpy data
+/// stays cheap.
+///
+/// Like [`RangeSetBlaze`], it supports `|`, `&`, `^`, `-`, and `!`, and [`HybridRangeSet::ranges`] is a
+/// [`SortedDisjoint`] iterator.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{HybridRangeSet, RangeSetBlaze};
+///
+/// // Every third integer: 3,334 ranges as a `RangeSetBlaze`, one bitmap chunk here.
+/// let sparse: HybridRangeSet<u32> = (0..10_000).step_by(3).collect();
+/// assert_eq!(sparse.bitmap_chunks_len(), 1);
+/// assert!(sparse.contains(9));
+/// assert!(!sparse.contains(10));
+///
+/// let clumpy = HybridRangeSet::from_iter([0u32..=1_000_000]);
+/// assert_eq!(clumpy.bitmap_chunks_len(), 0);
+///
+/// let both = &sparse & &clumpy;
+/// assert_eq!(both.len(), 3334);
+/// ```
+#[derive(Clone)]
+pub struct HybridRangeSet<T: Integer> {
+    // Up to 2^128 integers, so a `u128` isn't quite enough.
+    len: UIntPlusOne<u128>,
+    // The keys of chunks whose integers are all in the set.
+    full: RangeSetBlaze<u128>,
+    // The chunks that are neither empty nor full, by key.
+    chunks: BTreeMap<u128, Chunk>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Integer> HybridRangeSet<T> {
+    /// Makes a new, empty `HybridRangeSet`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            len: UIntPlusOne::UInt(0),
+            full: RangeSetBlaze::new(),
+            chunks: BTreeMap::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the set.
+    #[must_use]
+    pub fn len(&self) -> <T as Element>::SafeLen {
+        <<T as Element>::SafeLen as num_traits::NumCast>::from(self.len).unwrap()
+    }
+
+    /// Returns `true` if the set contains no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        matches!(self.len, UIntPlusOne::UInt(0))
+    }
+
+    /// Returns the number of chunks currently stored as bitmaps.
+    #[must_use]
+    pub fn bitmap_chunks_len(&self) -> usize {
+        self.chunks
+            .values()
+            .filter(|chunk| matches!(chunk, Chunk::Bitmap(_)))
+            .count()
+    }
+
+    /// Returns `true` if the set contains `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let set = HybridRangeSet::from_iter([1, 2, 3]);
+    /// assert!(set.contains(1));
+    /// assert!(!set.contains(4));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        assert!(
+            value <= T::safe_max_value(),
+            "value must be <= T::safe_max_value()"
+        );
+        let (key, low) = split(value);
+        self.full.contains(key)
+            || self
+                .chunks
+                .get(&key)
+                .map_or(false, |chunk| chunk.contains(low))
+    }
+
+    /// Adds a value to the set. Returns whether the value was newly inserted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let mut set = HybridRangeSet::new();
+    /// assert!(set.insert(2));
+    /// assert!(!set.insert(2));
+    /// assert_eq!(set.len(), 1usize);
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        self.ranges_insert(value..=value)
+    }
+
+    /// Adds a range to the set. Returns whether any values were newly inserted.
+    ///
+    /// # Performance
+    ///
+    /// This takes O(log n) time for each chunk partly covered by the range, and for each non-full chunk
+    /// already in the range, where n is the number of chunks. Chunks that the range covers completely
+    /// are recorded together in constant space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let mut set = HybridRangeSet::new();
+    /// assert!(set.ranges_insert(2..=5));
+    /// assert!(!set.ranges_insert(3..=4));
+    /// assert_eq!(set.to_string(), "2..=5");
+    /// ```
+    pub fn ranges_insert(&mut self, range: RangeInclusive<T>) -> bool {
+        let (start, end) = range.into_inner();
+        assert!(
+            end <= T::safe_max_value(),
+            "end must be <= T::safe_max_value()"
+        );
+        if start > end {
+            return false;
+        }
+        let len_before = self.len;
+        let ((start_key, start_low), (end_key, end_low)) = (split(start), split(end));
+        if start_key == end_key {
+            self.chunk_insert(start_key, start_low, end_low);
+            return self.len != len_before;
+        }
+        self.chunk_insert(start_key, start_low, CHUNK_MAX);
+        if start_key + 1 < end_key {
+            let inner = start_key + 1..=end_key - 1;
+            let partial_keys: vec::Vec<u128> = self
+                .chunks
+                .range(inner.clone())
+                .map(|(&key, _)| key)
+                .collect();
+            for key in partial_keys {
+                self.len -= count(self.chunks.remove(&key).unwrap().len());
+            }
+            let full_before = self.full.len();
+            self.full.ranges_insert(inner);
+            self.len += (self.full.len() - full_before) * count(CHUNK_LEN);
+        }
+        self.chunk_insert(end_key, 0, end_low);
+        self.len != len_before
+    }
+
+    /// If the set contains `value`, removes it. Returns whether the value was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let mut set = HybridRangeSet::from_iter([0u32..=99_999]);
+    /// assert!(set.remove(70_000));
+    /// assert!(!set.remove(70_000));
+    /// assert_eq!(set.to_string(), "0..=69999, 70001..=99999");
+    /// ```
+    pub fn remove(&mut self, value: T) -> bool {
+        assert!(
+            value <= T::safe_max_value(),
+            "value must be <= T::safe_max_value()"
+        );
+        let (key, low) = split(value);
+        if self.full.remove(key) {
+            let mut chunk = Chunk::Ranges(RangeSetBlaze::from_iter([0..=CHUNK_MAX as u16]));
+            chunk.remove(low);
+            self.chunks.insert(key, chunk);
+            self.len -= UIntPlusOne::UInt(1);
+            return true;
+        }
+        let btree_map::Entry::Occupied(mut entry) = self.chunks.entry(key) else {
+            return false;
+        };
+        if !entry.get_mut().remove(low) {
+            return false;
+        }
+        self.len -= UIntPlusOne::UInt(1);
+        if entry.get().len() == 0 {
+            entry.remove();
+        }
+        true
+    }
+
+    /// Gets a [`SortedDisjoint`] iterator over the ranges in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{prelude::*, HybridRangeSet};
+    ///
+    /// let a = HybridRangeSet::from_iter([1u16..=3, 10..=12]);
+    /// let b = RangeSetBlaze::from_iter([3u16..=10]);
+    /// assert_eq!((a.ranges() - b.ranges()).to_string(), "1..=2, 11..=12");
+    /// ```
+    pub fn ranges(&self) -> HybridRangesIter<'_, T> {
+        HybridRangesIter {
+            full: self.full.ranges().peekable(),
+            chunks: self.chunks.iter().peekable(),
+            current: None,
+            pending: None,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Copies the set into a new [`RangeSetBlaze`].
+    #[must_use]
+    pub fn to_range_set_blaze(&self) -> RangeSetBlaze<T> {
+        self.ranges().into_range_set_blaze()
+    }
+
+    // Inserts `low..=high` into the chunk with key `key`, keeping `len`, `full`, and the chunk's form up to date.
+    fn chunk_insert(&mut self, key: u128, low: u32, high: u32) {
+        if self.full.contains(key) {
+            return;
+        }
+        if low == 0 && high == CHUNK_MAX {
+            if let Some(chunk) = self.chunks.remove(&key) {
+                self.len -= count(chunk.len());
+            }
+            self.full.insert(key);
+            self.len += count(CHUNK_LEN);
+            return;
+        }
+        let chunk = self
+            .chunks
+            .entry(key)
+            .or_insert_with(|| Chunk::Ranges(RangeSetBlaze::new()));
+        self.len += count(chunk.insert(low, high));
+        if chunk.len() == CHUNK_LEN {
+            self.chunks.remove(&key);
+            self.full.insert(key);
+        }
+    }
+}
+
+// Splits a value into its chunk key and its position within the chunk.
+fn split<T: Integer>(value: T) -> (u128, u32) {
+    let ordinal = ordinal(value);
+    (ordinal >> CHUNK_BITS, (ordinal as u32) & CHUNK_MAX)
+}
+
+// Widens a count within one chunk to the set's length type.
+fn count(n: u32) -> UIntPlusOne<u128> {
+    UIntPlusOne::UInt(u128::from(n))
+}
+
+#[derive(Clone, Debug)]
+enum Chunk {
+    Ranges(RangeSetBlaze<u16>),
+    Bitmap(Bitmap),
+}
+
+impl Chunk {
+    fn len(&self) -> u32 {
+        match self {
+            Self::Ranges(set) => set.len() as u32,
+            Self::Bitmap(bitmap) => bitmap.len,
+        }
+    }
+
+    fn contains(&self, low: u32) -> bool {
+        match self {
+            Self::Ranges(set) => set.contains(low as u16),
+            Self::Bitmap(bitmap) => bitmap.contains(low),
+        }
+    }
+
+    // Inserts `low..=high` and returns how many values were newly inserted.
+    fn insert(&mut self, low: u32, high: u32) -> u32 {
+        let len_before = self.len();
+        match self {
+            Self::Ranges(set) => {
+                set.ranges_insert(low as u16..=high as u16);
+            }
+            Self::Bitmap(bitmap) => bitmap.insert(low, high),
+        }
+        self.normalize();
+        self.len() - len_before
+    }
+
+    fn remove(&mut self, low: u32) -> bool {
+        let removed = match self {
+            Self::Ranges(set) => set.remove(low as u16),
+            Self::Bitmap(bitmap) => bitmap.remove(low),
+        };
+        self.normalize();
+        removed
+    }
+
+    // Switches between ranges and a bitmap when the number of runs crosses a threshold.
+    fn normalize(&mut self) {
+        match self {
+            Self::Ranges(set) if set.ranges_len() > TO_BITMAP => {
+                let mut bitmap = Bitmap::new();
+                for range in set.ranges() {
+                    bitmap.insert(u32::from(*range.start()), u32::from(*range.end()));
+                }
+                *self = Self::Bitmap(bitmap);
+            }
+            Self::Bitmap(bitmap) if bitmap.runs < TO_RANGES => {
+                let ranges = bitmap.runs().map(|(start, end)| start as u16..=end as u16);
+                *self = Self::Ranges(RangeSetBlaze::from_sorted_disjoint(
+                    crate::CheckSortedDisjoint::new(ranges),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    fn runs(&self) -> ChunkRuns<'_> {
+        match self {
+            Self::Ranges(set) => ChunkRuns::Ranges(set.ranges()),
+            Self::Bitmap(bitmap) => ChunkRuns::Bitmap(bitmap.runs()),
+        }
+    }
+}
+
+// A dense bitmap of one chunk, which also tracks its number of runs of consecutive ones.
+#[derive(Clone, Debug)]
+struct Bitmap {
+    words: Box<[u64]>,
+    len: u32,
+    runs: u32,
+}
+
+impl Bitmap {
+    fn new() -> Self {
+        Self {
+            words: vec![0; CHUNK_WORDS].into_boxed_slice(),
+            len: 0,
+            runs: 0,
+        }
+    }
+
+    fn contains(&self, low: u32) -> bool {
+        self.words[(low / 64) as usize] & (1 << (low % 64)) != 0
+    }
+
+    fn insert(&mut self, low: u32, high: u32) {
+        // `low..=high` becomes one run, absorbing the runs inside it and any run just before or after it.
+        let before = low > 0 && self.contains(low - 1) && !self.contains(low);
+        let after = high < CHUNK_MAX && self.contains(high + 1) && !self.contains(high);
+        let mut carry = 0;
+        let mut runs_inside = 0;
+        for word_index in low / 64..=high / 64 {
+            let from = if word_index == low / 64 { low % 64 } else { 0 };
+            let to = if word_index == high / 64 {
+                high % 64
+            } else {
+                63
+            };
+            let mask = (u64::MAX >> (63 - to)) & (u64::MAX << from);
+            let word = &mut self.words[word_index as usize];
+            let inside = *word & mask;
+            runs_inside += (inside & !((inside << 1) | carry)).count_ones();
+            carry = inside >> 63;
+            self.len += (mask & !*word).count_ones();
+            *word |= mask;
+        }
+        self.runs = self.runs + 1 - runs_inside - u32::from(before) - u32::from(after);
+        debug_assert_eq!(self.runs, self.count_runs());
+    }
+
+    fn remove(&mut self, low: u32) -> bool {
+        if !self.contains(low) {
+            return false;
+        }
+        // Removing a one splits its run if both neighbors are ones, and ends it if neither is.
+        let before = low > 0 && self.contains(low - 1);
+        let after = low < CHUNK_MAX && self.contains(low + 1);
+        self.words[(low / 64) as usize] &= !(1 << (low % 64));
+        self.len -= 1;
+        self.runs = self.runs + u32::from(before && after) - u32::from(!before && !after);
+        true
+    }
+
+    fn count_runs(&self) -> u32 {
+        let mut carry = 0;
+        let mut runs = 0;
+        for &word in self.words.iter() {
+            // A run starts at each one whose lower neighbor is a zero.
+            runs += (word & !((word << 1) | carry)).count_ones();
+            carry = word >> 63;
+        }
+        runs
+    }
+
+    fn runs(&self) -> BitmapRuns<'_> {
+        BitmapRuns {
+            words: &self.words,
+            next: 0,
+        }
+    }
+}
+
+// The runs of ones in a bitmap, as inclusive ranges, in order.
+#[derive(Clone, Debug)]
+struct BitmapRuns<'a> {
+    words: &'a [u64],
+    next: u32,
+}
+
+impl BitmapRuns<'_> {
+    // Returns the position of the first bit at or after `from` that equals `one`, or `CHUNK_LEN` if none.
+    fn find_bit(&self, from: u32, one: bool) -> u32 {
+        let mut word_index = (from / 64) as usize;
+        if word_index == CHUNK_WORDS {
+            return CHUNK_LEN;
+        }
+        let flip = |word: u64| if one { word } else { !word };
+        let mut word = flip(self.words[word_index]) & (u64::MAX << (from % 64));
+        while word == 0 {
+            word_index += 1;
+            if word_index == CHUNK_WORDS {
+                return CHUNK_LEN;
+            }
+            word = flip(self.words[word_index]);
+        }
+        word_index as u32 * 64 + word.trailing_zeros()
+    }
+}
+
+impl Iterator for BitmapRuns<'_> {
+    type Item = (u32, u32);
+
+    fn next(&mut self) -> Option<(u32, u32)> {
+        let start = self.find_bit(self.next, true);
+        if start == CHUNK_LEN {
+            self.next = CHUNK_LEN;
+            return None;
+        }
+        let end = self.find_bit(start, false) - 1;
+        self.next = end + 1;
+        Some((start, end))
+    }
+}
+
+#[derive(Clone, Debug)]
+enum ChunkRuns<'a> {
+    Ranges(RangesIter<'a, u16>),
+    Bitmap(BitmapRuns<'a>),
+}
+
+impl Iterator for ChunkRuns<'_> {
+    type Item = (u32, u32);
+
+    fn next(&mut self) -> Option<(u32, u32)> {
+        match self {
+            Self::Ranges(ranges) => ranges
+                .next()
+                .map(|range| (u32::from(*range.start()), u32::from(*range.end()))),
+            Self::Bitmap(runs) => runs.next(),
+        }
+    }
+}
+
+impl<T: Integer> Default for HybridRangeSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> FromIterator<T> for HybridRangeSet<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut set = Self::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for HybridRangeSet<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = RangeInclusive<T>>,
+    {
+        let mut set = Self::new();
+        for range in iter {
+            set.ranges_insert(range);
+        }
+        set
+    }
+}
+
+impl<T: Integer> From<&RangeSetBlaze<T>> for HybridRangeSet<T> {
+    fn from(set: &RangeSetBlaze<T>) -> Self {
+        set.ranges().collect()
+    }
+}
+
+impl<T: Integer> PartialEq for HybridRangeSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.ranges().eq(other.ranges())
+    }
+}
+
+impl<T: Integer> Eq for HybridRangeSet<T> {}
+
+impl<T: Integer> fmt::Debug for HybridRangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+impl<T: Integer> fmt::Display for HybridRangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+gen_ops_ex!(
+    <T>;
+    types ref HybridRangeSet<T>, ref HybridRangeSet<T> => HybridRangeSet<T>;
+
+    /// Unions the contents of two [`HybridRangeSet`]'s.
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let a = HybridRangeSet::from_iter([1..=2, 5..=100]);
+    /// let b = HybridRangeSet::from_iter([2..=6]);
+    /// let result = &a | &b; // Alternatively, 'a | b'.
+    /// assert_eq!(result.to_string(), "1..=100");
+    /// ```
+    for | call |a: &HybridRangeSet<T>, b: &HybridRangeSet<T>| {
+        (a.ranges() | b.ranges()).collect()
+    };
+
+    /// Intersects the contents of two [`HybridRangeSet`]'s.
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let a = HybridRangeSet::from_iter([1..=2, 5..=100]);
+    /// let b = HybridRangeSet::from_iter([2..=6]);
+    /// let result = &a & &b; // Alternatively, 'a & b'.
+    /// assert_eq!(result.to_string(), "2..=2, 5..=6");
+    /// ```
+    for & call |a: &HybridRangeSet<T>, b: &HybridRangeSet<T>| {
+        (a.ranges() & b.ranges()).collect()
+    };
+
+    /// Symmetric difference the contents of two [`HybridRangeSet`]'s.
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let a = HybridRangeSet::from_iter([1..=2, 5..=100]);
+    /// let b = HybridRangeSet::from_iter([2..=6]);
+    /// let result = &a ^ &b; // Alternatively, 'a ^ b'.
+    /// assert_eq!(result.to_string(), "1..=1, 3..=4, 7..=100");
+    /// ```
+    for ^ call |a: &HybridRangeSet<T>, b: &HybridRangeSet<T>| {
+        ((a.ranges() - b.ranges()) | (b.ranges() - a.ranges())).collect()
+    };
+
+    /// Difference the contents of two [`HybridRangeSet`]'s.
+    ///
+    /// Either, neither, or both inputs may be borrowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let a = HybridRangeSet::from_iter([1..=2, 5..=100]);
+    /// let b = HybridRangeSet::from_iter([2..=6]);
+    /// let result = &a - &b; // Alternatively, 'a - b'.
+    /// assert_eq!(result.to_string(), "1..=1, 7..=100");
+    /// ```
+    for - call |a: &HybridRangeSet<T>, b: &HybridRangeSet<T>| {
+        (a.ranges() - b.ranges()).collect()
+    };
+    where T: Integer //Where clause for all impl's
+);
+
+gen_ops_ex!(
+    <T>;
+    types ref HybridRangeSet<T> => HybridRangeSet<T>;
+
+    /// Complement the contents of a [`HybridRangeSet`].
+    ///
+    /// The input may be borrowed or not.
+    ///
+    /// # Examples
+    /// ```
+    /// use range_set_blaze::HybridRangeSet;
+    ///
+    /// let a = HybridRangeSet::from_iter([1u8..=2, 5..=100]);
+    /// let result = !&a; // Alternatively, '!a'.
+    /// assert_eq!(result.to_string(), "0..=0, 3..=4, 101..=255");
+    /// ```
+    for ! call |a: &HybridRangeSet<T>| {
+        (!a.ranges()).collect()
+    };
+
+    where T: Integer //Where clause for all impl's
+);
+
+/// An iterator over the ranges of a [`HybridRangeSet`].
+///
+/// This `struct` is created by [`HybridRangeSet::ranges`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct HybridRangesIter<'a, T: Integer> {
+    full: Peekable<RangesIter<'a, u128>>,
+    chunks: Peekable<btree_map::Iter<'a, u128, Chunk>>,
+    // The runs of the chunk being read, and the ordinal of the chunk's first integer.
+    current: Option<(u128, ChunkRuns<'a>)>,
+    // A run, in ordinals, that may yet touch the next one.
+    pending: Option<(u128, u128)>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: Integer> HybridRangesIter<'_, T> {
+    // Returns the next run in ordinals. Runs from neighboring chunks may touch.
+    fn next_run(&mut self) -> Option<(u128, u128)> {
+        loop {
+            if let Some((base, runs)) = &mut self.current {
+                if let Some((start, end)) = runs.next() {
+                    return Some((*base + u128::from(start), *base + u128::from(end)));
+                }
+                self.current = None;
+            }
+            let next_full = self.full.peek().map(|keys| *keys.start());
+            let next_chunk = self.chunks.peek().map(|(&key, _)| key);
+            match (next_full, next_chunk) {
+                (None, None) => return None,
+                (Some(full_key), Some(chunk_key)) if chunk_key < full_key => {}
+                (Some(_), _) => {
+                    let (start_key, end_key) = self.full.next().unwrap().into_inner();
+                    return Some((
+                        start_key << CHUNK_BITS,
+                        (end_key << CHUNK_BITS) | u128::from(CHUNK_MAX),
+                    ));
+                }
+                (None, Some(_)) => {}
+            }
+            let (&key, chunk) = self.chunks.next().unwrap();
+            self.current = Some((key << CHUNK_BITS, chunk.runs()));
+        }
+    }
+}
+
+impl<T: Integer> FusedIterator for HybridRangesIter<'_, T> {}
+
+impl<T: Integer> Iterator for HybridRangesIter<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        while let Some((start, end)) = self.next_run() {
+            match self.pending {
+                Some((pending_start, pending_end)) if pending_end + 1 == start => {
+                    self.pending = Some((pending_start, end));
+                }
+                Some((pending_start, pending_end)) => {
+                    self.pending = Some((start, end));
+                    return Some(from_ordinal(pending_start)..=from_ordinal(pending_end));
+                }
+                None => self.pending = Some((start, end)),
+            }
+        }
+        self.pending
+            .take()
+            .map(|(start, end)| from_ordinal(start)..=from_ordinal(end))
+    }
+}
+
+impl<T: Integer> SortedStarts<T> for HybridRangesIter<'_, T> {}
+impl<T: Integer> SortedDisjoint<T> for HybridRangesIter<'_, T> {}
+
+impl<T: Integer> ops::Not for HybridRangesIter<'_, T> {
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: Integer, R> ops::BitOr<R> for HybridRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitAnd<R> for HybridRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::Sub<R> for HybridRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitXor<R> for HybridRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
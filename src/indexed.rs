@@ -0,0 +1,350 @@
+use alloc::{
+    collections::{btree_map, btree_set, BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::{
+    iter::FusedIterator,
+    ops::{Bound, RangeInclusive},
+};
+
+use crate::{Integer, RangeSetBlaze};
+
+/// A [`RangeSetBlaze`] that also keeps indexes of its range lengths and gap lengths, so that it can quickly
+/// answer questions such as "what is the longest range?" and "what is the longest gap?".
+///
+/// A *gap* is a run of missing integers between two ranges. The (possibly unbounded) runs before the first
+/// range and after the last range are not gaps.
+///
+/// The indexes are maintained incrementally as ranges are inserted and removed. They live in this
+/// wrapper rather than in [`RangeSetBlaze`] itself so that sets that don't need them don't pay to keep them up to date.
+///
+/// # Performance
+///
+/// With *n* ranges, [`longest_range`] and [`longest_gap`] are O(log *n*). Each edit is O(*k* log *n*), where
+/// *k* is the number of ranges the edit touches.
+///
+/// [`longest_range`]: IndexedRangeSetBlaze::longest_range
+/// [`longest_gap`]: IndexedRangeSetBlaze::longest_gap
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{IndexedRangeSetBlaze, RangeSetBlaze};
+///
+/// let mut set = IndexedRangeSetBlaze::from(RangeSetBlaze::from_iter([1..=3, 10..=19, 100..=102]));
+/// assert_eq!(set.longest_range(), Some(10..=19));
+/// assert_eq!(set.longest_gap(), Some(20..=99));
+///
+/// set.ranges_insert(50..=80);
+/// assert_eq!(set.longest_range(), Some(50..=80));
+/// assert_eq!(set.longest_gap(), Some(20..=49));
+/// assert_eq!(set.as_range_set_blaze().to_string(), "1..=3, 10..=19, 50..=80, 100..=102");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct IndexedRangeSetBlaze<T: Integer> {
+    set: RangeSetBlaze<T>,
+    ranges_by_len: BTreeSet<(T::SafeLen, T)>,
+    gaps_by_len: BTreeSet<(T::SafeLen, T)>,
+    histogram: BTreeMap<T::SafeLen, usize>,
+}
+
+impl<T: Integer> From<RangeSetBlaze<T>> for IndexedRangeSetBlaze<T> {
+    /// Builds the indexes for a [`RangeSetBlaze`]. This is O(*n* log *n*) in the number of ranges.
+    fn from(set: RangeSetBlaze<T>) -> Self {
+        let mut indexed = Self {
+            set,
+            ranges_by_len: BTreeSet::new(),
+            gaps_by_len: BTreeSet::new(),
+            histogram: BTreeMap::new(),
+        };
+        let ranges: Vec<_> = indexed.set.ranges().collect();
+        indexed.index(&ranges, None, None, true);
+        indexed
+    }
+}
+
+impl<T: Integer> AsRef<RangeSetBlaze<T>> for IndexedRangeSetBlaze<T> {
+    fn as_ref(&self) -> &RangeSetBlaze<T> {
+        &self.set
+    }
+}
+
+impl<T: Integer> IndexedRangeSetBlaze<T> {
+    /// Makes a new, empty [`IndexedRangeSetBlaze`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::from(RangeSetBlaze::new())
+    }
+
+    /// Returns the underlying [`RangeSetBlaze`].
+    #[must_use]
+    pub const fn as_range_set_blaze(&self) -> &RangeSetBlaze<T> {
+        &self.set
+    }
+
+    /// Returns the underlying [`RangeSetBlaze`], dropping the indexes.
+    #[must_use]
+    pub fn into_range_set_blaze(self) -> RangeSetBlaze<T> {
+        self.set
+    }
+
+    /// Adds a value to the set. Returns whether the value was newly inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    pub fn insert(&mut self, value: T) -> bool {
+        self.ranges_insert(value..=value)
+    }
+
+    /// Adds a range to the set. Returns whether any values were newly inserted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of the range is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    pub fn ranges_insert(&mut self, range: RangeInclusive<T>) -> bool {
+        self.edit(range, true)
+    }
+
+    /// Removes a value from the set. Returns whether the value was present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    pub fn remove(&mut self, value: T) -> bool {
+        self.ranges_remove(value..=value)
+    }
+
+    /// Removes a range from the set. Returns whether any values were present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of the range is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{IndexedRangeSetBlaze, RangeSetBlaze};
+    ///
+    /// let mut set = IndexedRangeSetBlaze::from(RangeSetBlaze::from_iter([1..=100]));
+    /// assert!(set.ranges_remove(40..=49));
+    /// assert!(!set.ranges_remove(40..=49));
+    /// assert_eq!(set.longest_range(), Some(50..=100));
+    /// assert_eq!(set.longest_gap(), Some(40..=49));
+    /// ```
+    pub fn ranges_remove(&mut self, range: RangeInclusive<T>) -> bool {
+        self.edit(range, false)
+    }
+
+    /// Returns the longest range in the set, or `None` if the set is empty. Ties go to the range with the lowest start.
+    #[must_use]
+    pub fn longest_range(&self) -> Option<RangeInclusive<T>> {
+        longest(&self.ranges_by_len)
+    }
+
+    /// Returns the longest gap (run of missing integers between two ranges), or `None` if the set has fewer
+    /// than two ranges. Ties go to the gap with the lowest start.
+    #[must_use]
+    pub fn longest_gap(&self) -> Option<RangeInclusive<T>> {
+        longest(&self.gaps_by_len)
+    }
+
+    /// Returns an iterator over the ranges longer than `len`, shortest first. Ties are ordered by start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{IndexedRangeSetBlaze, RangeSetBlaze};
+    ///
+    /// let set = IndexedRangeSetBlaze::from(RangeSetBlaze::from_iter([1u32..=3, 10..=19, 30..=32, 100..=101]));
+    /// let long: Vec<_> = set.ranges_longer_than(2).collect();
+    /// assert_eq!(long, vec![1..=3, 30..=32, 10..=19]);
+    /// ```
+    pub fn ranges_longer_than(&self, len: T::SafeLen) -> RangesLongerThan<'_, T> {
+        RangesLongerThan {
+            iter: self
+                .ranges_by_len
+                .range((Bound::Excluded((len, T::max_value())), Bound::Unbounded)),
+        }
+    }
+
+    /// Returns an iterator over the distinct range lengths in the set, shortest first, each with the number of ranges of that length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{IndexedRangeSetBlaze, RangeSetBlaze};
+    ///
+    /// let set = IndexedRangeSetBlaze::from(RangeSetBlaze::from_iter([1u32..=3, 10..=19, 30..=32, 100..=101]));
+    /// let histogram: Vec<_> = set.len_histogram().collect();
+    /// assert_eq!(histogram, vec![(2, 1), (3, 2), (10, 1)]);
+    /// ```
+    pub fn len_histogram(&self) -> LenHistogram<'_, T> {
+        LenHistogram {
+            iter: self.histogram.iter(),
+        }
+    }
+
+    // Replaces the ranges that touch `range` (overlap it or are adjacent to it) with their union with,
+    // or difference from, `range`, keeping the indexes in sync.
+    fn edit(&mut self, range: RangeInclusive<T>, insert: bool) -> bool {
+        let (start, end) = range.clone().into_inner();
+        assert!(
+            end <= T::safe_max_value(),
+            "end must be <= T::safe_max_value()"
+        );
+        if end < start {
+            return false;
+        }
+        let lower = if start > T::min_value() {
+            start - T::one()
+        } else {
+            start
+        };
+        let upper = if end < T::safe_max_value() {
+            end + T::one()
+        } else {
+            end
+        };
+        let btree_map = &self.set.btree_map;
+        let first_start = match btree_map.range(..=lower).next_back() {
+            Some((&first_start, &first_end)) if lower <= first_end => first_start,
+            _ => lower,
+        };
+        let before: Vec<_> = btree_map
+            .range(first_start..=upper)
+            .map(|(&start, &end)| start..=end)
+            .collect();
+        let prev_end = btree_map
+            .range(..first_start)
+            .next_back()
+            .map(|(_, &end)| end);
+        let next_start = btree_map
+            .range((Bound::Excluded(upper), Bound::Unbounded))
+            .next()
+            .map(|(&start, _)| start);
+
+        let before_set = RangeSetBlaze::from_iter(before.iter().cloned());
+        let edit_set = RangeSetBlaze::from_iter([range]);
+        let after_set = if insert {
+            &before_set | &edit_set
+        } else {
+            &before_set - &edit_set
+        };
+        if after_set == before_set {
+            return false;
+        }
+
+        self.index(&before, prev_end, next_start, false);
+        for range in &before {
+            self.set.btree_map.remove(range.start());
+            self.set.len -= T::safe_len(range);
+        }
+        let after: Vec<_> = after_set.ranges().collect();
+        for range in &after {
+            self.set.btree_map.insert(*range.start(), *range.end());
+            self.set.len += T::safe_len(range);
+        }
+        self.index(&after, prev_end, next_start, true);
+        true
+    }
+
+    // Adds (or removes) index entries for consecutive `ranges` and for the gaps between them, and between them
+    // and their neighbors: the range ending at `prev_end` and the range starting at `next_start`.
+    fn index(
+        &mut self,
+        ranges: &[RangeInclusive<T>],
+        prev_end: Option<T>,
+        next_start: Option<T>,
+        add: bool,
+    ) {
+        for range in ranges {
+            let len = T::safe_len(range);
+            let count = self.histogram.entry(len).or_insert(0);
+            if add {
+                self.ranges_by_len.insert((len, *range.start()));
+                *count += 1;
+            } else {
+                self.ranges_by_len.remove(&(len, *range.start()));
+                *count -= 1;
+                if *count == 0 {
+                    self.histogram.remove(&len);
+                }
+            }
+        }
+        // Each gap runs from the end of one range to the start of the next.
+        let ends = prev_end
+            .into_iter()
+            .chain(ranges.iter().map(|range| *range.end()));
+        let starts = ranges
+            .iter()
+            .map(|range| *range.start())
+            .skip(usize::from(prev_end.is_none()))
+            .chain(next_start);
+        for (end, start) in ends.zip(starts) {
+            let gap = end + T::one()..=start - T::one();
+            let key = (T::safe_len(&gap), *gap.start());
+            if add {
+                self.gaps_by_len.insert(key);
+            } else {
+                self.gaps_by_len.remove(&key);
+            }
+        }
+    }
+}
+
+fn longest<T: Integer>(by_len: &BTreeSet<(T::SafeLen, T)>) -> Option<RangeInclusive<T>> {
+    let &(len, _) = by_len.last()?;
+    let &(len, start) = by_len.range((len, T::min_value())..).next()?;
+    Some(start..=T::add_len_less_one(start, len))
+}
+
+/// An iterator over the ranges of an [`IndexedRangeSetBlaze`] that are longer than a given length, shortest first.
+///
+/// This `struct` is created by [`IndexedRangeSetBlaze::ranges_longer_than`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RangesLongerThan<'a, T: Integer> {
+    iter: btree_set::Range<'a, (T::SafeLen, T)>,
+}
+
+impl<T: Integer> Iterator for RangesLongerThan<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|&(len, start)| start..=T::add_len_less_one(start, len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: Integer> FusedIterator for RangesLongerThan<'_, T> {}
+
+/// An iterator over the distinct range lengths of an [`IndexedRangeSetBlaze`], each with its number of ranges.
+///
+/// This `struct` is created by [`IndexedRangeSetBlaze::len_histogram`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LenHistogram<'a, T: Integer> {
+    iter: btree_map::Iter<'a, T::SafeLen, usize>,
+}
+
+impl<T: Integer> Iterator for LenHistogram<'_, T> {
+    type Item = (T::SafeLen, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(&len, &count)| (len, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T: Integer> ExactSizeIterator for LenHistogram<'_, T> {}
+
+impl<T: Integer> FusedIterator for LenHistogram<'_, T> {}
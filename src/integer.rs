@@ -1,27 +1,47 @@
 #[cfg(feature = "from_slice")]
 use crate::{from_slice::FromSliceIter, RangeSetBlaze};
 use core::ops::RangeInclusive;
+#[cfg(feature = "std")]
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 #[cfg(feature = "from_slice")]
 const LANES: usize = 16;
 
-use crate::Integer;
+use crate::UIntPlusOne;
+use crate::{Element, Integer};
+use num_traits::ToPrimitive;
 
-impl Integer for i8 {
+impl Element for i8 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = usize;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = usize;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as u8 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as u8 as <Self as Element>::SafeLen + 1
     }
+}
+
+impl Integer for i8 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -36,22 +56,37 @@ impl Integer for i8 {
     }
 }
 
-impl Integer for u8 {
+impl Element for u8 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = usize;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = usize;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as <Self as Element>::SafeLen + 1
     }
+}
 
+impl Integer for u8 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -66,16 +101,37 @@ impl Integer for u8 {
     }
 }
 
-impl Integer for i32 {
+impl Element for i32 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = u64;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = usize;
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as u32 as <Self as Integer>::SafeLen + 1
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as u32 as <Self as Element>::SafeLen + 1
+    }
+
+    #[cfg(feature = "from_slice")]
+    #[inline]
+    fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
+        FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
+}
 
+impl Integer for i32 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -88,30 +144,39 @@ impl Integer for i32 {
     fn sub_len_less_one(a: Self, b: Self::SafeLen) -> Self {
         a - (b - 1) as Self
     }
-
-    #[cfg(feature = "from_slice")]
-    #[inline]
-    fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
-        FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
-    }
 }
 
-impl Integer for u32 {
+impl Element for u32 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = u64;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = usize;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as <Self as Element>::SafeLen + 1
     }
+}
 
+impl Integer for u32 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -126,21 +191,37 @@ impl Integer for u32 {
     }
 }
 
-impl Integer for i64 {
+impl Element for i64 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = u128;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = u128;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as u64 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as u64 as <Self as Element>::SafeLen + 1
     }
+}
+
+impl Integer for i64 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -155,21 +236,37 @@ impl Integer for i64 {
     }
 }
 
-impl Integer for u64 {
+impl Element for u64 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = u128;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = u128;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as <Self as Element>::SafeLen + 1
     }
+}
+
+impl Integer for u64 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -184,11 +281,22 @@ impl Integer for u64 {
     }
 }
 
-impl Integer for i128 {
-    #[cfg(target_pointer_width = "32")]
-    type SafeLen = u128;
-    #[cfg(target_pointer_width = "64")]
-    type SafeLen = u128;
+impl Element for i128 {
+    // The full range holds 2^128 values, so the length is a `UIntPlusOne<u128>` and every value can be used.
+    type SafeLen = UIntPlusOne<u128>;
+
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
 
     #[cfg(feature = "from_slice")]
     #[inline]
@@ -196,32 +304,42 @@ impl Integer for i128 {
         return slice.as_ref().iter().collect();
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as u128 as <Self as Integer>::SafeLen + 1
-    }
-    fn safe_max_value() -> Self {
-        Self::max_value() - 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        UIntPlusOne::UInt(r.end().overflowing_sub(*r.start()).0 as u128) + UIntPlusOne::UInt(1)
     }
+}
 
+impl Integer for i128 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
-        len as f64
+        len.to_f64().expect("every length converts to f64")
     }
     fn f64_to_safe_len(f: f64) -> Self::SafeLen {
-        f as Self::SafeLen
+        <Self::SafeLen as num_traits::NumCast>::from(f).unwrap_or(UIntPlusOne::MaxPlusOne)
     }
     fn add_len_less_one(a: Self, b: Self::SafeLen) -> Self {
-        a + (b - 1) as Self
+        a.wrapping_add(len_less_one(b) as Self)
     }
     fn sub_len_less_one(a: Self, b: Self::SafeLen) -> Self {
-        a - (b - 1) as Self
+        a.wrapping_sub(len_less_one(b) as Self)
     }
 }
 
-impl Integer for u128 {
-    #[cfg(target_pointer_width = "32")]
-    type SafeLen = u128;
-    #[cfg(target_pointer_width = "64")]
-    type SafeLen = u128;
+impl Element for u128 {
+    // The full range holds 2^128 values, so the length is a `UIntPlusOne<u128>` and every value can be used.
+    type SafeLen = UIntPlusOne<u128>;
+
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
 
     #[cfg(feature = "from_slice")]
     #[inline]
@@ -229,42 +347,57 @@ impl Integer for u128 {
         return slice.as_ref().iter().collect();
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as <Self as Integer>::SafeLen + 1
-    }
-    fn safe_max_value() -> Self {
-        Self::max_value() - 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        UIntPlusOne::UInt(r.end() - r.start()) + UIntPlusOne::UInt(1)
     }
+}
+
+impl Integer for u128 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
-        len as f64
+        len.to_f64().expect("every length converts to f64")
     }
     fn f64_to_safe_len(f: f64) -> Self::SafeLen {
-        f as Self::SafeLen
+        <Self::SafeLen as num_traits::NumCast>::from(f).unwrap_or(UIntPlusOne::MaxPlusOne)
     }
     fn add_len_less_one(a: Self, b: Self::SafeLen) -> Self {
-        a + (b - 1) as Self
+        a + len_less_one(b)
     }
     fn sub_len_less_one(a: Self, b: Self::SafeLen) -> Self {
-        a - (b - 1) as Self
+        a - len_less_one(b)
     }
 }
 
-impl Integer for isize {
+impl Element for isize {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = u64;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = u128;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as usize as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as usize as <Self as Element>::SafeLen + 1
     }
+}
 
+impl Integer for isize {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -279,22 +412,37 @@ impl Integer for isize {
     }
 }
 
-impl Integer for usize {
+impl Element for usize {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = u64;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = u128;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as <Self as Element>::SafeLen + 1
     }
+}
 
+impl Integer for usize {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -309,22 +457,37 @@ impl Integer for usize {
     }
 }
 
-impl Integer for i16 {
+impl Element for i16 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = usize;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = usize;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as u16 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as u16 as <Self as Element>::SafeLen + 1
     }
+}
 
+impl Integer for i16 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -339,22 +502,37 @@ impl Integer for i16 {
     }
 }
 
-impl Integer for u16 {
+impl Element for u16 {
     #[cfg(target_pointer_width = "32")]
     type SafeLen = usize;
     #[cfg(target_pointer_width = "64")]
     type SafeLen = usize;
 
+    fn min_value() -> Self {
+        Self::MIN
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        self + 1
+    }
+    fn sub_one(self) -> Self {
+        self - 1
+    }
+
     #[cfg(feature = "from_slice")]
     #[inline]
     fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
         FromSliceIter::<Self, LANES>::new(slice.as_ref()).collect()
     }
 
-    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen {
-        r.end().overflowing_sub(*r.start()).0 as <Self as Integer>::SafeLen + 1
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        r.end().overflowing_sub(*r.start()).0 as <Self as Element>::SafeLen + 1
     }
+}
 
+impl Integer for u16 {
     fn safe_len_to_f64(len: Self::SafeLen) -> f64 {
         len as f64
     }
@@ -368,3 +546,97 @@ impl Integer for u16 {
         a - (b - 1) as Self
     }
 }
+
+#[cfg(feature = "std")]
+impl Element for Ipv4Addr {
+    #[cfg(target_pointer_width = "32")]
+    type SafeLen = u64;
+    #[cfg(target_pointer_width = "64")]
+    type SafeLen = usize;
+
+    fn min_value() -> Self {
+        Self::UNSPECIFIED
+    }
+    fn max_value() -> Self {
+        Self::BROADCAST
+    }
+    fn add_one(self) -> Self {
+        Self::from(u32::from(self) + 1)
+    }
+    fn sub_one(self) -> Self {
+        Self::from(u32::from(self) - 1)
+    }
+
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        (u32::from(*r.end()) - u32::from(*r.start())) as <Self as Element>::SafeLen + 1
+    }
+}
+
+// Every address can be used: the length of the full range, 2^128, is `UIntPlusOne::MaxPlusOne`.
+#[cfg(feature = "std")]
+impl Element for Ipv6Addr {
+    type SafeLen = UIntPlusOne<u128>;
+
+    fn min_value() -> Self {
+        Self::UNSPECIFIED
+    }
+    fn max_value() -> Self {
+        Self::from(u128::MAX)
+    }
+    fn add_one(self) -> Self {
+        Self::from(u128::from(self) + 1)
+    }
+    fn sub_one(self) -> Self {
+        Self::from(u128::from(self) - 1)
+    }
+
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        UIntPlusOne::UInt(u128::from(*r.end()) - u128::from(*r.start())) + UIntPlusOne::UInt(1)
+    }
+}
+
+// Returns `len - 1`, which always fits in a `u128` because a length is at least one.
+fn len_less_one(len: UIntPlusOne<u128>) -> u128 {
+    let UIntPlusOne::UInt(len_less_one) = len - UIntPlusOne::UInt(1) else {
+        unreachable!("one less than a length always fits in a u128")
+    };
+    len_less_one
+}
+
+// The surrogates, 0xD800 to 0xDFFF, aren't `char`s, so 0xD7FF and 0xE000 are adjacent.
+const SURROGATE_START: u32 = 0xD800;
+const SURROGATE_LEN: u32 = 0x800;
+
+impl Element for char {
+    type SafeLen = usize;
+
+    fn min_value() -> Self {
+        '\0'
+    }
+    fn max_value() -> Self {
+        Self::MAX
+    }
+    fn add_one(self) -> Self {
+        if self == '\u{D7FF}' {
+            '\u{E000}'
+        } else {
+            Self::from_u32(self as u32 + 1).expect("next value must be a char")
+        }
+    }
+    fn sub_one(self) -> Self {
+        if self == '\u{E000}' {
+            '\u{D7FF}'
+        } else {
+            Self::from_u32(self as u32 - 1).expect("previous value must be a char")
+        }
+    }
+
+    fn safe_len(r: &RangeInclusive<Self>) -> <Self as Element>::SafeLen {
+        let (start, end) = (*r.start() as u32, *r.end() as u32);
+        let mut len = end - start + 1;
+        if start < SURROGATE_START && SURROGATE_START < end {
+            len -= SURROGATE_LEN;
+        }
+        len as <Self as Element>::SafeLen
+    }
+}
@@ -23,21 +23,61 @@
 extern crate alloc;
 
 // FUTURE: Support serde via optional feature
+mod allocator;
+#[cfg(feature = "arrow")]
+mod arrow_interop;
+mod bitmap_words;
+mod cidr;
+mod contains_sorted;
+mod convert;
+mod cursor;
 mod dyn_sorted_disjoint;
+mod elias_fano;
 mod from_slice;
+mod frozen;
+mod hybrid;
+mod indexed;
 mod integer;
+mod max_len_index;
 mod merge;
+mod minkowski;
+mod neighbors;
 mod not_iter;
+mod periodic;
+mod predicate;
 pub mod prelude;
+#[cfg(feature = "range-collections")]
+mod range_collections_interop;
+#[cfg(feature = "rangemap")]
+mod rangemap_interop;
 mod ranges;
+#[cfg(feature = "roaring")]
+mod roaring_interop;
 #[cfg(feature = "rog-experimental")]
 mod rog;
+mod seek;
 mod sorted_disjoint;
 mod tests;
+mod uint_plus_one;
 mod union_iter;
 mod unsorted_disjoint;
+mod validate;
+mod view;
 pub use crate::ranges::{IntoRangesIter, RangesIter};
-use alloc::{collections::BTreeMap, vec::Vec};
+pub use allocator::{DoubleFreeError, FitPolicy, RangeAllocator};
+#[cfg(feature = "arrow")]
+pub use arrow_interop::{ArrowInteger, ArrowRangeError, ArrowRangesIter, NullPolicy};
+#[cfg(feature = "std")]
+pub use cidr::AddrRangesIter;
+pub use cidr::{Cidr, CidrError, CidrInteger, CidrIter};
+pub use contains_sorted::{ContainsSorted, FilterMembers, FilterNonMembers};
+pub use convert::{SignBias, TryConvertError};
+pub use cursor::{Cursor, CursorMut};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 use core::{
     cmp::{max, Ordering},
     convert::From,
@@ -47,68 +87,133 @@ use core::{
     str::FromStr,
 };
 pub use dyn_sorted_disjoint::DynSortedDisjoint;
+pub use elias_fano::{EliasFanoRangeSet, EliasFanoRangesIter};
+pub use frozen::{FrozenRangeSet, FrozenRangesIter};
+pub use indexed::{IndexedRangeSetBlaze, LenHistogram, RangesLongerThan};
 use gen_ops::gen_ops_ex;
+pub use hybrid::{HybridRangeSet, HybridRangesIter};
 use itertools::Tee;
 pub use merge::{KMerge, Merge};
+pub use neighbors::NeighborsSorted;
 pub use not_iter::NotIter;
+pub use periodic::Periodic;
 use num_traits::{ops::overflowing::OverflowingSub, CheckedAdd, One, WrappingSub, Zero};
+#[cfg(feature = "derive")]
+pub use range_set_blaze_derive::Element;
+#[cfg(feature = "range-collections")]
+pub use range_collections_interop::RangeCollectionsRangesIter;
+#[cfg(feature = "rangemap")]
+pub use rangemap_interop::RangeMapRangesIter;
+#[cfg(feature = "roaring")]
+pub use roaring_interop::{RoaringRangesIter, RoaringTreemapRangesIter};
 #[cfg(feature = "rog-experimental")]
 pub use rog::{Rog, RogsIter};
 pub use sorted_disjoint::{CheckSortedDisjoint, SortedDisjoint, SortedStarts};
+pub use uint_plus_one::UIntPlusOne;
 pub use union_iter::UnionIter;
-pub use unsorted_disjoint::AssumeSortedStarts;
 use unsorted_disjoint::SortedDisjointWithLenSoFar;
 use unsorted_disjoint::UnsortedDisjoint;
+pub use unsorted_disjoint::{AssumeSortedStarts, CheckSortedStarts};
+pub use validate::{SortedDisjointError, ValidateSortedDisjoint};
+pub use view::{LeBytes, RangeSetView, ViewError, ViewRangesIter};
 
-/// The element trait of the [`RangeSetBlaze`] and [`SortedDisjoint`], specifically `u8` to `u128` (including `usize`) and `i8` to `i128` (including `isize`).
-pub trait Integer:
-    num_integer::Integer
-    + FromStr
-    + Copy
-    + fmt::Display
-    + fmt::Debug
-    + core::iter::Sum
-    + num_traits::NumAssignOps
-    + num_traits::Bounded
-    + num_traits::NumCast
-    + Send
-    + Sync
-    + OverflowingSub
-    + CheckedAdd
-    + WrappingSub
-{
+/// The element trait of the [`RangeSetBlaze`] and [`SortedDisjoint`]: any type with a total order, a smallest and
+/// largest value, and a next and previous value.
+///
+/// It is implemented for `u8` to `u128` (including `usize`), `i8` to `i128` (including `isize`), `char`,
+/// [`Ipv4Addr`](core::net::Ipv4Addr), and [`Ipv6Addr`](core::net::Ipv6Addr). For `char`, the values skip the surrogates, so `'\u{D7FF}'` and
+/// `'\u{E000}'` are adjacent. The primitive integers also implement [`Integer`], which adds the arithmetic that some
+/// operations, for example [`RangeSetBlaze::from_monotone`], need.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::RangeSetBlaze;
+/// use std::net::Ipv4Addr;
+///
+/// let set = RangeSetBlaze::from_iter([Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 0, 0, 255)]);
+/// assert!(set.contains(Ipv4Addr::new(10, 0, 0, 7)));
+/// assert_eq!(set.len(), 256);
+///
+/// let letters = RangeSetBlaze::from_iter(['a'..='z', 'A'..='Z', '\u{D7FC}'..='\u{D7FF}', '\u{E000}'..='\u{E0FF}']);
+/// assert_eq!(letters.to_string(), r"'A'..='Z', 'a'..='z', '\u{d7fc}'..='\u{e0ff}'");
+/// assert_eq!(letters.len(), 52 + 0x104);
+/// assert_eq!((!letters).ranges_len(), 4);
+/// ```
+///
+/// Your own types can implement `Element`, too. A newtype usually forwards to the type it wraps. With the
+/// `derive` feature, `#[derive(Element)]` writes this implementation for any `#[repr(transparent)]`
+/// wrapper of a type that implements `Element`.
+///
+/// ```
+/// use core::ops::RangeInclusive;
+/// use range_set_blaze::{Element, RangeSetBlaze};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// struct UserId(u32);
+///
+/// impl Element for UserId {
+///     type SafeLen = <u32 as Element>::SafeLen;
+///
+///     fn safe_len(range: &RangeInclusive<Self>) -> Self::SafeLen {
+///         u32::safe_len(&(range.start().0..=range.end().0))
+///     }
+///     fn min_value() -> Self {
+///         Self(u32::MIN)
+///     }
+///     fn max_value() -> Self {
+///         Self(u32::MAX)
+///     }
+///     fn add_one(self) -> Self {
+///         Self(self.0 + 1)
+///     }
+///     fn sub_one(self) -> Self {
+///         Self(self.0 - 1)
+///     }
+/// }
+///
+/// let banned = RangeSetBlaze::from_iter([UserId(10)..=UserId(19), UserId(20)..=UserId(20)]);
+/// assert_eq!(banned.ranges_len(), 1);
+/// assert_eq!(banned.len(), 11);
+/// assert_eq!(banned.to_string(), "UserId(10)..=UserId(20)");
+/// ```
+pub trait Element: Copy + Ord + fmt::Debug + Send + Sync {
     #[cfg(feature = "from_slice")]
-    /// A definition of [`RangeSetBlaze::from_slice()`] specific to this integer type.
-    fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self>;
+    /// A definition of [`RangeSetBlaze::from_slice()`] specific to this element type.
+    fn from_slice(slice: impl AsRef<[Self]>) -> RangeSetBlaze<Self> {
+        slice.as_ref().iter().collect()
+    }
 
     /// The type of the length of a [`RangeSetBlaze`]. For example, the length of a `RangeSetBlaze<u8>` is `usize`. Note
     /// that it can't be `u8` because the length ranges from 0 to 256, which is one too large for `u8`.
     ///
     /// In general, `SafeLen` will be `usize` if `usize` is always large enough. If not, `SafeLen` will be the smallest unsigned integer
-    /// type that is always large enough. However, for `u128` and `i128`, nothing is always large enough so
-    ///  `SafeLen` will be `u128` and we prohibit the largest value from being used in [`Integer`].
+    /// type that is always large enough. However, for `u128`, `i128`, and [`Ipv6Addr`](core::net::Ipv6Addr), no primitive
+    /// is always large enough, so `SafeLen` is [`UIntPlusOne<u128>`], which holds every count up to and including 2^128.
     ///
     /// # Examples
     /// ```
-    /// use range_set_blaze::{RangeSetBlaze, Integer};
+    /// use range_set_blaze::{RangeSetBlaze, Element, UIntPlusOne};
     ///
-    /// let len: <u8 as Integer>::SafeLen = RangeSetBlaze::from_iter([0u8..=255]).len();
+    /// let len: <u8 as Element>::SafeLen = RangeSetBlaze::from_iter([0u8..=255]).len();
     /// assert_eq!(len, 256);
+    ///
+    /// let len: <u128 as Element>::SafeLen = RangeSetBlaze::from_iter([0..=u128::MAX]).len();
+    /// assert_eq!(len, UIntPlusOne::MaxPlusOne);
     /// ```
     type SafeLen: core::hash::Hash
-        + num_integer::Integer
-        + num_traits::NumAssignOps
-        + num_traits::Bounded
-        + num_traits::NumCast
+        + num_traits::Zero
         + num_traits::One
         + core::ops::AddAssign
         + core::ops::SubAssign
+        + core::ops::Sub<Output = Self::SafeLen>
         + Copy
         + PartialEq
         + Eq
         + PartialOrd
         + Ord
         + Send
+        + Sync
         + Default
         + fmt::Debug
         + fmt::Display;
@@ -117,41 +222,71 @@ pub trait Integer:
     ///
     /// # Example
     /// ```
-    /// use range_set_blaze::Integer;
+    /// use range_set_blaze::Element;
     ///
-    /// assert_eq!(<u8 as Integer>::safe_len(&(0..=255)), 256);
+    /// assert_eq!(<u8 as Element>::safe_len(&(0..=255)), 256);
     /// ```
-    fn safe_len(range: &RangeInclusive<Self>) -> <Self as Integer>::SafeLen;
+    fn safe_len(range: &RangeInclusive<Self>) -> <Self as Element>::SafeLen;
+
+    /// Returns the smallest value of the type.
+    fn min_value() -> Self;
 
-    /// For a given `Integer` type, returns the largest value that can be used. For all types other than `u128` and `i128`,
-    /// this is the same as `Self::MAX`. For `u128` and `i128`, this is one less than `Self::MAX`.
+    /// Returns the largest value of the type.
+    fn max_value() -> Self;
+
+    /// For a given `Element` type, returns the largest value that can be used. Every built-in element type can use
+    /// its whole range, so this is the same as `Self::max_value()`. A custom type whose [`Element::SafeLen`] can't
+    /// count its full range may return something smaller.
     ///
     /// # Example
     /// ```
-    /// use range_set_blaze::{Integer, RangeSetBlaze};
+    /// use range_set_blaze::{Element, RangeSetBlaze};
     ///
-    /// // for i8, we can use up to 127
-    /// let a = RangeSetBlaze::from_iter([i8::MAX]);
-    /// // for i128, we can use up to 170141183460469231731687303715884105726
-    /// let a = RangeSetBlaze::from_iter([<i128 as Integer>::safe_max_value()]);
-    /// ```
-    /// # Panics
-    /// ```should_panic
-    /// use range_set_blaze::{Integer, RangeSetBlaze};
-    ///
-    /// // for i128, using 170141183460469231731687303715884105727 throws a panic.
+    /// assert_eq!(<i128 as Element>::safe_max_value(), i128::MAX);
     /// let a = RangeSetBlaze::from_iter([i128::MAX]);
+    /// assert!(a.contains(i128::MAX));
     /// ```
     fn safe_max_value() -> Self {
         Self::max_value()
     }
 
+    /// Returns the next value. The result is unspecified for the largest value.
+    #[must_use]
+    fn add_one(self) -> Self;
+
+    /// Returns the previous value. The result is unspecified for the smallest value.
+    #[must_use]
+    fn sub_one(self) -> Self;
+}
+
+/// The primitive integer element types, specifically `u8` to `u128` (including `usize`) and `i8` to `i128` (including `isize`).
+///
+/// Beyond [`Element`], it supplies the arithmetic used by operations such as [`RangeSetBlaze::from_monotone`],
+/// [`Periodic`], and [`RangeAllocator`].
+pub trait Integer:
+    Element<
+        SafeLen: num_integer::Integer
+                     + num_traits::NumAssignOps
+                     + num_traits::Bounded
+                     + num_traits::NumCast
+                     + core::ops::AddAssign
+                     + core::ops::SubAssign,
+    > + num_integer::Integer
+    + FromStr
+    + fmt::Display
+    + core::iter::Sum
+    + num_traits::NumAssignOps
+    + num_traits::NumCast
+    + OverflowingSub
+    + CheckedAdd
+    + WrappingSub
+{
     // FUTURE define .len() SortedDisjoint
 
-    /// Converts a `f64` to [`Integer::SafeLen`] using the formula `f as Self::SafeLen`. For large integer types, this will result in a loss of precision.
+    /// Converts a `f64` to [`Element::SafeLen`] using the formula `f as Self::SafeLen`. For large integer types, this will result in a loss of precision.
     fn f64_to_safe_len(f: f64) -> Self::SafeLen;
 
-    /// Converts [`Integer::SafeLen`] to `f64` using the formula `len as f64`. For large integer types, this will result in a loss of precision.
+    /// Converts [`Element::SafeLen`] to `f64` using the formula `len as f64`. For large integer types, this will result in a loss of precision.
     fn safe_len_to_f64(len: Self::SafeLen) -> f64;
 
     /// Computes `a + (b - 1) as Self`
@@ -384,25 +519,180 @@ pub trait Integer:
 /// See the [module-level documentation] for additional examples.
 ///
 /// [module-level documentation]: index.html
-pub struct RangeSetBlaze<T: Integer> {
-    len: <T as Integer>::SafeLen,
+pub struct RangeSetBlaze<T: Element> {
+    len: <T as Element>::SafeLen,
     btree_map: BTreeMap<T, T>,
 }
 
 // FUTURE: Make all RangeSetBlaze iterators DoubleEndedIterator and ExactSizeIterator.
-impl<T: Integer> fmt::Debug for RangeSetBlaze<T> {
+impl<T: Element> fmt::Debug for RangeSetBlaze<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.ranges().to_string())
     }
 }
 
-impl<T: Integer> fmt::Display for RangeSetBlaze<T> {
+impl<T: Element> fmt::Display for RangeSetBlaze<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.ranges().to_string())
     }
 }
 
-impl<T: Integer> RangeSetBlaze<T> {
+impl<T: Element + FromStr> FromStr for RangeSetBlaze<T> {
+    type Err = ParseRangeSetError;
+
+    /// Parses the format written by [`Display`], comma-separated inclusive ranges such as `1..=3, 7..=7`.
+    /// A single value, such as `7`, may stand for a one-element range, and an empty string is the empty set.
+    ///
+    /// The ranges may be in any order and may overlap.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseRangeSetError`] if a value doesn't parse, a range's start is greater than its end, or
+    /// a value is greater than `T::safe_max_value()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let set: RangeSetBlaze<Ipv4Addr> = "10.0.0.0..=10.0.0.255, 192.168.1.1".parse()?;
+    /// assert_eq!(set.to_string(), "10.0.0.0..=10.0.0.255, 192.168.1.1..=192.168.1.1");
+    /// assert_eq!(set.to_string().parse::<RangeSetBlaze<Ipv4Addr>>()?, set);
+    ///
+    /// let set: RangeSetBlaze<i8> = "5..=7, -3, 1..=6".parse()?;
+    /// assert_eq!(set.to_string(), "-3..=-3, 1..=7");
+    /// # Ok::<(), range_set_blaze::ParseRangeSetError>(())
+    /// ```
+    ///
+    /// [`Display`]: fmt::Display
+    fn from_str(s: &str) -> Result<Self, ParseRangeSetError> {
+        let parse = |text: &str| {
+            let value = text
+                .trim()
+                .parse::<T>()
+                .map_err(|_| ParseRangeSetError::Element(text.trim().to_string()))?;
+            if value > T::safe_max_value() {
+                return Err(ParseRangeSetError::TooLarge(text.trim().to_string()));
+            }
+            Ok(value)
+        };
+        if s.trim().is_empty() {
+            return Ok(Self::new());
+        }
+        let mut ranges = Vec::new();
+        for text in s.split(',') {
+            let range = match text.split_once("..=") {
+                Some((start, end)) => parse(start)?..=parse(end)?,
+                None => {
+                    let value = parse(text)?;
+                    value..=value
+                }
+            };
+            if range.start() > range.end() {
+                return Err(ParseRangeSetError::Backwards(text.trim().to_string()));
+            }
+            ranges.push(range);
+        }
+        Ok(Self::from_iter(ranges))
+    }
+}
+
+/// The error type returned when a [`RangeSetBlaze`] can't be parsed from a string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRangeSetError {
+    /// The text isn't a value of the element type.
+    Element(String),
+    /// The range's start is greater than its end.
+    Backwards(String),
+    /// The value is greater than the element type's `safe_max_value()`.
+    TooLarge(String),
+}
+
+impl fmt::Display for ParseRangeSetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Element(text) => write!(f, "'{text}' is not a valid value"),
+            Self::Backwards(text) => write!(f, "range '{text}' has a start greater than its end"),
+            Self::TooLarge(text) => write!(f, "'{text}' is greater than the largest usable value"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseRangeSetError {}
+
+/// The error type returned by the fallible constructors and mutators, such as [`RangeSetBlaze::try_from_iter`],
+/// [`RangeSetBlaze::try_insert`], and [`CheckSortedDisjoint::try_new`], in place of a panic.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{CheckSortedDisjoint, RangeSetError};
+///
+/// let err = CheckSortedDisjoint::try_new([1..=3, 2..=5]).unwrap_err();
+/// assert_eq!(err, RangeSetError::NotSortedDisjoint { prev_end: 3, range: 2..=5 });
+/// assert_eq!(err.to_string(), "range 2..=5 doesn't start after the previous range, which ends at 3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RangeSetError<T: Element> {
+    /// The value is greater than the element type's [`Element::safe_max_value`].
+    TooLarge(T),
+    /// The range's start is greater than its end.
+    Backwards(RangeInclusive<T>),
+    /// The range doesn't start after, and apart from, the end of the range before it.
+    NotSortedDisjoint {
+        /// The end of the range before.
+        prev_end: T,
+        /// The offending range.
+        range: RangeInclusive<T>,
+    },
+    /// The range starts before the range before it.
+    NotSortedStarts {
+        /// The start of the range before.
+        prev_start: T,
+        /// The offending range.
+        range: RangeInclusive<T>,
+    },
+    /// The iterator returned a range after returning `None`.
+    SomeAfterNone,
+}
+
+impl<T: Element> fmt::Display for RangeSetError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooLarge(value) => {
+                write!(f, "{value:?} is greater than the largest usable value")
+            }
+            Self::Backwards(range) => {
+                write!(f, "range {range:?} has a start greater than its end")
+            }
+            Self::NotSortedDisjoint { prev_end, range } => write!(
+                f,
+                "range {range:?} doesn't start after the previous range, which ends at {prev_end:?}"
+            ),
+            Self::NotSortedStarts { prev_start, range } => write!(
+                f,
+                "range {range:?} starts before the previous range, which starts at {prev_start:?}"
+            ),
+            Self::SomeAfterNone => write!(f, "iterator cannot return Some after returning None"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Element> std::error::Error for RangeSetError<T> {}
+
+// Checks that a non-empty range ends at or below `T::safe_max_value()`.
+fn check_end<T: Element>(range: &RangeInclusive<T>) -> Result<(), RangeSetError<T>> {
+    let (start, end) = (*range.start(), *range.end());
+    if start <= end && end > T::safe_max_value() {
+        return Err(RangeSetError::TooLarge(end));
+    }
+    Ok(())
+}
+
+impl<T: Element> RangeSetBlaze<T> {
     /// Gets an (double-ended) iterator that visits the integer elements in the [`RangeSetBlaze`] in
     /// ascending and/or descending order.
     ///
@@ -553,6 +843,46 @@ impl<T: Integer> RangeSetBlaze<T> {
         Self::from_sorted_disjoint(UnionIter::new(iter))
     }
 
+    /// Creates a [`RangeSetBlaze`] from an iterator of inclusive ranges, like [`RangeSetBlaze::from_iter`], but
+    /// returns an error instead of panicking. Overlapping, out-of-order, and empty ranges are fine. For an iterator
+    /// of values, map each value `x` to `x..=x`.
+    ///
+    /// *For more about constructors and performance, see [`RangeSetBlaze` Constructors](struct.RangeSetBlaze.html#rangesetblaze-constructors).*
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeSetError::TooLarge`] for the first non-empty range whose end is greater than
+    /// [`Element::safe_max_value`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::try_from_iter([1..=2, 2..=2, -10..=-5])?;
+    /// assert_eq!(a.to_string(), "-10..=-5, 1..=2");
+    /// let b = RangeSetBlaze::try_from_iter([3, 2, 1, 100, 1].map(|x| x..=x))?;
+    /// assert_eq!(b.to_string(), "1..=3, 100..=100");
+    /// # Ok::<(), range_set_blaze::RangeSetError<i32>>(())
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, RangeSetError<T>>
+    where
+        I: IntoIterator<Item = RangeInclusive<T>>,
+    {
+        let mut error = None;
+        let set = iter
+            .into_iter()
+            .map_while(|range| match check_end(&range) {
+                Ok(()) => Some(range),
+                Err(err) => {
+                    error = Some(err);
+                    None
+                }
+            })
+            .collect();
+        error.map_or(Ok(set), Err)
+    }
+
     /// Creates a [`RangeSetBlaze`] from a collection of integers. It is typically many
     /// times faster than [`from_iter`][1]/[`collect`][1].
     /// On a representative benchmark, the speed up was 7×.
@@ -593,7 +923,7 @@ impl<T: Integer> RangeSetBlaze<T> {
         T::from_slice(slice)
     }
 
-    fn _len_slow(&self) -> <T as Integer>::SafeLen {
+    fn _len_slow(&self) -> <T as Element>::SafeLen {
         Self::btree_map_len(&self.btree_map)
     }
 
@@ -645,7 +975,7 @@ impl<T: Integer> RangeSetBlaze<T> {
     /// ```
     pub fn clear(&mut self) {
         self.btree_map.clear();
-        self.len = <T as Integer>::SafeLen::zero();
+        self.len = <T as Element>::SafeLen::zero();
     }
 
     /// Returns `true` if the set contains no elements.
@@ -669,6 +999,11 @@ impl<T: Integer> RangeSetBlaze<T> {
     /// Returns `true` if the set is a subset of another,
     /// i.e., `other` contains at least all the elements in `self`.
     ///
+    /// # Performance
+    /// It merge-walks both sets in O(n+m) time, where n and m are the numbers of ranges in the two sets.
+    /// When one set has far fewer ranges than the other, it instead seeks into the larger set once per range
+    /// of the smaller, in O(n log m) time.
+    ///
     /// # Examples
     ///
     /// ```
@@ -690,6 +1025,9 @@ impl<T: Integer> RangeSetBlaze<T> {
         if self.len() > other.len() {
             return false;
         }
+        if seek::is_skewed(self, other) || seek::is_skewed(other, self) {
+            return self.is_subset_by_seek(other);
+        }
         self.ranges().is_subset(other.ranges())
     }
 
@@ -743,6 +1081,11 @@ impl<T: Integer> RangeSetBlaze<T> {
     /// Returns `true` if `self` has no elements in common with `other`.
     /// This is equivalent to checking for an empty intersection.
     ///
+    /// # Performance
+    /// It merge-walks both sets in O(n+m) time, where n and m are the numbers of ranges in the two sets.
+    /// When one set has far fewer ranges than the other, it instead seeks into the larger set once per range
+    /// of the smaller, in O(n log m) time.
+    ///
     /// # Examples
     ///
     /// ```
@@ -760,6 +1103,9 @@ impl<T: Integer> RangeSetBlaze<T> {
     #[must_use]
     #[inline]
     pub fn is_disjoint(&self, other: &Self) -> bool {
+        if seek::is_skewed(self, other) || seek::is_skewed(other, self) {
+            return self.is_disjoint_by_seek(other);
+        }
         self.ranges().is_disjoint(other.ranges())
     }
 
@@ -773,7 +1119,7 @@ impl<T: Integer> RangeSetBlaze<T> {
         let delete_list = after
             .map_while(|(start_delete, end_delete)| {
                 // must check this in two parts to avoid overflow
-                if *start_delete <= end || *start_delete <= end + T::one() {
+                if *start_delete <= end || *start_delete <= end.add_one() {
                     end_new = max(end_new, *end_delete);
                     self.len -= T::safe_len(&(*start_delete..=*end_delete));
                     Some(*start_delete)
@@ -783,7 +1129,7 @@ impl<T: Integer> RangeSetBlaze<T> {
             })
             .collect::<Vec<_>>();
         if end_new > end {
-            self.len += T::safe_len(&(end..=end_new - T::one()));
+            self.len += T::safe_len(&(end..=end_new.sub_one()));
             *end_after = end_new;
         }
         for start in delete_list {
@@ -821,6 +1167,31 @@ impl<T: Integer> RangeSetBlaze<T> {
         self.len != len_before
     }
 
+    /// Adds a value to the set, like [`RangeSetBlaze::insert`], but returns an error instead of panicking.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeSetError::TooLarge`] if the value is greater than [`Element::safe_max_value`]. The set is
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let mut set = RangeSetBlaze::new();
+    ///
+    /// assert_eq!(set.try_insert(2), Ok(true));
+    /// assert_eq!(set.try_insert(2), Ok(false));
+    /// assert_eq!(set.len(), 1usize);
+    /// ```
+    pub fn try_insert(&mut self, value: T) -> Result<bool, RangeSetError<T>> {
+        if value > T::safe_max_value() {
+            return Err(RangeSetError::TooLarge(value));
+        }
+        Ok(self.insert(value))
+    }
+
     /// Constructs an iterator over a sub-range of elements in the set.
     ///
     /// Not to be confused with [`RangeSetBlaze::ranges`], which returns an iterator over the ranges in the set.
@@ -861,12 +1232,12 @@ impl<T: Integer> RangeSetBlaze<T> {
     {
         let start = match range.start_bound() {
             Bound::Included(n) => *n,
-            Bound::Excluded(n) => *n + T::one(),
+            Bound::Excluded(n) => n.add_one(),
             Bound::Unbounded => T::min_value(),
         };
         let end = match range.end_bound() {
             Bound::Included(n) => *n,
-            Bound::Excluded(n) => *n - T::one(),
+            Bound::Excluded(n) => n.sub_one(),
             Bound::Unbounded => T::safe_max_value(),
         };
         assert!(start <= end);
@@ -906,6 +1277,33 @@ impl<T: Integer> RangeSetBlaze<T> {
         self.len != len_before
     }
 
+    /// Adds a range to the set, like [`RangeSetBlaze::ranges_insert`], but returns an error instead of panicking.
+    /// As with `ranges_insert`, an empty range, `start > end`, inserts nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeSetError::TooLarge`] if the range isn't empty and its end is greater than
+    /// [`Element::safe_max_value`]. The set is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let mut set = RangeSetBlaze::new();
+    ///
+    /// assert_eq!(set.try_ranges_insert(2..=5), Ok(true));
+    /// assert_eq!(set.try_ranges_insert(3..=4), Ok(false));
+    /// assert_eq!(set.len(), 4usize);
+    /// ```
+    pub fn try_ranges_insert(
+        &mut self,
+        range: RangeInclusive<T>,
+    ) -> Result<bool, RangeSetError<T>> {
+        check_end(&range)?;
+        Ok(self.ranges_insert(range))
+    }
+
     /// If the set contains an element equal to the value, removes it from the
     /// set and drops it. Returns whether such an element was present.
     ///
@@ -938,7 +1336,7 @@ impl<T: Integer> RangeSetBlaze<T> {
         let start = *start_ref;
         // special case if in range and start strictly less than value
         if start < value {
-            *end_ref = value - T::one();
+            *end_ref = value.sub_one();
             // special, special case if value == end
             if value == end {
                 self.len -= <T::SafeLen>::one();
@@ -950,7 +1348,7 @@ impl<T: Integer> RangeSetBlaze<T> {
             self.btree_map.remove(&start);
         };
         if value < end {
-            self.btree_map.insert(value + T::one(), end);
+            self.btree_map.insert(value.add_one(), end);
         }
         true
     }
@@ -989,7 +1387,7 @@ impl<T: Integer> RangeSetBlaze<T> {
             let end_ref = last_entry.get_mut();
             if value <= *end_ref {
                 b.insert(value, *end_ref);
-                *end_ref = value - T::one();
+                *end_ref = value.sub_one();
             }
         }
 
@@ -1011,7 +1409,7 @@ impl<T: Integer> RangeSetBlaze<T> {
     fn btree_map_len(btree_map: &BTreeMap<T, T>) -> T::SafeLen {
         btree_map
             .iter()
-            .fold(<T as Integer>::SafeLen::zero(), |acc, (start, end)| {
+            .fold(<T as Element>::SafeLen::zero(), |acc, (start, end)| {
                 acc + T::safe_len(&(*start..=*end))
             })
     }
@@ -1074,7 +1472,7 @@ impl<T: Integer> RangeSetBlaze<T> {
     //         }
 
     //         // If this range overlaps or is adjacent, merge it
-    //         if end_value >= start - T::one() {
+    //         if end_value >= start.sub_one() {
     //             let new_end = end.max(end_value);
     //             let new_start = start.min(start_key);
 
@@ -1104,13 +1502,10 @@ impl<T: Integer> RangeSetBlaze<T> {
         let mut before = self.btree_map.range_mut(..=start).rev();
         if let Some((start_before, end_before)) = before.next() {
             // Must check this in two parts to avoid overflow
-            if match (*end_before).checked_add(&T::one()) {
-                Some(end_before_succ) => end_before_succ < start,
-                None => false,
-            } {
+            if *end_before < T::max_value() && end_before.add_one() < start {
                 self.internal_add2(&range);
             } else if *end_before < end {
-                self.len += T::safe_len(&(*end_before..=end - T::one()));
+                self.len += T::safe_len(&(*end_before..=end.sub_one()));
                 *end_before = end;
                 let start_before = *start_before;
                 self.delete_extra(&(start_before..=end));
@@ -1137,7 +1532,7 @@ impl<T: Integer> RangeSetBlaze<T> {
     /// # Examples
     ///
     /// ```
-    /// use range_set_blaze::RangeSetBlaze;
+    /// use range_set_blaze::{RangeSetBlaze, UIntPlusOne};
     ///
     /// let mut v = RangeSetBlaze::new();
     /// assert_eq!(v.len(), 0usize);
@@ -1150,11 +1545,14 @@ impl<T: Integer> RangeSetBlaze<T> {
     /// ]);
     /// assert_eq!(
     ///     v.len(),
-    ///     340_282_366_920_938_463_463_374_607_431_768_211_455u128
+    ///     UIntPlusOne::UInt(340_282_366_920_938_463_463_374_607_431_768_211_455u128)
     /// );
+    ///
+    /// let v = RangeSetBlaze::from_iter([i128::MIN..=i128::MAX]);
+    /// assert_eq!(v.len(), UIntPlusOne::MaxPlusOne);
     /// ```
     #[must_use]
-    pub const fn len(&self) -> <T as Integer>::SafeLen {
+    pub const fn len(&self) -> <T as Element>::SafeLen {
         self.len
     }
 
@@ -1172,7 +1570,7 @@ impl<T: Integer> RangeSetBlaze<T> {
     pub fn new() -> Self {
         Self {
             btree_map: BTreeMap::new(),
-            len: <T as Integer>::SafeLen::zero(),
+            len: <T as Element>::SafeLen::zero(),
         }
     }
 
@@ -1197,7 +1595,7 @@ impl<T: Integer> RangeSetBlaze<T> {
             let (start, end) = entry.remove_entry();
             self.len -= T::safe_len(&(start..=end));
             if start != end {
-                let start = start + T::one();
+                let start = start.add_one();
                 self.btree_map.insert(start, end);
                 self.len += T::safe_len(&(start..=end));
             }
@@ -1235,7 +1633,7 @@ impl<T: Integer> RangeSetBlaze<T> {
         if start == *end {
             entry.remove_entry();
         } else {
-            *end -= T::one();
+            *end = end.sub_one();
             self.len += T::safe_len(&(start..=*end));
         }
         Some(result)
@@ -1353,7 +1751,7 @@ impl<T: Integer> RangeSetBlaze<T> {
 // We create a RangeSetBlaze from an iterator of integers or integer ranges by
 // 1. turning them into a UnionIter (internally, it collects into intervals and sorts by start).
 // 2. Turning the SortedDisjoint into a BTreeMap.
-impl<T: Integer> FromIterator<T> for RangeSetBlaze<T> {
+impl<T: Element> FromIterator<T> for RangeSetBlaze<T> {
     /// Create a [`RangeSetBlaze`] from an iterator of integers. Duplicates and out-of-order elements are fine.
     ///
     /// *For more about constructors and performance, see [`RangeSetBlaze` Constructors](struct.RangeSetBlaze.html#rangesetblaze-constructors).*
@@ -1375,7 +1773,7 @@ impl<T: Integer> FromIterator<T> for RangeSetBlaze<T> {
     }
 }
 
-impl<'a, T: Integer> FromIterator<&'a T> for RangeSetBlaze<T> {
+impl<'a, T: Element> FromIterator<&'a T> for RangeSetBlaze<T> {
     /// Create a [`RangeSetBlaze`] from an iterator of integers references. Duplicates and out-of-order elements are fine.
     ///
     /// *For more about constructors and performance, see [`RangeSetBlaze` Constructors](struct.RangeSetBlaze.html#rangesetblaze-constructors).*
@@ -1397,7 +1795,7 @@ impl<'a, T: Integer> FromIterator<&'a T> for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSetBlaze<T> {
+impl<T: Element> FromIterator<RangeInclusive<T>> for RangeSetBlaze<T> {
     /// Create a [`RangeSetBlaze`] from an iterator of inclusive ranges, `start..=end`.
     /// Overlapping, out-of-order, and empty ranges are fine.
     ///
@@ -1423,7 +1821,7 @@ impl<T: Integer> FromIterator<RangeInclusive<T>> for RangeSetBlaze<T> {
     }
 }
 
-impl<'a, T: Integer + 'a> FromIterator<&'a RangeInclusive<T>> for RangeSetBlaze<T> {
+impl<'a, T: Element + 'a> FromIterator<&'a RangeInclusive<T>> for RangeSetBlaze<T> {
     /// Create a [`RangeSetBlaze`] from an iterator of inclusive ranges, `start..=end`.
     /// Overlapping, out-of-order, and empty ranges are fine.
     ///
@@ -1448,7 +1846,7 @@ impl<'a, T: Integer + 'a> FromIterator<&'a RangeInclusive<T>> for RangeSetBlaze<
         Self::from_sorted_disjoint(union_iter)
     }
 }
-impl<T: Integer, const N: usize> From<[T; N]> for RangeSetBlaze<T> {
+impl<T: Element, const N: usize> From<[T; N]> for RangeSetBlaze<T> {
     /// For compatibility with [`BTreeSet`] you may create a [`RangeSetBlaze`] from an array of integers.
     ///
     /// *For more about constructors and performance, see [`RangeSetBlaze` Constructors](struct.RangeSetBlaze.html#rangesetblaze-constructors).*
@@ -1504,7 +1902,7 @@ pub type BitEq<T, L, R> = BitOrMerge<
 
 impl<T, I> MultiwayRangeSetBlazeRef<T> for I
 where
-    T: Integer,
+    T: Element,
     I: IntoIterator<Item = RangeSetBlaze<T>>,
 {
 }
@@ -1516,7 +1914,7 @@ where
 ///
 /// [`union`]: MultiwayRangeSetBlazeRef::union
 /// [`intersection`]: MultiwayRangeSetBlazeRef::intersection
-pub trait MultiwayRangeSetBlazeRef<T: Integer>:
+pub trait MultiwayRangeSetBlazeRef<T: Element>:
     IntoIterator<Item = RangeSetBlaze<T>> + Sized
 {
     /// Unions the given [`RangeSetBlaze`] references, creating a new [`RangeSetBlaze`].
@@ -1582,7 +1980,7 @@ pub trait MultiwayRangeSetBlazeRef<T: Integer>:
 }
 impl<'a, T, I> MultiwayRangeSetBlaze<'a, T> for I
 where
-    T: Integer + 'a,
+    T: Element + 'a,
     I: IntoIterator<Item = &'a RangeSetBlaze<T>>,
 {
 }
@@ -1593,7 +1991,7 @@ where
 ///
 /// [`union`]: MultiwayRangeSetBlaze::union
 /// [`intersection`]: MultiwayRangeSetBlaze::intersection
-pub trait MultiwayRangeSetBlaze<'a, T: Integer + 'a>:
+pub trait MultiwayRangeSetBlaze<'a, T: Element + 'a>:
     IntoIterator<Item = &'a RangeSetBlaze<T>> + Sized
 {
     /// Unions the given [`RangeSetBlaze`]'s, creating a new [`RangeSetBlaze`].
@@ -1663,7 +2061,7 @@ pub trait MultiwayRangeSetBlaze<'a, T: Integer + 'a>:
 
 impl<T, II, I> MultiwaySortedDisjoint<T, I> for II
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
     II: IntoIterator<Item = I>,
 {
@@ -1674,7 +2072,7 @@ where
 ///
 /// [`union`]: crate::MultiwaySortedDisjoint::union
 /// [`intersection`]: crate::MultiwaySortedDisjoint::intersection
-pub trait MultiwaySortedDisjoint<T: Integer, I>: IntoIterator<Item = I> + Sized
+pub trait MultiwaySortedDisjoint<T: Element, I>: IntoIterator<Item = I> + Sized
 where
     I: SortedDisjoint<T>,
 {
@@ -1747,6 +2145,9 @@ gen_ops_ex!(
     ///
     /// Either, neither, or both inputs may be borrowed.
     ///
+    /// When one set has far fewer ranges than the other, this seeks into the larger set once per range of the smaller,
+    /// rather than merge-walking both.
+    ///
     /// # Examples
     /// ```
     /// use range_set_blaze::prelude::*;
@@ -1757,6 +2158,12 @@ gen_ops_ex!(
     /// assert_eq!(result.to_string(), "2..=2, 5..=6");
     /// ```
     for & call |a: &RangeSetBlaze<T>, b: &RangeSetBlaze<T>| {
+        if seek::is_skewed(a, b) {
+            return RangeSetBlaze::intersection_by_seek(a, b);
+        }
+        if seek::is_skewed(b, a) {
+            return RangeSetBlaze::intersection_by_seek(b, a);
+        }
         (a.ranges() & b.ranges()).into_range_set_blaze()
     };
 
@@ -1786,6 +2193,9 @@ gen_ops_ex!(
     ///
     /// Either, neither, or both inputs may be borrowed.
     ///
+    /// When one set has far fewer ranges than the other, this seeks into the larger set once per range of the smaller,
+    /// rather than merge-walking both.
+    ///
     /// # Examples
     /// ```
     /// use range_set_blaze::prelude::*;
@@ -1796,9 +2206,15 @@ gen_ops_ex!(
     /// assert_eq!(result.to_string(), "1..=1, 7..=100");
     /// ```
     for - call |a: &RangeSetBlaze<T>, b: &RangeSetBlaze<T>| {
+        if seek::is_skewed(a, b) {
+            return RangeSetBlaze::difference_by_seek(a, b);
+        }
+        if seek::is_skewed(b, a) {
+            return RangeSetBlaze::intersection_by_seek(&!b, a);
+        }
         (a.ranges() - b.ranges()).into_range_set_blaze()
     };
-    where T: Integer //Where clause for all impl's
+    where T: Element //Where clause for all impl's
 );
 
 gen_ops_ex!(
@@ -1824,10 +2240,10 @@ gen_ops_ex!(
         (!a.ranges()).into_range_set_blaze()
     };
 
-    where T: Integer //Where clause for all impl's
+    where T: Element //Where clause for all impl's
 );
 
-impl<T: Integer> IntoIterator for RangeSetBlaze<T> {
+impl<T: Element> IntoIterator for RangeSetBlaze<T> {
     type Item = T;
     type IntoIter = IntoIter<T>;
 
@@ -1866,7 +2282,7 @@ impl<T: Integer> IntoIterator for RangeSetBlaze<T> {
 #[derive(Clone, Debug)]
 pub struct Iter<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     iter: I,
@@ -1874,9 +2290,9 @@ where
     option_range_back: Option<RangeInclusive<T>>,
 }
 
-impl<T: Integer, I> FusedIterator for Iter<T, I> where I: SortedDisjoint<T> + FusedIterator {}
+impl<T: Element, I> FusedIterator for Iter<T, I> where I: SortedDisjoint<T> + FusedIterator {}
 
-impl<T: Integer, I> Iterator for Iter<T, I>
+impl<T: Element, I> Iterator for Iter<T, I>
 where
     I: SortedDisjoint<T>,
 {
@@ -1891,7 +2307,7 @@ where
         let (start, end) = range.into_inner();
         debug_assert!(start <= end && end <= T::safe_max_value());
         if start < end {
-            self.option_range_front = Some(start + T::one()..=end);
+            self.option_range_front = Some(start.add_one()..=end);
         }
         Some(start)
     }
@@ -1904,7 +2320,7 @@ where
     }
 }
 
-impl<T: Integer, I> DoubleEndedIterator for Iter<T, I>
+impl<T: Element, I> DoubleEndedIterator for Iter<T, I>
 where
     I: SortedDisjoint<T> + DoubleEndedIterator,
 {
@@ -1917,7 +2333,7 @@ where
         let (start, end) = range.into_inner();
         debug_assert!(start <= end && end <= T::safe_max_value());
         if start < end {
-            self.option_range_back = Some(start..=end - T::one());
+            self.option_range_back = Some(start..=end.sub_one());
         }
 
         Some(end)
@@ -1932,15 +2348,15 @@ where
 /// documentation for more.
 ///
 /// [`into_iter`]: RangeSetBlaze::into_iter
-pub struct IntoIter<T: Integer> {
+pub struct IntoIter<T: Element> {
     option_range_front: Option<RangeInclusive<T>>,
     option_range_back: Option<RangeInclusive<T>>,
     into_iter: alloc::collections::btree_map::IntoIter<T, T>,
 }
 
-impl<T: Integer> FusedIterator for IntoIter<T> {}
+impl<T: Element> FusedIterator for IntoIter<T> {}
 
-impl<T: Integer> Iterator for IntoIter<T> {
+impl<T: Element> Iterator for IntoIter<T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -1953,7 +2369,7 @@ impl<T: Integer> Iterator for IntoIter<T> {
         let (start, end) = range.into_inner();
         debug_assert!(start <= end && end <= T::safe_max_value());
         if start < end {
-            self.option_range_front = Some(start + T::one()..=end);
+            self.option_range_front = Some(start.add_one()..=end);
         }
         Some(start)
     }
@@ -1966,7 +2382,7 @@ impl<T: Integer> Iterator for IntoIter<T> {
     }
 }
 
-impl<T: Integer> DoubleEndedIterator for IntoIter<T> {
+impl<T: Element> DoubleEndedIterator for IntoIter<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         let range = self
             .option_range_back
@@ -1977,14 +2393,14 @@ impl<T: Integer> DoubleEndedIterator for IntoIter<T> {
         let (start, end) = range.into_inner();
         debug_assert!(start <= end && end <= T::safe_max_value());
         if start < end {
-            self.option_range_back = Some(start..=end - T::one());
+            self.option_range_back = Some(start..=end.sub_one());
         }
 
         Some(end)
     }
 }
 
-impl<T: Integer> Extend<T> for RangeSetBlaze<T> {
+impl<T: Element> Extend<T> for RangeSetBlaze<T> {
     /// Extends the [`RangeSetBlaze`] with the contents of an Integer iterator.
     ///
     /// Integers are added one-by-one. There is also a version
@@ -2017,7 +2433,7 @@ impl<T: Integer> Extend<T> for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> BitOrAssign<&RangeSetBlaze<T>> for RangeSetBlaze<T> {
+impl<T: Element> BitOrAssign<&RangeSetBlaze<T>> for RangeSetBlaze<T> {
     /// Adds the contents of another [`RangeSetBlaze`] to this one.
     ///
     /// Passing the right-hand side by ownership rather than borrow
@@ -2050,7 +2466,7 @@ impl<T: Integer> BitOrAssign<&RangeSetBlaze<T>> for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> BitOrAssign<RangeSetBlaze<T>> for RangeSetBlaze<T> {
+impl<T: Element> BitOrAssign<RangeSetBlaze<T>> for RangeSetBlaze<T> {
     /// Adds the contents of another [`RangeSetBlaze`] to this one.
     ///
     /// Passing the right-hand side by ownership rather than borrow
@@ -2081,7 +2497,7 @@ impl<T: Integer> BitOrAssign<RangeSetBlaze<T>> for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> BitOr<RangeSetBlaze<T>> for RangeSetBlaze<T> {
+impl<T: Element> BitOr<RangeSetBlaze<T>> for RangeSetBlaze<T> {
     /// Unions the contents of two [`RangeSetBlaze`]'s.
     ///
     /// Passing ownership rather than borrow sometimes allows a many-times
@@ -2102,7 +2518,7 @@ impl<T: Integer> BitOr<RangeSetBlaze<T>> for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> BitOr<&RangeSetBlaze<T>> for RangeSetBlaze<T> {
+impl<T: Element> BitOr<&RangeSetBlaze<T>> for RangeSetBlaze<T> {
     /// Unions the contents of two [`RangeSetBlaze`]'s.
     ///
     /// Passing ownership rather than borrow sometimes allows a many-times
@@ -2123,7 +2539,7 @@ impl<T: Integer> BitOr<&RangeSetBlaze<T>> for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> BitOr<RangeSetBlaze<T>> for &RangeSetBlaze<T> {
+impl<T: Element> BitOr<RangeSetBlaze<T>> for &RangeSetBlaze<T> {
     type Output = RangeSetBlaze<T>;
     /// Unions the contents of two [`RangeSetBlaze`]'s.
     ///
@@ -2144,7 +2560,7 @@ impl<T: Integer> BitOr<RangeSetBlaze<T>> for &RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> BitOr<&RangeSetBlaze<T>> for &RangeSetBlaze<T> {
+impl<T: Element> BitOr<&RangeSetBlaze<T>> for &RangeSetBlaze<T> {
     type Output = RangeSetBlaze<T>;
     /// Unions the contents of two [`RangeSetBlaze`]'s.
     ///
@@ -2164,7 +2580,7 @@ impl<T: Integer> BitOr<&RangeSetBlaze<T>> for &RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> Extend<RangeInclusive<T>> for RangeSetBlaze<T> {
+impl<T: Element> Extend<RangeInclusive<T>> for RangeSetBlaze<T> {
     /// Extends the [`RangeSetBlaze`] with the contents of a
     /// range iterator.
 
@@ -2198,7 +2614,7 @@ impl<T: Integer> Extend<RangeInclusive<T>> for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> Ord for RangeSetBlaze<T> {
+impl<T: Element> Ord for RangeSetBlaze<T> {
     /// We define a total ordering on RangeSetBlaze. Following the convention of
     /// [`BTreeSet`], the ordering is lexicographic, *not* by subset/superset.
     ///
@@ -2247,10 +2663,10 @@ impl<T: Integer> Ord for RangeSetBlaze<T> {
                         }
                         Ordering::Less => {
                             a_rx = a.next();
-                            b_rx = Some(*a_r.end() + T::one()..=*b_r.end());
+                            b_rx = Some(a_r.end().add_one()..=*b_r.end());
                         }
                         Ordering::Greater => {
-                            a_rx = Some(*b_r.end() + T::one()..=*a_r.end());
+                            a_rx = Some(b_r.end().add_one()..=*a_r.end());
                             b_rx = b.next();
                         }
                     }
@@ -2263,24 +2679,24 @@ impl<T: Integer> Ord for RangeSetBlaze<T> {
     }
 }
 
-impl<T: Integer> PartialOrd for RangeSetBlaze<T> {
+impl<T: Element> PartialOrd for RangeSetBlaze<T> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<T: Integer> Eq for RangeSetBlaze<T> {}
+impl<T: Element> Eq for RangeSetBlaze<T> {}
 
 // If the iterator inside a BitOrIter is SortedStart, the output will be SortedDisjoint
-impl<T: Integer, I: SortedStarts<T>> SortedStarts<T> for UnionIter<T, I> {}
-impl<T: Integer, I: SortedStarts<T>> SortedDisjoint<T> for UnionIter<T, I> {}
+impl<T: Element, I: SortedStarts<T>> SortedStarts<T> for UnionIter<T, I> {}
+impl<T: Element, I: SortedStarts<T>> SortedDisjoint<T> for UnionIter<T, I> {}
 // If the iterator inside NotIter is SortedDisjoint, the output will be SortedDisjoint
-impl<T: Integer, I: SortedDisjoint<T>> SortedStarts<T> for NotIter<T, I> {}
-impl<T: Integer, I: SortedDisjoint<T>> SortedDisjoint<T> for NotIter<T, I> {}
+impl<T: Element, I: SortedDisjoint<T>> SortedStarts<T> for NotIter<T, I> {}
+impl<T: Element, I: SortedDisjoint<T>> SortedDisjoint<T> for NotIter<T, I> {}
 // If the iterator inside Tee is SortedDisjoint, the output will be SortedDisjoint
-impl<T: Integer, I: SortedDisjoint<T>> SortedStarts<T> for Tee<I> {}
-impl<T: Integer, I: SortedDisjoint<T>> SortedDisjoint<T> for Tee<I> {}
+impl<T: Element, I: SortedDisjoint<T>> SortedStarts<T> for Tee<I> {}
+impl<T: Element, I: SortedDisjoint<T>> SortedDisjoint<T> for Tee<I> {}
 
 #[cfg(feature = "std")]
 use std::{
@@ -2294,7 +2710,7 @@ use std::{
 pub fn demo_read_ranges_from_file<P, T>(path: P) -> io::Result<RangeSetBlaze<T>>
 where
     P: AsRef<Path>,
-    T: FromStr + Integer,
+    T: FromStr + Element,
 {
     let lines = BufReader::new(File::open(&path)?).lines();
 
@@ -0,0 +1,154 @@
+use alloc::boxed::Box;
+use core::cmp::{max, Ordering};
+
+// An ordered map from keys (range starts) to lengths that can quickly find the first entry, at or after
+// a given key, whose length is at least a given length.
+//
+// It is a treap: a binary search tree on the keys that is also a heap on random priorities, which keeps
+// the expected depth logarithmic. Each node also records the largest length in its subtree, so searches
+// can skip any subtree whose lengths are all too small.
+#[derive(Clone, Debug)]
+pub(crate) struct MaxLenIndex<K, L> {
+    root: Link<K, L>,
+    seed: u64,
+}
+
+type Link<K, L> = Option<Box<Node<K, L>>>;
+
+#[derive(Clone, Debug)]
+struct Node<K, L> {
+    key: K,
+    len: L,
+    max_len: L,
+    priority: u64,
+    left: Link<K, L>,
+    right: Link<K, L>,
+}
+
+impl<K: Ord + Copy, L: Ord + Copy> MaxLenIndex<K, L> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            root: None,
+            seed: 0,
+        }
+    }
+
+    // The key must not already be in the index.
+    pub(crate) fn insert(&mut self, key: K, len: L) {
+        let node = Box::new(Node {
+            key,
+            len,
+            max_len: len,
+            priority: self.next_priority(),
+            left: None,
+            right: None,
+        });
+        let (less, greater) = split(self.root.take(), key);
+        self.root = merge(merge(less, Some(node)), greater);
+    }
+
+    pub(crate) fn remove(&mut self, key: K) -> Option<L> {
+        remove(&mut self.root, key)
+    }
+
+    // Returns the entry with the smallest key at or after `key` whose length is at least `min_len`.
+    pub(crate) fn first_at_or_after(&self, key: K, min_len: L) -> Option<(K, L)> {
+        first_at_or_after(&self.root, key, min_len)
+    }
+
+    // splitmix64, so that priorities are well mixed without needing a random number generator.
+    fn next_priority(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.seed;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl<K: Ord + Copy, L: Ord + Copy> Node<K, L> {
+    fn update(&mut self) {
+        self.max_len = self.len;
+        if let Some(left) = &self.left {
+            self.max_len = max(self.max_len, left.max_len);
+        }
+        if let Some(right) = &self.right {
+            self.max_len = max(self.max_len, right.max_len);
+        }
+    }
+}
+
+// Splits a tree into the keys less than `key` and the keys greater than or equal to `key`.
+fn split<K: Ord + Copy, L: Ord + Copy>(link: Link<K, L>, key: K) -> (Link<K, L>, Link<K, L>) {
+    let Some(mut node) = link else {
+        return (None, None);
+    };
+    if node.key < key {
+        let (less, greater) = split(node.right.take(), key);
+        node.right = less;
+        node.update();
+        (Some(node), greater)
+    } else {
+        let (less, greater) = split(node.left.take(), key);
+        node.left = greater;
+        node.update();
+        (less, Some(node))
+    }
+}
+
+// Merges two trees, where every key in `less` is less than every key in `greater`.
+fn merge<K: Ord + Copy, L: Ord + Copy>(less: Link<K, L>, greater: Link<K, L>) -> Link<K, L> {
+    match (less, greater) {
+        (None, link) | (link, None) => link,
+        (Some(mut less), Some(mut greater)) => {
+            if less.priority > greater.priority {
+                less.right = merge(less.right.take(), Some(greater));
+                less.update();
+                Some(less)
+            } else {
+                greater.left = merge(Some(less), greater.left.take());
+                greater.update();
+                Some(greater)
+            }
+        }
+    }
+}
+
+fn remove<K: Ord + Copy, L: Ord + Copy>(link: &mut Link<K, L>, key: K) -> Option<L> {
+    let node = link.as_mut()?;
+    let removed = match key.cmp(&node.key) {
+        Ordering::Less => remove(&mut node.left, key),
+        Ordering::Greater => remove(&mut node.right, key),
+        Ordering::Equal => {
+            let len = node.len;
+            let (left, right) = (node.left.take(), node.right.take());
+            *link = merge(left, right);
+            return Some(len);
+        }
+    };
+    if removed.is_some() {
+        node.update();
+    }
+    removed
+}
+
+fn first_at_or_after<K: Ord + Copy, L: Ord + Copy>(
+    link: &Link<K, L>,
+    key: K,
+    min_len: L,
+) -> Option<(K, L)> {
+    let node = link.as_ref()?;
+    if node.max_len < min_len {
+        return None;
+    }
+    if node.key < key {
+        return first_at_or_after(&node.right, key, min_len);
+    }
+    if let Some(found) = first_at_or_after(&node.left, key, min_len) {
+        return Some(found);
+    }
+    if node.len >= min_len {
+        return Some((node.key, node.len));
+    }
+    first_at_or_after(&node.right, key, min_len)
+}
@@ -2,7 +2,7 @@ use core::{iter::FusedIterator, ops::RangeInclusive};
 
 use itertools::{Itertools, KMergeBy, MergeBy};
 
-use crate::{Integer, SortedDisjoint, SortedStarts};
+use crate::{Element, SortedDisjoint, SortedStarts};
 
 /// Works with [`UnionIter`] to turn any number of [`SortedDisjoint`] iterators into a [`SortedDisjoint`] iterator of their union,
 /// i.e., all the integers in any input iterator, as sorted & disjoint ranges.
@@ -33,7 +33,7 @@ use crate::{Integer, SortedDisjoint, SortedStarts};
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Merge<T, L, R>
 where
-    T: Integer,
+    T: Element,
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
 {
@@ -43,7 +43,7 @@ where
 
 impl<T, L, R> Merge<T, L, R>
 where
-    T: Integer,
+    T: Element,
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
 {
@@ -57,7 +57,7 @@ where
 
 impl<T, L, R> FusedIterator for Merge<T, L, R>
 where
-    T: Integer,
+    T: Element,
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
 {
@@ -65,7 +65,7 @@ where
 
 impl<T, L, R> Iterator for Merge<T, L, R>
 where
-    T: Integer,
+    T: Element,
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
 {
@@ -82,7 +82,7 @@ where
 
 impl<T, L, R> SortedStarts<T> for Merge<T, L, R>
 where
-    T: Integer,
+    T: Element,
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
 {
@@ -119,7 +119,7 @@ where
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct KMerge<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     #[allow(clippy::type_complexity)]
@@ -128,7 +128,7 @@ where
 
 impl<T, I> KMerge<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     /// Creates a new [`KMerge`] iterator from zero or more [`SortedDisjoint`] iterators. See [`KMerge`] for more details and examples.
@@ -144,14 +144,14 @@ where
 
 impl<T, I> FusedIterator for KMerge<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
 }
 
 impl<T, I> Iterator for KMerge<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     type Item = RangeInclusive<T>;
@@ -167,7 +167,7 @@ where
 
 impl<T, I> SortedStarts<T> for KMerge<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
 }
@@ -0,0 +1,167 @@
+use core::{cmp::min, ops::RangeInclusive};
+
+use crate::{
+    AssumeSortedStarts, Integer, MultiwaySortedDisjoint, RangeSetBlaze, SortedDisjoint, UnionIter,
+};
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Returns the Minkowski sum of two [`RangeSetBlaze`]'s, that is, `{a + b : a ∈ self, b ∈ other}`.
+    ///
+    /// Sums that fall outside the range of `T` (or above [`Element::safe_max_value`](crate::Element::safe_max_value)) are dropped.
+    ///
+    /// # Performance
+    ///
+    /// The sum of two ranges is a range, so the work depends only on the number of ranges, never on the
+    /// number of integers. Each range of the set with fewer ranges becomes one [`SortedDisjoint`] stream
+    /// of candidate ranges. The streams are merged on demand (as with [`KMerge`]) and
+    /// coalesced by [`UnionIter`]. With *n* and *m* ranges (*n* ≤ *m*), the time is O(*n* *m* log *n*)
+    /// and the extra memory is O(*n*).
+    ///
+    /// [`KMerge`]: crate::KMerge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::from_iter([0..=2, 10..=10]);
+    /// let b = RangeSetBlaze::from_iter([0, 100]);
+    /// assert_eq!(a.minkowski_sum(&b).to_string(), "0..=2, 10..=10, 100..=102, 110..=110");
+    ///
+    /// // Sums that overflow are dropped.
+    /// let a = RangeSetBlaze::from_iter([250u8..=255]);
+    /// let b = RangeSetBlaze::from_iter([0u8, 3]);
+    /// assert_eq!(a.minkowski_sum(&b).to_string(), "250..=255");
+    /// ```
+    #[must_use]
+    pub fn minkowski_sum(&self, other: &Self) -> Self {
+        let (small, large) = if self.ranges_len() <= other.ranges_len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        small
+            .ranges()
+            .map(|small_range| {
+                let candidates = large
+                    .ranges()
+                    .filter_map(move |large_range| add_ranges(&small_range, &large_range));
+                UnionIter::new(AssumeSortedStarts::new(candidates))
+            })
+            .union()
+            .into_range_set_blaze()
+    }
+
+    /// Returns the Minkowski difference of two [`RangeSetBlaze`]'s, that is, `{a - b : a ∈ self, b ∈ other}`.
+    ///
+    /// Differences that fall outside the range of `T` (or above [`Element::safe_max_value`](crate::Element::safe_max_value)) are dropped.
+    ///
+    /// # Performance
+    ///
+    /// The same as [`RangeSetBlaze::minkowski_sum`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::from_iter([10..=12, 20..=20]);
+    /// let b = RangeSetBlaze::from_iter([0..=1, 10..=10]);
+    /// assert_eq!(a.minkowski_difference(&b).to_string(), "0..=2, 9..=12, 19..=20");
+    ///
+    /// // Differences that underflow are dropped.
+    /// let a = RangeSetBlaze::from_iter([0u8..=5]);
+    /// let b = RangeSetBlaze::from_iter([3u8]);
+    /// assert_eq!(a.minkowski_difference(&b).to_string(), "0..=2");
+    /// ```
+    #[must_use]
+    pub fn minkowski_difference(&self, other: &Self) -> Self {
+        // For a fixed range of `self`, subtracting `other`'s ranges in descending order gives
+        // ascending starts. For a fixed range of `other`, subtracting it from `self`'s ranges
+        // in ascending order gives ascending starts. Either way, we make one stream per range
+        // of the smaller set.
+        if self.ranges_len() <= other.ranges_len() {
+            self.ranges()
+                .map(|self_range| {
+                    let candidates = other
+                        .ranges()
+                        .rev()
+                        .filter_map(move |other_range| sub_ranges(&self_range, &other_range));
+                    UnionIter::new(AssumeSortedStarts::new(candidates))
+                })
+                .union()
+                .into_range_set_blaze()
+        } else {
+            other
+                .ranges()
+                .map(|other_range| {
+                    let candidates = self
+                        .ranges()
+                        .filter_map(move |self_range| sub_ranges(&self_range, &other_range));
+                    UnionIter::new(AssumeSortedStarts::new(candidates))
+                })
+                .union()
+                .into_range_set_blaze()
+        }
+    }
+}
+
+// Tells which side of `T`'s range an overflowing result falls on.
+enum Overflow {
+    Below,
+    Above,
+}
+
+fn checked_sum<T: Integer>(a: T, b: T) -> Result<T, Overflow> {
+    match a.checked_add(&b) {
+        Some(sum) => Ok(sum),
+        None if b < T::zero() => Err(Overflow::Below),
+        None => Err(Overflow::Above),
+    }
+}
+
+fn checked_difference<T: Integer>(a: T, b: T) -> Result<T, Overflow> {
+    match a.overflowing_sub(&b) {
+        (difference, false) => Ok(difference),
+        (_, true) if b < T::zero() => Err(Overflow::Above),
+        (_, true) => Err(Overflow::Below),
+    }
+}
+
+// Clips a start and end to `T::min_value()..=T::safe_max_value()`.
+fn clip<T: Integer>(
+    start: Result<T, Overflow>,
+    end: Result<T, Overflow>,
+) -> Option<RangeInclusive<T>> {
+    let start = match start {
+        Ok(start) if start <= T::safe_max_value() => start,
+        Ok(_) | Err(Overflow::Above) => return None,
+        Err(Overflow::Below) => T::min_value(),
+    };
+    let end = match end {
+        Ok(end) => min(end, T::safe_max_value()),
+        Err(Overflow::Above) => T::safe_max_value(),
+        Err(Overflow::Below) => return None,
+    };
+    Some(start..=end)
+}
+
+fn add_ranges<T: Integer>(
+    a: &RangeInclusive<T>,
+    b: &RangeInclusive<T>,
+) -> Option<RangeInclusive<T>> {
+    clip(
+        checked_sum(*a.start(), *b.start()),
+        checked_sum(*a.end(), *b.end()),
+    )
+}
+
+fn sub_ranges<T: Integer>(
+    a: &RangeInclusive<T>,
+    b: &RangeInclusive<T>,
+) -> Option<RangeInclusive<T>> {
+    clip(
+        checked_difference(*a.start(), *b.end()),
+        checked_difference(*a.end(), *b.start()),
+    )
+}
@@ -0,0 +1,362 @@
+use core::{
+    iter::{FusedIterator, Peekable},
+    ops::{Bound, RangeInclusive},
+};
+
+use crate::{Integer, RangeSetBlaze, RangesIter};
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Returns the smallest element of the set that is greater than or equal to `value`, if any.
+    ///
+    /// # Performance
+    ///
+    /// This is O(log *n*), where *n* is the number of ranges. For many queries in sorted order, see
+    /// [`RangeSetBlaze::next_at_or_after_sorted`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let allowed_ports = RangeSetBlaze::from_iter([22u16..=22, 8000..=8099]);
+    /// assert_eq!(allowed_ports.next_at_or_after(22), Some(22));
+    /// assert_eq!(allowed_ports.next_at_or_after(23), Some(8000));
+    /// assert_eq!(allowed_ports.next_at_or_after(8050), Some(8050));
+    /// assert_eq!(allowed_ports.next_at_or_after(8100), None);
+    /// ```
+    #[must_use]
+    pub fn next_at_or_after(&self, value: T) -> Option<T> {
+        if let Some((_, &end)) = self.btree_map.range(..=value).next_back() {
+            if value <= end {
+                return Some(value);
+            }
+        }
+        self.btree_map
+            .range((Bound::Excluded(value), Bound::Unbounded))
+            .next()
+            .map(|(&start, _)| start)
+    }
+
+    /// Returns the largest element of the set that is less than or equal to `value`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// assert_eq!(set.prev_at_or_before(35), Some(35));
+    /// assert_eq!(set.prev_at_or_before(25), Some(19));
+    /// assert_eq!(set.prev_at_or_before(5), None);
+    /// ```
+    #[must_use]
+    pub fn prev_at_or_before(&self, value: T) -> Option<T> {
+        self.btree_map
+            .range(..=value)
+            .next_back()
+            .map(|(_, &end)| if value < end { value } else { end })
+    }
+
+    /// Returns the smallest element of the set that is strictly greater than `value`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// assert_eq!(set.next_after(15), Some(16));
+    /// assert_eq!(set.next_after(19), Some(30));
+    /// assert_eq!(set.next_after(39), None);
+    /// ```
+    #[must_use]
+    pub fn next_after(&self, value: T) -> Option<T> {
+        self.next_at_or_after(value.checked_add(&T::one())?)
+    }
+
+    /// Returns the largest element of the set that is strictly less than `value`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// assert_eq!(set.prev_before(35), Some(34));
+    /// assert_eq!(set.prev_before(30), Some(19));
+    /// assert_eq!(set.prev_before(10), None);
+    /// ```
+    #[must_use]
+    pub fn prev_before(&self, value: T) -> Option<T> {
+        if value == T::min_value() {
+            return None;
+        }
+        self.prev_at_or_before(value - T::one())
+    }
+
+    /// Returns the element of the set closest to `value`, if any. Ties go to the smaller element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// assert_eq!(set.nearest(15), Some(15));
+    /// assert_eq!(set.nearest(23), Some(19));
+    /// assert_eq!(set.nearest(25), Some(30)); // 19 and 30 are 6 and 5 away
+    /// assert_eq!(set.nearest(100), Some(39));
+    /// ```
+    #[must_use]
+    pub fn nearest(&self, value: T) -> Option<T> {
+        nearer(
+            value,
+            self.prev_at_or_before(value),
+            self.next_at_or_after(value),
+        )
+    }
+
+    /// Returns the smallest integer greater than or equal to `value` that is *not* in the set, if any.
+    ///
+    /// Only integers up to [`Element::safe_max_value`](crate::Element::safe_max_value) are considered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let used_ids = RangeSetBlaze::from_iter([0u8..=9, 11..=255]);
+    /// assert_eq!(used_ids.next_missing_at_or_after(0), Some(10));
+    /// assert_eq!(used_ids.next_missing_at_or_after(11), None);
+    /// ```
+    #[must_use]
+    pub fn next_missing_at_or_after(&self, value: T) -> Option<T> {
+        assert!(
+            value <= T::safe_max_value(),
+            "value must be <= T::safe_max_value()"
+        );
+        match self.btree_map.range(..=value).next_back() {
+            Some((_, &end)) if value <= end => {
+                if end < T::safe_max_value() {
+                    Some(end + T::one())
+                } else {
+                    None
+                }
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Returns the largest integer less than or equal to `value` that is *not* in the set, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([-5i8..=5]);
+    /// assert_eq!(set.prev_missing_at_or_before(3), Some(-6));
+    /// assert_eq!(set.prev_missing_at_or_before(10), Some(10));
+    /// ```
+    #[must_use]
+    pub fn prev_missing_at_or_before(&self, value: T) -> Option<T> {
+        assert!(
+            value <= T::safe_max_value(),
+            "value must be <= T::safe_max_value()"
+        );
+        match self.btree_map.range(..=value).next_back() {
+            Some((&start, &end)) if value <= end => {
+                if start > T::min_value() {
+                    Some(start - T::one())
+                } else {
+                    None
+                }
+            }
+            _ => Some(value),
+        }
+    }
+
+    /// Answers [`RangeSetBlaze::next_at_or_after`] for each of a sorted sequence of values.
+    ///
+    /// # Performance
+    ///
+    /// The answers come from one pass over the ranges, so *m* queries on a set of *n* ranges take O(*n* + *m*) time,
+    /// rather than O(*m* log *n*).
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the values are not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// let answers: Vec<_> = set.next_at_or_after_sorted([5, 15, 15, 25, 45]).collect();
+    /// assert_eq!(answers, vec![Some(10), Some(15), Some(15), Some(30), None]);
+    /// ```
+    pub fn next_at_or_after_sorted<I>(&self, values: I) -> NeighborsSorted<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        NeighborsSorted::new(self, values.into_iter(), Neighbor::NextAtOrAfter)
+    }
+
+    /// Answers [`RangeSetBlaze::prev_at_or_before`] for each of a sorted sequence of values.
+    /// See [`RangeSetBlaze::next_at_or_after_sorted`] for performance.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the values are not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// let answers: Vec<_> = set.prev_at_or_before_sorted([5, 15, 25, 45]).collect();
+    /// assert_eq!(answers, vec![None, Some(15), Some(19), Some(39)]);
+    /// ```
+    pub fn prev_at_or_before_sorted<I>(&self, values: I) -> NeighborsSorted<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        NeighborsSorted::new(self, values.into_iter(), Neighbor::PrevAtOrBefore)
+    }
+
+    /// Answers [`RangeSetBlaze::nearest`] for each of a sorted sequence of values.
+    /// See [`RangeSetBlaze::next_at_or_after_sorted`] for performance.
+    ///
+    /// # Panics
+    ///
+    /// The iterator panics if the values are not sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let set = RangeSetBlaze::from_iter([10..=19, 30..=39]);
+    /// let answers: Vec<_> = set.nearest_sorted([5, 15, 23, 25, 45]).collect();
+    /// assert_eq!(answers, vec![Some(10), Some(15), Some(19), Some(30), Some(39)]);
+    /// ```
+    pub fn nearest_sorted<I>(&self, values: I) -> NeighborsSorted<'_, T, I::IntoIter>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        NeighborsSorted::new(self, values.into_iter(), Neighbor::Nearest)
+    }
+}
+
+// Of two candidates on either side of `value`, returns the closer one, preferring `prev` on ties.
+fn nearer<T: Integer>(value: T, prev: Option<T>, next: Option<T>) -> Option<T> {
+    match (prev, next) {
+        (Some(prev), Some(next)) => {
+            if T::safe_len(&(prev..=value)) <= T::safe_len(&(value..=next)) {
+                Some(prev)
+            } else {
+                Some(next)
+            }
+        }
+        (prev, None) => prev,
+        (None, next) => next,
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Neighbor {
+    NextAtOrAfter,
+    PrevAtOrBefore,
+    Nearest,
+}
+
+/// An iterator that answers neighbor queries, such as [`RangeSetBlaze::nearest`], for a sorted sequence of values
+/// in one pass over the ranges.
+///
+/// This `struct` is created by [`RangeSetBlaze::next_at_or_after_sorted`], [`RangeSetBlaze::prev_at_or_before_sorted`],
+/// and [`RangeSetBlaze::nearest_sorted`]. See them for examples.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct NeighborsSorted<'a, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    values: I,
+    ranges: Peekable<RangesIter<'a, T>>,
+    // The last range that starts at or before the previous value.
+    prev_range: Option<RangeInclusive<T>>,
+    prev_value: Option<T>,
+    neighbor: Neighbor,
+}
+
+impl<'a, T, I> NeighborsSorted<'a, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    fn new(set: &'a RangeSetBlaze<T>, values: I, neighbor: Neighbor) -> Self {
+        Self {
+            values,
+            ranges: set.ranges().peekable(),
+            prev_range: None,
+            prev_value: None,
+            neighbor,
+        }
+    }
+}
+
+impl<T, I> FusedIterator for NeighborsSorted<'_, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T> + FusedIterator,
+{
+}
+
+impl<T, I> Iterator for NeighborsSorted<'_, T, I>
+where
+    T: Integer,
+    I: Iterator<Item = T>,
+{
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Option<T>> {
+        let value = self.values.next()?;
+        if let Some(prev_value) = self.prev_value {
+            assert!(prev_value <= value, "values must be sorted");
+        }
+        self.prev_value = Some(value);
+        while let Some(range) = self.ranges.next_if(|range| *range.start() <= value) {
+            self.prev_range = Some(range);
+        }
+        let contains = self
+            .prev_range
+            .as_ref()
+            .filter(|range| value <= *range.end())
+            .is_some();
+        if contains {
+            return Some(Some(value));
+        }
+        let prev = self.prev_range.as_ref().map(|range| *range.end());
+        let next = self.ranges.peek().map(|range| *range.start());
+        Some(match self.neighbor {
+            Neighbor::NextAtOrAfter => next,
+            Neighbor::PrevAtOrBefore => prev,
+            Neighbor::Nearest => nearer(value, prev, next),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
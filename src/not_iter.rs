@@ -3,7 +3,7 @@ use core::{
     ops::{self, RangeInclusive},
 };
 
-use crate::{BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Integer, SortedDisjoint};
+use crate::{BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Element, SortedDisjoint};
 
 /// Turns a [`SortedDisjoint`] iterator into a [`SortedDisjoint`] iterator of its complement,
 /// i.e., all the integers not in the original iterator, as sorted & disjoint ranges.
@@ -25,7 +25,7 @@ use crate::{BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Integer, SortedDisj
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct NotIter<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     iter: I,
@@ -35,7 +35,7 @@ where
 
 impl<T, I> NotIter<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     /// Create a new [`NotIter`] from a [`SortedDisjoint`] iterator. See [`NotIter`] for an example.
@@ -53,14 +53,14 @@ where
 
 impl<T, I> FusedIterator for NotIter<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T> + FusedIterator,
 {
 }
 
 impl<T, I> Iterator for NotIter<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     type Item = RangeInclusive<T>;
@@ -76,15 +76,15 @@ where
             if self.start_not < start {
                 // We can subtract with underflow worry because
                 // we know that start > start_not and so not min_value
-                let result = Some(self.start_not..=start - T::one());
+                let result = Some(self.start_not..=start.sub_one());
                 if end < T::safe_max_value() {
-                    self.start_not = end + T::one();
+                    self.start_not = end.add_one();
                 } else {
                     self.next_time_return_none = true;
                 }
                 result
             } else if end < T::safe_max_value() {
-                self.start_not = end + T::one();
+                self.start_not = end.add_one();
                 self.next() // will recurse at most once
             } else {
                 self.next_time_return_none = true;
@@ -111,7 +111,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::Not for NotIter<T, I>
+impl<T: Element, I> ops::Not for NotIter<T, I>
 where
     I: SortedDisjoint<T>,
 {
@@ -124,7 +124,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitOr<R> for NotIter<T, L>
+impl<T: Element, R, L> ops::BitOr<R> for NotIter<T, L>
 where
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
@@ -136,7 +136,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::Sub<R> for NotIter<T, L>
+impl<T: Element, R, L> ops::Sub<R> for NotIter<T, L>
 where
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
@@ -150,7 +150,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitXor<R> for NotIter<T, L>
+impl<T: Element, R, L> ops::BitXor<R> for NotIter<T, L>
 where
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
@@ -166,7 +166,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitAnd<R> for NotIter<T, L>
+impl<T: Element, R, L> ops::BitAnd<R> for NotIter<T, L>
 where
     L: SortedDisjoint<T>,
     R: SortedDisjoint<T>,
@@ -0,0 +1,233 @@
+use core::{
+    cmp::min,
+    iter::FusedIterator,
+    ops::{self, RangeInclusive},
+};
+
+use num_traits::{One, Zero};
+
+use crate::{
+    BitAndMerge, BitOrKMerge, BitOrMerge, BitSubMerge, BitXOrTee, Integer, MultiwaySortedDisjoint,
+    NotIter, SortedDisjoint, SortedStarts,
+};
+
+/// A [`SortedDisjoint`] iterator of ranges that repeat with a fixed period, for example,
+/// "every 15 minutes, for 5 minutes".
+///
+/// The ranges are `start + k*period ..= start + k*period + on_len - 1` for `k = 0, 1, 2, ...`,
+/// clipped to end at `until` (inclusive). The iterator is lazy, so `until` can be as large as
+/// [`Element::safe_max_value`](crate::Element::safe_max_value). The `period` and `on_len` are lengths, so they have type [`Element::SafeLen`](crate::Element::SafeLen).
+///
+/// If `on_len` is `0`, the iterator is empty. If `on_len` is at least `period`, the ranges touch, so
+/// the iterator returns the single range `start..=until`. Setting `on_len` to `1` gives an arithmetic progression.
+///
+/// To combine several periodic schedules, use [`Periodic::with_offsets`] or any of the [`SortedDisjoint`] operations.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{Periodic, SortedDisjoint};
+///
+/// // Every 15 minutes, for 5 minutes, during the first hour.
+/// let a = Periodic::new(0i32, 15, 5, 59);
+/// assert_eq!(a.to_string(), "0..=4, 15..=19, 30..=34, 45..=49");
+///
+/// // The multiples of 3 in 1..=10.
+/// let a = Periodic::arithmetic(3i32, 3, 10);
+/// assert_eq!(a.to_string(), "3..=3, 6..=6, 9..=9");
+///
+/// // Unbounded (up to the largest `u8`).
+/// let a = Periodic::new(200u8, 20, 10, u8::MAX);
+/// assert_eq!(a.to_string(), "200..=209, 220..=229, 240..=249");
+/// ```
+///
+/// The ordinal weekends of 2023, as in the [`SortedDisjoint`] example. January 1, 2023, is a Sunday.
+///
+/// ```
+/// use range_set_blaze::prelude::*;
+/// use range_set_blaze::Periodic;
+///
+/// let weekends = Periodic::new(1i32, 7, 1, 365).with_offsets([0, 6]);
+/// let september = CheckSortedDisjoint::from([244..=273]);
+/// let september_weekdays = september - weekends;
+/// assert_eq!(
+///     september_weekdays.to_string(),
+///     "244..=244, 247..=251, 254..=258, 261..=265, 268..=272"
+/// );
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Periodic<T: Integer> {
+    next_start: Option<T>,
+    period: T::SafeLen,
+    on_len: T::SafeLen,
+    until: T,
+}
+
+impl<T: Integer> Periodic<T> {
+    /// Creates a [`Periodic`] iterator. See [`Periodic`] for details and examples.
+    ///
+    /// If `until` is larger than [`Element::safe_max_value`](crate::Element::safe_max_value), it is treated as [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is `0`.
+    pub fn new(start: T, period: T::SafeLen, on_len: T::SafeLen, until: T) -> Self {
+        assert!(!period.is_zero(), "period must be greater than 0");
+        let until = min(until, T::safe_max_value());
+        let next_start = if on_len.is_zero() || start > until {
+            None
+        } else {
+            Some(start)
+        };
+        Self {
+            next_start,
+            period,
+            on_len,
+            until,
+        }
+    }
+
+    /// Creates a [`Periodic`] iterator of the single integers `start`, `start + step`, `start + 2*step`, ...,
+    /// up to `until` (inclusive).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{Periodic, SortedDisjoint};
+    ///
+    /// let a = Periodic::arithmetic(-10i32, 5, 4);
+    /// assert_eq!(a.to_string(), "-10..=-10, -5..=-5, 0..=0");
+    /// ```
+    pub fn arithmetic(start: T, step: T::SafeLen, until: T) -> Self {
+        Self::new(start, step, T::SafeLen::one(), until)
+    }
+
+    /// Shifts the (remaining) ranges of a [`Periodic`] iterator later by `offset`. The end, `until`, stays the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{Periodic, SortedDisjoint};
+    ///
+    /// let a = Periodic::new(0i32, 10, 2, 30).offset(5);
+    /// assert_eq!(a.to_string(), "5..=6, 15..=16, 25..=26");
+    /// ```
+    pub fn offset(mut self, offset: T::SafeLen) -> Self {
+        if let Some(start) = self.next_start {
+            self.next_start = if offset.is_zero() {
+                Some(start)
+            } else if offset < T::safe_len(&(start..=self.until)) {
+                Some(T::add_len_less_one(start, offset) + T::one())
+            } else {
+                None
+            };
+        }
+        self
+    }
+
+    /// Unions copies of a [`Periodic`] iterator, each shifted later by one of the given offsets.
+    ///
+    /// This makes it easy to describe compound schedules, for example, "minutes 0 to 4 and 20 to 24 of every hour".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{Periodic, SortedDisjoint};
+    ///
+    /// let a = Periodic::new(0i32, 60, 5, 179).with_offsets([0, 20]);
+    /// assert_eq!(a.to_string(), "0..=4, 20..=24, 60..=64, 80..=84, 120..=124, 140..=144");
+    /// ```
+    pub fn with_offsets<I>(self, offsets: I) -> BitOrKMerge<T, Self>
+    where
+        I: IntoIterator<Item = T::SafeLen>,
+    {
+        offsets
+            .into_iter()
+            .map(|offset| self.clone().offset(offset))
+            .union()
+    }
+}
+
+impl<T: Integer> FusedIterator for Periodic<T> {}
+
+impl<T: Integer> Iterator for Periodic<T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        let start = self.next_start?;
+        // The number of integers left, counting `start`.
+        let remaining = T::safe_len(&(start..=self.until));
+        if self.on_len >= self.period || self.on_len >= remaining {
+            self.next_start = None;
+            return Some(start..=self.until);
+        }
+        let end = T::add_len_less_one(start, self.on_len);
+        self.next_start = if self.period < remaining {
+            Some(T::add_len_less_one(start, self.period) + T::one())
+        } else {
+            None
+        };
+        Some(start..=end)
+    }
+}
+
+impl<T: Integer> SortedStarts<T> for Periodic<T> {}
+impl<T: Integer> SortedDisjoint<T> for Periodic<T> {}
+
+impl<T: Integer> ops::Not for Periodic<T> {
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: Integer, R> ops::BitOr<R> for Periodic<T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitAnd<R> for Periodic<T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::Sub<R> for Periodic<T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T: Integer, R> ops::BitXor<R> for Periodic<T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
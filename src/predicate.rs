@@ -0,0 +1,177 @@
+use core::ops::RangeInclusive;
+
+use alloc::vec::Vec;
+use num_traits::{Bounded, One};
+
+use crate::{CheckSortedDisjoint, Integer, RangeSetBlaze, SortedDisjoint};
+
+impl<T: Integer> RangeSetBlaze<T> {
+    /// Creates a [`RangeSetBlaze`] of the integers in `range` for which the predicate `f` is true,
+    /// assuming `f` is piecewise-constant with few pieces.
+    ///
+    /// Rather than call `f` on every integer, this searches for the points where `f` changes value.
+    /// From the start of each piece, it probes ahead at exponentially growing distances (galloping) until `f`
+    /// changes, then binary searches for the exact change point.
+    ///
+    /// # Performance
+    ///
+    /// With *k* pieces in a range of *n* integers, `f` is called O(*k* log *n*) times.
+    ///
+    /// Because `f` is not called on every integer, a piece can be missed if it starts and ends between two probes.
+    /// This can only happen to a piece that is shorter than the piece before it, so the result is exact
+    /// if the pieces never get shorter (for example, if they all have the same length). Otherwise, use this
+    /// only when missing a short piece is acceptable.
+    /// For a predicate that changes value at most once, see [`RangeSetBlaze::from_monotone`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of `range` is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// let a = RangeSetBlaze::from_predicate(0..=499_999, |x| (x / 100_000) % 2 == 1);
+    /// assert_eq!(a.to_string(), "100000..=199999, 300000..=399999");
+    ///
+    /// let a = RangeSetBlaze::from_predicate(i8::MIN..=i8::MAX, |x| x.unsigned_abs() > 100);
+    /// assert_eq!(a.to_string(), "-128..=-101, 101..=127");
+    /// ```
+    pub fn from_predicate<F>(range: RangeInclusive<T>, mut f: F) -> Self
+    where
+        F: FnMut(T) -> bool,
+    {
+        let (start, end) = range.into_inner();
+        assert!(
+            end <= T::safe_max_value(),
+            "end must be less than or equal to safe_max_value"
+        );
+        if start > end {
+            return Self::new();
+        }
+        let mut ranges = Vec::new();
+        let mut piece_start = start;
+        let mut value = f(piece_start);
+        loop {
+            let piece_end = piece_end(piece_start, end, value, &mut f);
+            if value {
+                ranges.push(piece_start..=piece_end);
+            }
+            if piece_end == end {
+                break;
+            }
+            // piece_end + 1 is the first integer known to have the other value.
+            piece_start = piece_end + T::one();
+            value = !value;
+        }
+        CheckSortedDisjoint::new(ranges).into_range_set_blaze()
+    }
+
+    /// Creates a [`RangeSetBlaze`] of the integers in `range` for which the predicate `f` is true,
+    /// assuming `f` is monotone, that is, it changes value at most once in `range`.
+    ///
+    /// The predicate may go from false to true (giving a range that ends at the end of `range`) or
+    /// from true to false (giving a range that starts at the start of `range`).
+    ///
+    /// # Performance
+    ///
+    /// With *n* integers in `range`, `f` is called O(log *n*) times.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the end of `range` is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    ///
+    /// // The integers whose square is at least 2,000,000.
+    /// let a = RangeSetBlaze::from_monotone(0u64..=10_000, |x| x * x >= 2_000_000);
+    /// assert_eq!(a.to_string(), "1415..=10000");
+    ///
+    /// // The integers whose square is less than 2,000,000.
+    /// let a = RangeSetBlaze::from_monotone(0u64..=10_000, |x| x * x < 2_000_000);
+    /// assert_eq!(a.to_string(), "0..=1414");
+    /// ```
+    pub fn from_monotone<F>(range: RangeInclusive<T>, mut f: F) -> Self
+    where
+        F: FnMut(T) -> bool,
+    {
+        let (start, end) = range.into_inner();
+        assert!(
+            end <= T::safe_max_value(),
+            "end must be less than or equal to safe_max_value"
+        );
+        if start > end {
+            return Self::new();
+        }
+        let first = f(start);
+        if first == f(end) {
+            return if first {
+                Self::from_iter([start..=end])
+            } else {
+                Self::new()
+            };
+        }
+        let last_same = last_same(start, end, first, &mut f);
+        if first {
+            Self::from_iter([start..=last_same])
+        } else {
+            Self::from_iter([last_same + T::one()..=end])
+        }
+    }
+}
+
+// Given that f(start) == value, gallops forward to find the last integer of the piece that starts at `start`.
+fn piece_end<T, F>(start: T, end: T, value: bool, f: &mut F) -> T
+where
+    T: Integer,
+    F: FnMut(T) -> bool,
+{
+    let mut same = start;
+    let mut step = T::SafeLen::one();
+    loop {
+        if same == end {
+            return end;
+        }
+        // The number of integers after `same`.
+        let after = T::safe_len(&(same..=end)) - T::SafeLen::one();
+        let probe = if step < after {
+            T::add_len_less_one(same, step) + T::one()
+        } else {
+            end
+        };
+        if f(probe) != value {
+            return last_same(same, probe, value, f);
+        }
+        same = probe;
+        if step <= T::SafeLen::max_value() / (T::SafeLen::one() + T::SafeLen::one()) {
+            step += step;
+        }
+    }
+}
+
+// Given that f(same) == value and f(different) != value, binary searches for the last integer before
+// `different` that has the same value as `same`.
+fn last_same<T, F>(mut same: T, mut different: T, value: bool, f: &mut F) -> T
+where
+    T: Integer,
+    F: FnMut(T) -> bool,
+{
+    let two = T::SafeLen::one() + T::SafeLen::one();
+    loop {
+        let len = T::safe_len(&(same..=different));
+        if len <= two {
+            return same;
+        }
+        // same < mid < different
+        let mid = T::add_len_less_one(same, len / two) + T::one();
+        if f(mid) == value {
+            same = mid;
+        } else {
+            different = mid;
+        }
+    }
+}
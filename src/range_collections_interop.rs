@@ -0,0 +1,176 @@
+use core::{
+    iter::FusedIterator,
+    ops::{self, RangeInclusive},
+};
+
+use range_collections::{
+    range_set::{self, RangeSetEntry, RangeSetRange},
+    RangeSet, RangeSetRef,
+};
+use smallvec::Array;
+
+use crate::{
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Integer, NotIter, RangeSetBlaze,
+    SortedDisjoint, SortedStarts,
+};
+
+/// A [`SortedDisjoint`] iterator over the ranges of a [`range_collections::RangeSet`].
+///
+/// A `RangeSet` stores half-open boundaries, so this iterator turns each `start..end` into
+/// `start..=end - 1`. A final unbounded `start..` becomes `start..=T::safe_max_value()`.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{prelude::*, RangeCollectionsRangesIter};
+/// use range_collections::RangeSet2;
+///
+/// let legacy = RangeSet2::from(1u32..4) | RangeSet2::from(10u32..);
+/// assert_eq!(RangeCollectionsRangesIter::new(&legacy).to_string(), "1..=3, 10..=4294967295");
+///
+/// let set = RangeSetBlaze::from_iter([2u32..=15]);
+/// let intersection = set.ranges() & RangeCollectionsRangesIter::new(&legacy);
+/// assert_eq!(intersection.to_string(), "2..=3, 10..=15");
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RangeCollectionsRangesIter<'a, T> {
+    iter: range_set::Iter<'a, T>,
+}
+
+impl<'a, T> RangeCollectionsRangesIter<'a, T>
+where
+    T: Integer + RangeSetEntry,
+{
+    /// Creates an iterator over the ranges of a [`range_collections::RangeSet`] or [`range_collections::RangeSetRef`].
+    pub fn new(set: &'a RangeSetRef<T>) -> Self {
+        Self { iter: set.iter() }
+    }
+}
+
+impl<T> FusedIterator for RangeCollectionsRangesIter<'_, T> where T: Integer + RangeSetEntry {}
+
+impl<T> Iterator for RangeCollectionsRangesIter<'_, T>
+where
+    T: Integer + RangeSetEntry,
+{
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        Some(match self.iter.next()? {
+            RangeSetRange::Range(range) => *range.start..=*range.end - T::one(),
+            RangeSetRange::RangeFrom(range) => *range.start..=T::safe_max_value(),
+        })
+    }
+}
+
+impl<T> SortedStarts<T> for RangeCollectionsRangesIter<'_, T> where T: Integer + RangeSetEntry {}
+impl<T> SortedDisjoint<T> for RangeCollectionsRangesIter<'_, T> where T: Integer + RangeSetEntry {}
+
+impl<T, A> From<&RangeSet<A>> for RangeSetBlaze<T>
+where
+    T: Integer + RangeSetEntry,
+    A: Array<Item = T>,
+{
+    /// Converts a [`range_collections::RangeSet`] into a [`RangeSetBlaze`] in one pass over its boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use range_collections::RangeSet2;
+    ///
+    /// let legacy = RangeSet2::from(1u8..4) | RangeSet2::from(250u8..);
+    /// assert_eq!(RangeSetBlaze::from(&legacy).to_string(), "1..=3, 250..=255");
+    /// ```
+    fn from(set: &RangeSet<A>) -> Self {
+        Self::from_sorted_disjoint(RangeCollectionsRangesIter::new(set))
+    }
+}
+
+impl<T, A> From<&RangeSetBlaze<T>> for RangeSet<A>
+where
+    T: Integer + RangeSetEntry,
+    A: Array<Item = T>,
+{
+    /// Converts a [`RangeSetBlaze`] into a [`range_collections::RangeSet`], writing its boundaries in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use range_collections::RangeSet2;
+    ///
+    /// let set = RangeSetBlaze::from_iter([1u8..=3, 250..=255]);
+    /// let legacy: RangeSet2<u8> = (&set).into();
+    /// assert_eq!(legacy, RangeSet2::from(1u8..4) | RangeSet2::from(250u8..));
+    /// ```
+    fn from(set: &RangeSetBlaze<T>) -> Self {
+        let boundaries = set.ranges().flat_map(|range| {
+            let (start, end) = range.into_inner();
+            // A range that reaches the maximum value has no exclusive end, so it becomes `start..`.
+            [Some(start), (end < T::max_value()).then(|| end + T::one())]
+        });
+        Self::new(boundaries.flatten().collect())
+            .expect("boundaries of a RangeSetBlaze are strictly sorted")
+    }
+}
+
+impl<T> ops::Not for RangeCollectionsRangesIter<'_, T>
+where
+    T: Integer + RangeSetEntry,
+{
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T, R> ops::BitOr<R> for RangeCollectionsRangesIter<'_, T>
+where
+    T: Integer + RangeSetEntry,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T, R> ops::BitAnd<R> for RangeCollectionsRangesIter<'_, T>
+where
+    T: Integer + RangeSetEntry,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T, R> ops::Sub<R> for RangeCollectionsRangesIter<'_, T>
+where
+    T: Integer + RangeSetEntry,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T, R> ops::BitXor<R> for RangeCollectionsRangesIter<'_, T>
+where
+    T: Integer + RangeSetEntry,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
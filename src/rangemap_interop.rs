@@ -0,0 +1,166 @@
+use core::{
+    iter::FusedIterator,
+    ops::{self, RangeInclusive},
+};
+
+use rangemap::{inclusive_set, RangeInclusiveSet, StepFns, StepLite};
+
+use crate::{
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Integer, NotIter, RangeSetBlaze,
+    SortedDisjoint, SortedStarts,
+};
+
+/// A [`SortedDisjoint`] iterator over the ranges of a [`rangemap::RangeInclusiveSet`].
+///
+/// A `RangeInclusiveSet` already keeps its ranges sorted, disjoint, and non-touching, so this
+/// iterator just clones each range.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{prelude::*, RangeMapRangesIter};
+/// use rangemap::RangeInclusiveSet;
+///
+/// let legacy = RangeInclusiveSet::from_iter([1u32..=3, 10..=20]);
+/// let set = RangeSetBlaze::from_iter([2u32..=15]);
+/// let union = set.ranges() | RangeMapRangesIter::new(&legacy);
+/// assert_eq!(union.to_string(), "1..=20");
+/// let intersection = set.ranges() & RangeMapRangesIter::new(&legacy);
+/// assert_eq!(intersection.to_string(), "2..=3, 10..=15");
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RangeMapRangesIter<'a, T> {
+    iter: inclusive_set::Iter<'a, T>,
+}
+
+impl<'a, T> RangeMapRangesIter<'a, T>
+where
+    T: Integer + StepLite,
+{
+    /// Creates an iterator over the ranges of a [`rangemap::RangeInclusiveSet`].
+    pub fn new<S: StepFns<T>>(set: &'a RangeInclusiveSet<T, S>) -> Self {
+        Self { iter: set.iter() }
+    }
+}
+
+impl<T> FusedIterator for RangeMapRangesIter<'_, T> where T: Integer + StepLite {}
+
+impl<T> Iterator for RangeMapRangesIter<'_, T>
+where
+    T: Integer + StepLite,
+{
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        self.iter.next().cloned()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> SortedStarts<T> for RangeMapRangesIter<'_, T> where T: Integer + StepLite {}
+impl<T> SortedDisjoint<T> for RangeMapRangesIter<'_, T> where T: Integer + StepLite {}
+
+impl<T, S> From<&RangeInclusiveSet<T, S>> for RangeSetBlaze<T>
+where
+    T: Integer + StepLite,
+    S: StepFns<T>,
+{
+    /// Converts a [`rangemap::RangeInclusiveSet`] into a [`RangeSetBlaze`] in one pass over its ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use rangemap::RangeInclusiveSet;
+    ///
+    /// let legacy = RangeInclusiveSet::from_iter([1u8..=3, 4..=5, 10..=20]);
+    /// assert_eq!(RangeSetBlaze::from(&legacy).to_string(), "1..=5, 10..=20");
+    /// ```
+    fn from(set: &RangeInclusiveSet<T, S>) -> Self {
+        Self::from_sorted_disjoint(RangeMapRangesIter::new(set))
+    }
+}
+
+impl<T> From<&RangeSetBlaze<T>> for RangeInclusiveSet<T>
+where
+    T: Integer + StepLite,
+{
+    /// Converts a [`RangeSetBlaze`] into a [`rangemap::RangeInclusiveSet`], inserting it range by range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use rangemap::RangeInclusiveSet;
+    ///
+    /// let set = RangeSetBlaze::from_iter([1u8..=5, 10..=20]);
+    /// let legacy: RangeInclusiveSet<u8> = (&set).into();
+    /// assert_eq!(legacy, RangeInclusiveSet::from_iter([1..=5, 10..=20]));
+    /// ```
+    fn from(set: &RangeSetBlaze<T>) -> Self {
+        set.ranges().collect()
+    }
+}
+
+impl<T> ops::Not for RangeMapRangesIter<'_, T>
+where
+    T: Integer + StepLite,
+{
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T, R> ops::BitOr<R> for RangeMapRangesIter<'_, T>
+where
+    T: Integer + StepLite,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T, R> ops::BitAnd<R> for RangeMapRangesIter<'_, T>
+where
+    T: Integer + StepLite,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T, R> ops::Sub<R> for RangeMapRangesIter<'_, T>
+where
+    T: Integer + StepLite,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T, R> ops::BitXor<R> for RangeMapRangesIter<'_, T>
+where
+    T: Integer + StepLite,
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
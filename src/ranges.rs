@@ -7,7 +7,7 @@ use core::{
 use itertools::Itertools;
 
 use crate::{
-    BitAndMerge, BitOrMerge, BitSubMerge, BitXOr, BitXOrTee, Integer, NotIter, SortedDisjoint,
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOr, BitXOrTee, Element, NotIter, SortedDisjoint,
     SortedStarts,
 };
 
@@ -21,11 +21,11 @@ use crate::{
 /// [`ranges`]: crate::RangeSetBlaze::ranges
 #[derive(Clone, Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct RangesIter<'a, T: Integer> {
+pub struct RangesIter<'a, T: Element> {
     pub(crate) iter: btree_map::Iter<'a, T, T>,
 }
 
-impl<'a, T: Integer> AsRef<RangesIter<'a, T>> for RangesIter<'a, T> {
+impl<'a, T: Element> AsRef<RangesIter<'a, T>> for RangesIter<'a, T> {
     fn as_ref(&self) -> &Self {
         // Self is RangesIter<'a>, the type for which we impl AsRef
         self
@@ -33,20 +33,20 @@ impl<'a, T: Integer> AsRef<RangesIter<'a, T>> for RangesIter<'a, T> {
 }
 
 // RangesIter (one of the iterators from RangeSetBlaze) is SortedDisjoint
-impl<T: Integer> SortedStarts<T> for RangesIter<'_, T> {}
-impl<T: Integer> SortedDisjoint<T> for RangesIter<'_, T> {}
+impl<T: Element> SortedStarts<T> for RangesIter<'_, T> {}
+impl<T: Element> SortedDisjoint<T> for RangesIter<'_, T> {}
 
-impl<T: Integer> ExactSizeIterator for RangesIter<'_, T> {
+impl<T: Element> ExactSizeIterator for RangesIter<'_, T> {
     #[must_use]
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<'a, T: Integer> FusedIterator for RangesIter<'a, T> {}
+impl<'a, T: Element> FusedIterator for RangesIter<'a, T> {}
 
 // Range's iterator is just the inside BTreeMap iterator as values
-impl<'a, T: Integer> Iterator for RangesIter<'a, T> {
+impl<'a, T: Element> Iterator for RangesIter<'a, T> {
     type Item = RangeInclusive<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -58,7 +58,7 @@ impl<'a, T: Integer> Iterator for RangesIter<'a, T> {
     }
 }
 
-impl<T: Integer> DoubleEndedIterator for RangesIter<'_, T> {
+impl<T: Element> DoubleEndedIterator for RangesIter<'_, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
             .map(|(start, end)| *start..=*end)
@@ -75,24 +75,24 @@ impl<T: Integer> DoubleEndedIterator for RangesIter<'_, T> {
 /// [`RangeSetBlaze`]: crate::RangeSetBlaze
 /// [`into_ranges`]: crate::RangeSetBlaze::into_ranges
 #[derive(Debug)]
-pub struct IntoRangesIter<T: Integer> {
+pub struct IntoRangesIter<T: Element> {
     pub(crate) iter: alloc::collections::btree_map::IntoIter<T, T>,
 }
 
-impl<T: Integer> SortedStarts<T> for IntoRangesIter<T> {}
-impl<T: Integer> SortedDisjoint<T> for IntoRangesIter<T> {}
+impl<T: Element> SortedStarts<T> for IntoRangesIter<T> {}
+impl<T: Element> SortedDisjoint<T> for IntoRangesIter<T> {}
 
-impl<T: Integer> ExactSizeIterator for IntoRangesIter<T> {
+impl<T: Element> ExactSizeIterator for IntoRangesIter<T> {
     #[must_use]
     fn len(&self) -> usize {
         self.iter.len()
     }
 }
 
-impl<T: Integer> FusedIterator for IntoRangesIter<T> {}
+impl<T: Element> FusedIterator for IntoRangesIter<T> {}
 
 // Range's iterator is just the inside BTreeMap iterator as values
-impl<T: Integer> Iterator for IntoRangesIter<T> {
+impl<T: Element> Iterator for IntoRangesIter<T> {
     type Item = RangeInclusive<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -104,7 +104,7 @@ impl<T: Integer> Iterator for IntoRangesIter<T> {
     }
 }
 
-impl<T: Integer> DoubleEndedIterator for IntoRangesIter<T> {
+impl<T: Element> DoubleEndedIterator for IntoRangesIter<T> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back()
             .map(|(start, end)| start..=end)
@@ -112,7 +112,7 @@ impl<T: Integer> DoubleEndedIterator for IntoRangesIter<T> {
 }
 
 
-impl<T: Integer> ops::Not for RangesIter<'_, T> {
+impl<T: Element> ops::Not for RangesIter<'_, T> {
     type Output = NotIter<T, Self>;
 
     fn not(self) -> Self::Output {
@@ -120,7 +120,7 @@ impl<T: Integer> ops::Not for RangesIter<'_, T> {
     }
 }
 
-impl<T: Integer> ops::Not for IntoRangesIter<T> {
+impl<T: Element> ops::Not for IntoRangesIter<T> {
     type Output = NotIter<T, Self>;
 
     fn not(self) -> Self::Output {
@@ -128,7 +128,7 @@ impl<T: Integer> ops::Not for IntoRangesIter<T> {
     }
 }
 
-impl<T: Integer, I> ops::BitOr<I> for RangesIter<'_, T>
+impl<T: Element, I> ops::BitOr<I> for RangesIter<'_, T>
 where
     I: SortedDisjoint<T>,
 {
@@ -139,7 +139,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::BitOr<I> for IntoRangesIter<T>
+impl<T: Element, I> ops::BitOr<I> for IntoRangesIter<T>
 where
     I: SortedDisjoint<T>,
 {
@@ -150,7 +150,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::Sub<I> for RangesIter<'_, T>
+impl<T: Element, I> ops::Sub<I> for RangesIter<'_, T>
 where
     I: SortedDisjoint<T>,
 {
@@ -161,7 +161,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::Sub<I> for IntoRangesIter<T>
+impl<T: Element, I> ops::Sub<I> for IntoRangesIter<T>
 where
     I: SortedDisjoint<T>,
 {
@@ -172,7 +172,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::BitXor<I> for RangesIter<'_, T>
+impl<T: Element, I> ops::BitXor<I> for RangesIter<'_, T>
 where
     I: SortedDisjoint<T>,
 {
@@ -187,7 +187,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::BitXor<I> for IntoRangesIter<T>
+impl<T: Element, I> ops::BitXor<I> for IntoRangesIter<T>
 where
     I: SortedDisjoint<T>,
 {
@@ -199,7 +199,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::BitAnd<I> for RangesIter<'_, T>
+impl<T: Element, I> ops::BitAnd<I> for RangesIter<'_, T>
 where
     I: SortedDisjoint<T>,
 {
@@ -211,7 +211,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::BitAnd<I> for IntoRangesIter<T>
+impl<T: Element, I> ops::BitAnd<I> for IntoRangesIter<T>
 where
     I: SortedDisjoint<T>,
 {
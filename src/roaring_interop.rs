@@ -0,0 +1,315 @@
+use core::{
+    iter::FusedIterator,
+    ops::{self, RangeInclusive},
+};
+
+use roaring::{bitmap, treemap, RoaringBitmap, RoaringTreemap};
+
+use crate::{
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, NotIter, RangeSetBlaze, SortedDisjoint,
+    SortedStarts,
+};
+
+/// A [`SortedDisjoint`] iterator over the runs of consecutive integers in a [`RoaringBitmap`].
+///
+/// It finds each run with a few range lookups and then skips the bitmap's iterator past it, so the work
+/// grows with the number of runs, not the number of integers.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{prelude::*, RoaringRangesIter};
+/// use roaring::RoaringBitmap;
+///
+/// let mut bitmap = RoaringBitmap::new();
+/// bitmap.insert_range(10..=1_000_000);
+/// bitmap.insert(2_000_000);
+/// assert_eq!(RoaringRangesIter::new(&bitmap).to_string(), "10..=1000000, 2000000..=2000000");
+///
+/// let set = RangeSetBlaze::from_iter([0u32..=99, 1_999_999..=2_000_001]);
+/// let both = set.ranges() & RoaringRangesIter::new(&bitmap);
+/// assert_eq!(both.to_string(), "10..=99, 2000000..=2000000");
+/// ```
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoaringRangesIter<'a> {
+    bitmap: &'a RoaringBitmap,
+    iter: bitmap::Iter<'a>,
+}
+
+impl<'a> RoaringRangesIter<'a> {
+    /// Creates an iterator over the runs of a [`RoaringBitmap`].
+    pub fn new(bitmap: &'a RoaringBitmap) -> Self {
+        Self {
+            bitmap,
+            iter: bitmap.iter(),
+        }
+    }
+
+    // Returns the end of the run that starts at `start`, galloping and then binary searching with `contains_range`.
+    fn run_end(&self, start: u32) -> u32 {
+        // `inside` is always in the run; `outside` never is.
+        let (mut inside, mut step) = (start, 1u32);
+        let mut outside = loop {
+            let Some(probe) = inside.checked_add(step) else {
+                if self.bitmap.contains_range(start..=u32::MAX) {
+                    return u32::MAX;
+                }
+                break u32::MAX;
+            };
+            if !self.bitmap.contains_range(start..=probe) {
+                break probe;
+            }
+            inside = probe;
+            step = step.saturating_mul(2);
+        };
+        while outside - inside > 1 {
+            let mid = inside + (outside - inside) / 2;
+            if self.bitmap.contains_range(start..=mid) {
+                inside = mid;
+            } else {
+                outside = mid;
+            }
+        }
+        inside
+    }
+}
+
+impl FusedIterator for RoaringRangesIter<'_> {}
+
+impl Iterator for RoaringRangesIter<'_> {
+    type Item = RangeInclusive<u32>;
+
+    fn next(&mut self) -> Option<RangeInclusive<u32>> {
+        let start = self.iter.next()?;
+        let end = self.run_end(start);
+        // Skip the rest of the run, including `end` itself, without stepping through it.
+        self.iter.advance_to(end);
+        self.iter.next();
+        Some(start..=end)
+    }
+}
+
+/// A [`SortedDisjoint`] iterator over the runs of consecutive integers in a [`RoaringTreemap`].
+///
+/// It reads each of the treemap's bitmaps with a [`RoaringRangesIter`], joining runs that cross
+/// from one bitmap to the next.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{prelude::*, RoaringTreemapRangesIter};
+/// use roaring::RoaringTreemap;
+///
+/// let mut treemap = RoaringTreemap::new();
+/// treemap.insert_range(u64::from(u32::MAX) - 1..=u64::from(u32::MAX) + 1);
+/// assert_eq!(RoaringTreemapRangesIter::new(&treemap).to_string(), "4294967294..=4294967296");
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoaringTreemapRangesIter<'a> {
+    bitmaps: treemap::BitmapIter<'a>,
+    // The runs of the bitmap being read, and the value its integers are offset by.
+    current: Option<(u64, RoaringRangesIter<'a>)>,
+    // A run that may yet touch the next one.
+    pending: Option<RangeInclusive<u64>>,
+}
+
+impl<'a> RoaringTreemapRangesIter<'a> {
+    /// Creates an iterator over the runs of a [`RoaringTreemap`].
+    pub fn new(treemap: &'a RoaringTreemap) -> Self {
+        Self {
+            bitmaps: treemap.bitmaps(),
+            current: None,
+            pending: None,
+        }
+    }
+
+    fn next_run(&mut self) -> Option<RangeInclusive<u64>> {
+        loop {
+            if let Some((base, runs)) = &mut self.current {
+                if let Some(run) = runs.next() {
+                    return Some(*base + u64::from(*run.start())..=*base + u64::from(*run.end()));
+                }
+            }
+            let (high, bitmap) = self.bitmaps.next()?;
+            self.current = Some((u64::from(high) << 32, RoaringRangesIter::new(bitmap)));
+        }
+    }
+}
+
+impl FusedIterator for RoaringTreemapRangesIter<'_> {}
+
+impl Iterator for RoaringTreemapRangesIter<'_> {
+    type Item = RangeInclusive<u64>;
+
+    fn next(&mut self) -> Option<RangeInclusive<u64>> {
+        while let Some(run) = self.next_run() {
+            match self.pending.take() {
+                Some(pending) if *pending.end() + 1 == *run.start() => {
+                    self.pending = Some(*pending.start()..=*run.end());
+                }
+                Some(pending) => {
+                    self.pending = Some(run);
+                    return Some(pending);
+                }
+                None => self.pending = Some(run),
+            }
+        }
+        self.pending.take()
+    }
+}
+
+impl From<&RoaringBitmap> for RangeSetBlaze<u32> {
+    /// Converts a [`RoaringBitmap`] into a [`RangeSetBlaze`], reading it run by run.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use roaring::RoaringBitmap;
+    ///
+    /// let bitmap = RoaringBitmap::from_iter([1, 2, 3, 7]);
+    /// assert_eq!(RangeSetBlaze::from(&bitmap).to_string(), "1..=3, 7..=7");
+    /// ```
+    fn from(bitmap: &RoaringBitmap) -> Self {
+        Self::from_sorted_disjoint(RoaringRangesIter::new(bitmap))
+    }
+}
+
+impl From<&RangeSetBlaze<u32>> for RoaringBitmap {
+    /// Converts a [`RangeSetBlaze`] into a [`RoaringBitmap`], inserting it range by range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::RangeSetBlaze;
+    /// use roaring::RoaringBitmap;
+    ///
+    /// let set = RangeSetBlaze::from_iter([1u32..=3, 7..=7]);
+    /// assert_eq!(RoaringBitmap::from(&set), RoaringBitmap::from_iter([1, 2, 3, 7]));
+    /// ```
+    fn from(set: &RangeSetBlaze<u32>) -> Self {
+        let mut bitmap = Self::new();
+        for range in set.ranges() {
+            bitmap.insert_range(range);
+        }
+        bitmap
+    }
+}
+
+impl From<&RoaringTreemap> for RangeSetBlaze<u64> {
+    /// Converts a [`RoaringTreemap`] into a [`RangeSetBlaze`], reading it run by run.
+    fn from(treemap: &RoaringTreemap) -> Self {
+        Self::from_sorted_disjoint(RoaringTreemapRangesIter::new(treemap))
+    }
+}
+
+impl From<&RangeSetBlaze<u64>> for RoaringTreemap {
+    /// Converts a [`RangeSetBlaze`] into a [`RoaringTreemap`], inserting it range by range.
+    fn from(set: &RangeSetBlaze<u64>) -> Self {
+        let mut treemap = Self::new();
+        for range in set.ranges() {
+            treemap.insert_range(range);
+        }
+        treemap
+    }
+}
+
+macro_rules! impl_roaring_ops {
+    ($roaring:ty, $ranges_iter:ident, $t:ty) => {
+        impl SortedStarts<$t> for $ranges_iter<'_> {}
+        impl SortedDisjoint<$t> for $ranges_iter<'_> {}
+
+        impl ops::Not for $ranges_iter<'_> {
+            type Output = NotIter<$t, Self>;
+
+            fn not(self) -> Self::Output {
+                self.complement()
+            }
+        }
+
+        impl<R> ops::BitOr<R> for $ranges_iter<'_>
+        where
+            R: SortedDisjoint<$t>,
+        {
+            type Output = BitOrMerge<$t, Self, R>;
+
+            fn bitor(self, other: R) -> Self::Output {
+                SortedDisjoint::union(self, other)
+            }
+        }
+
+        impl<R> ops::BitAnd<R> for $ranges_iter<'_>
+        where
+            R: SortedDisjoint<$t>,
+        {
+            type Output = BitAndMerge<$t, Self, R>;
+
+            fn bitand(self, other: R) -> Self::Output {
+                SortedDisjoint::intersection(self, other)
+            }
+        }
+
+        impl<R> ops::Sub<R> for $ranges_iter<'_>
+        where
+            R: SortedDisjoint<$t>,
+        {
+            type Output = BitSubMerge<$t, Self, R>;
+
+            fn sub(self, other: R) -> Self::Output {
+                SortedDisjoint::difference(self, other)
+            }
+        }
+
+        impl<R> ops::BitXor<R> for $ranges_iter<'_>
+        where
+            R: SortedDisjoint<$t>,
+        {
+            type Output = BitXOrTee<$t, Self, R>;
+
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn bitxor(self, other: R) -> Self::Output {
+                SortedDisjoint::symmetric_difference(self, other)
+            }
+        }
+
+        impl ops::BitOr<&$roaring> for &RangeSetBlaze<$t> {
+            type Output = RangeSetBlaze<$t>;
+
+            /// Unions a [`RangeSetBlaze`] with a roaring set, reading the roaring set run by run.
+            fn bitor(self, other: &$roaring) -> RangeSetBlaze<$t> {
+                (self.ranges() | $ranges_iter::new(other)).into_range_set_blaze()
+            }
+        }
+
+        impl ops::BitAnd<&$roaring> for &RangeSetBlaze<$t> {
+            type Output = RangeSetBlaze<$t>;
+
+            /// Intersects a [`RangeSetBlaze`] with a roaring set, reading the roaring set run by run.
+            fn bitand(self, other: &$roaring) -> RangeSetBlaze<$t> {
+                (self.ranges() & $ranges_iter::new(other)).into_range_set_blaze()
+            }
+        }
+
+        impl ops::Sub<&$roaring> for &RangeSetBlaze<$t> {
+            type Output = RangeSetBlaze<$t>;
+
+            /// Subtracts a roaring set from a [`RangeSetBlaze`], reading the roaring set run by run.
+            fn sub(self, other: &$roaring) -> RangeSetBlaze<$t> {
+                (self.ranges() - $ranges_iter::new(other)).into_range_set_blaze()
+            }
+        }
+
+        impl ops::BitXor<&$roaring> for &RangeSetBlaze<$t> {
+            type Output = RangeSetBlaze<$t>;
+
+            /// Symmetric differences a [`RangeSetBlaze`] with a roaring set, reading the roaring set run by run.
+            fn bitxor(self, other: &$roaring) -> RangeSetBlaze<$t> {
+                (self.ranges() ^ $ranges_iter::new(other)).into_range_set_blaze()
+            }
+        }
+    };
+}
+
+impl_roaring_ops!(RoaringBitmap, RoaringRangesIter, u32);
+impl_roaring_ops!(RoaringTreemap, RoaringTreemapRangesIter, u64);
@@ -0,0 +1,111 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use num_traits::Zero;
+
+use crate::{Element, RangeSetBlaze, SortedDisjoint};
+
+// When one set has at least this many times as many ranges as the other, binary operations seek into the
+// larger set's map once per range of the smaller set, rather than merge-walking both sets.
+const SKEW_RATIO: usize = 16;
+
+// Returns `true` if `small` has so few ranges, relative to `large`, that seeking beats merge-walking.
+pub(crate) fn is_skewed<T: Element>(small: &RangeSetBlaze<T>, large: &RangeSetBlaze<T>) -> bool {
+    small.ranges_len().saturating_mul(SKEW_RATIO) <= large.ranges_len()
+}
+
+impl<T: Element> RangeSetBlaze<T> {
+    // Returns `true` if any range of the set overlaps `start..=end`.
+    fn overlaps(&self, start: T, end: T) -> bool {
+        self.btree_map
+            .range(..=end)
+            .next_back()
+            .map_or(false, |(_, &end_before)| start <= end_before)
+    }
+
+    // Calls `f` with each range of the set that overlaps `start..=end`, clipped to `start..=end`, in order.
+    fn for_each_overlap(&self, start: T, end: T, mut f: impl FnMut(T, T)) {
+        if let Some((_, &end_before)) = self.btree_map.range(..start).next_back() {
+            if start <= end_before {
+                f(start, end.min(end_before));
+            }
+        }
+        for (&start_in, &end_in) in self.btree_map.range(start..=end) {
+            f(start_in, end.min(end_in));
+        }
+    }
+
+    // Builds a set from ranges that are sorted, disjoint, and not touching.
+    fn from_separated_ranges(ranges: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut len = <T as Element>::SafeLen::zero();
+        let btree_map: BTreeMap<T, T> = ranges
+            .into_iter()
+            .inspect(|&(start, end)| len += T::safe_len(&(start..=end)))
+            .collect();
+        Self { len, btree_map }
+    }
+
+    // The intersection of `small` and `large`, found with O(`small.ranges_len()` log `large.ranges_len()`) seeks.
+    pub(crate) fn intersection_by_seek(small: &Self, large: &Self) -> Self {
+        let mut pieces = Vec::new();
+        for (&start, &end) in &small.btree_map {
+            large.for_each_overlap(start, end, |start, end| pieces.push((start, end)));
+        }
+        Self::from_separated_ranges(pieces)
+    }
+
+    // `small - large`, found with one seek into `large` per range of `small`.
+    pub(crate) fn difference_by_seek(small: &Self, large: &Self) -> Self {
+        let mut pieces = Vec::new();
+        for (&start, &end) in &small.btree_map {
+            let mut next = Some(start);
+            large.for_each_overlap(start, end, |start_in, end_in| {
+                if let Some(next_start) = next {
+                    if next_start < start_in {
+                        pieces.push((next_start, start_in.sub_one()));
+                    }
+                }
+                next = if end_in < end {
+                    Some(end_in.add_one())
+                } else {
+                    None
+                };
+            });
+            if let Some(next_start) = next {
+                pieces.push((next_start, end));
+            }
+        }
+        Self::from_separated_ranges(pieces)
+    }
+
+    // `self.is_subset(other)` when one of the two sets has far fewer ranges than the other.
+    pub(crate) fn is_subset_by_seek(&self, other: &Self) -> bool {
+        if self.ranges_len() <= other.ranges_len() {
+            // Every range of `self` must lie inside a single range of `other`.
+            self.btree_map.iter().all(|(&start, &end)| {
+                other
+                    .btree_map
+                    .range(..=start)
+                    .next_back()
+                    .map_or(false, |(_, &end_other)| end <= end_other)
+            })
+        } else {
+            // No range of `self` may reach into a gap of `other`.
+            other
+                .ranges()
+                .complement()
+                .all(|gap| !self.overlaps(*gap.start(), *gap.end()))
+        }
+    }
+
+    // `self.is_disjoint(other)` when one of the two sets has far fewer ranges than the other.
+    pub(crate) fn is_disjoint_by_seek(&self, other: &Self) -> bool {
+        let (small, large) = if self.ranges_len() <= other.ranges_len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        small
+            .btree_map
+            .iter()
+            .all(|(&start, &end)| !large.overlaps(start, end))
+    }
+}
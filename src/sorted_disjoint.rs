@@ -1,5 +1,6 @@
 use alloc::format;
 use alloc::string::String;
+use alloc::vec::{self, Vec};
 use core::{
     iter::FusedIterator,
     ops::{self, RangeInclusive},
@@ -8,13 +9,13 @@ use core::{
 use itertools::Itertools;
 
 use crate::{
-    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Integer, Merge, NotIter, RangeSetBlaze,
-    UnionIter,
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Element, Merge, NotIter, RangeSetBlaze,
+    RangeSetError, UnionIter,
 };
 
 /// A trait used to mark iterators that provide ranges sorted by start, but not necessarily by end,
 /// and may overlap.
-pub trait SortedStarts<T: Integer>: Iterator<Item = RangeInclusive<T>> {}
+pub trait SortedStarts<T: Element>: Iterator<Item = RangeInclusive<T>> {}
 
 /// The trait used to mark iterators that provide ranges that are sorted by start and disjoint. Set operations on
 /// iterators that implement this trait can be performed in linear time.
@@ -203,7 +204,11 @@ pub trait SortedStarts<T: Integer>: Iterator<Item = RangeInclusive<T>> {}
 ///     "244..=244, 247..=251, 254..=258, 261..=265, 268..=272"
 /// );
 /// ```
-pub trait SortedDisjoint<T: Integer>: SortedStarts<T> {
+///
+/// For periodic ranges such as these, the built-in [`Periodic`] iterator saves writing a new type.
+///
+/// [`Periodic`]: crate::Periodic
+pub trait SortedDisjoint<T: Element>: SortedStarts<T> {
     // I think this is 'Sized' because will sometimes want to create a struct (e.g. BitOrIter) that contains a field of this type
 
     /// Given two [`SortedDisjoint`] iterators, efficiently returns a [`SortedDisjoint`] iterator of their union.
@@ -525,7 +530,8 @@ pub trait SortedDisjoint<T: Integer>: SortedStarts<T> {
 }
 
 /// Gives the [`SortedDisjoint`] trait to any iterator of ranges. The iterator will panic
-/// if/when it finds that the ranges are not actually sorted and disjoint.
+/// if/when it finds that the ranges are not actually sorted and disjoint. To get an error instead,
+/// use [`CheckSortedDisjoint::try_new`].
 ///
 /// # Performance
 ///
@@ -555,26 +561,28 @@ pub trait SortedDisjoint<T: Integer>: SortedStarts<T> {
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct CheckSortedDisjoint<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     pub(crate) iter: I,
     prev_end: Option<T>,
     seen_none: bool,
+    // Set by `try_new`, which has already checked every range.
+    checked: bool,
 }
 
-impl<T: Integer, I> SortedDisjoint<T> for CheckSortedDisjoint<T, I> where
+impl<T: Element, I> SortedDisjoint<T> for CheckSortedDisjoint<T, I> where
     I: Iterator<Item = RangeInclusive<T>>
 {
 }
-impl<T: Integer, I> SortedStarts<T> for CheckSortedDisjoint<T, I> where
+impl<T: Element, I> SortedStarts<T> for CheckSortedDisjoint<T, I> where
     I: Iterator<Item = RangeInclusive<T>>
 {
 }
 
 impl<T, I> CheckSortedDisjoint<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     /// Creates a new [`CheckSortedDisjoint`] from an iterator of ranges. See [`CheckSortedDisjoint`] for details and examples.
@@ -583,13 +591,104 @@ where
             iter: iter.into_iter(),
             prev_end: None,
             seen_none: false,
+            checked: false,
         }
     }
 }
 
+impl<T> CheckSortedDisjoint<T, vec::IntoIter<RangeInclusive<T>>>
+where
+    T: Element,
+{
+    /// Creates a new [`CheckSortedDisjoint`] from an iterator of ranges, checking all the ranges up front, so
+    /// that bad input returns an error instead of panicking later.
+    ///
+    /// It collects the ranges into a `Vec`, so it uses memory in proportion to the number of ranges, and
+    /// the returned iterator yields them without checking them again. To check ranges as they stream instead,
+    /// use [`ValidateSortedDisjoint`](crate::ValidateSortedDisjoint).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RangeSetError`] for the first range that is backwards, greater than
+    /// [`Element::safe_max_value`], or not after and apart from the range before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    /// use range_set_blaze::RangeSetError;
+    ///
+    /// let a = CheckSortedDisjoint::try_new([1..=2, 5..=100])?;
+    /// assert_eq!(a.to_string(), "1..=2, 5..=100");
+    ///
+    /// #[allow(clippy::reversed_empty_ranges)]
+    /// let err = CheckSortedDisjoint::try_new([1..=2, 7..=6]).unwrap_err();
+    /// assert_eq!(err, RangeSetError::Backwards(7..=6));
+    /// # Ok::<(), RangeSetError<i32>>(())
+    /// ```
+    pub fn try_new<J>(iter: J) -> Result<Self, RangeSetError<T>>
+    where
+        J: IntoIterator<Item = RangeInclusive<T>>,
+    {
+        let mut prev_end = None;
+        let ranges = iter
+            .into_iter()
+            .map(|range| {
+                check_next(prev_end, &range)?;
+                prev_end = Some(*range.end());
+                Ok(range)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            checked: true,
+            ..Self::new(ranges)
+        })
+    }
+}
+
+// Checks that a range is non-empty, at or below `T::safe_max_value()`, and after and apart from the
+// previous range, if any.
+pub(crate) fn check_next<T: Element>(
+    prev_end: Option<T>,
+    range: &RangeInclusive<T>,
+) -> Result<(), RangeSetError<T>> {
+    let (start, end) = range.clone().into_inner();
+    if start > end {
+        return Err(RangeSetError::Backwards(range.clone()));
+    }
+    if end > T::safe_max_value() {
+        return Err(RangeSetError::TooLarge(end));
+    }
+    if let Some(prev_end) = prev_end {
+        if prev_end >= T::safe_max_value() || prev_end.add_one() >= start {
+            return Err(RangeSetError::NotSortedDisjoint {
+                prev_end,
+                range: range.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// Checks that a range is non-empty, at or below `T::safe_max_value()`, and starts no earlier than the
+// previous range, if any.
+pub(crate) fn check_next_start<T: Element>(
+    prev_start: Option<T>,
+    range: &RangeInclusive<T>,
+) -> Result<(), RangeSetError<T>> {
+    check_next(None, range)?;
+    match prev_start {
+        Some(prev_start) if *range.start() < prev_start => Err(RangeSetError::NotSortedStarts {
+            prev_start,
+            range: range.clone(),
+        }),
+        _ => Ok(()),
+    }
+}
+
 impl<T> Default for CheckSortedDisjoint<T, core::array::IntoIter<RangeInclusive<T>, 0>>
 where
-    T: Integer,
+    T: Element,
 {
     // Default is an empty iterator.
     fn default() -> Self {
@@ -599,43 +698,36 @@ where
 
 impl<T, I> FusedIterator for CheckSortedDisjoint<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>> + FusedIterator,
 {
 }
 
 impl<T, I> Iterator for CheckSortedDisjoint<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     type Item = RangeInclusive<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
         let next = self.iter.next();
+        if self.checked {
+            return next;
+        }
 
         let Some(range) = next.as_ref() else {
             self.seen_none = true;
             return next;
         };
 
-        assert!(
-            !self.seen_none,
-            "iterator cannot return Some after returning None"
-        );
-        let (start, end) = range.clone().into_inner();
-        assert!(start <= end, "start must be less or equal to end");
-        assert!(
-            end <= T::safe_max_value(),
-            "end must be less than or equal to safe_max_value"
-        );
-        if let Some(prev_end) = self.prev_end {
-            assert!(
-                prev_end < T::safe_max_value() && prev_end + T::one() < start,
-                "ranges must be disjoint"
-            );
+        if self.seen_none {
+            panic!("{}", RangeSetError::<T>::SomeAfterNone);
+        }
+        if let Err(err) = check_next(self.prev_end, range) {
+            panic!("{err}");
         }
-        self.prev_end = Some(end);
+        self.prev_end = Some(*range.end());
 
         next
     }
@@ -645,7 +737,7 @@ where
     }
 }
 
-impl<T: Integer, const N: usize> From<[RangeInclusive<T>; N]>
+impl<T: Element, const N: usize> From<[RangeInclusive<T>; N]>
     for CheckSortedDisjoint<T, core::array::IntoIter<RangeInclusive<T>, N>>
 {
     /// You may create a [`CheckSortedDisjoint`] from an array of integers.
@@ -666,7 +758,7 @@ impl<T: Integer, const N: usize> From<[RangeInclusive<T>; N]>
     }
 }
 
-impl<T: Integer, I> ops::Not for CheckSortedDisjoint<T, I>
+impl<T: Element, I> ops::Not for CheckSortedDisjoint<T, I>
 where
     I: Iterator<Item = RangeInclusive<T>>,
 {
@@ -677,7 +769,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitOr<R> for CheckSortedDisjoint<T, L>
+impl<T: Element, R, L> ops::BitOr<R> for CheckSortedDisjoint<T, L>
 where
     L: Iterator<Item = RangeInclusive<T>>,
     R: SortedDisjoint<T>,
@@ -689,7 +781,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitAnd<R> for CheckSortedDisjoint<T, L>
+impl<T: Element, R, L> ops::BitAnd<R> for CheckSortedDisjoint<T, L>
 where
     L: Iterator<Item = RangeInclusive<T>>,
     R: SortedDisjoint<T>,
@@ -701,7 +793,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::Sub<R> for CheckSortedDisjoint<T, L>
+impl<T: Element, R, L> ops::Sub<R> for CheckSortedDisjoint<T, L>
 where
     L: Iterator<Item = RangeInclusive<T>>,
     R: SortedDisjoint<T>,
@@ -713,7 +805,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitXor<R> for CheckSortedDisjoint<T, L>
+impl<T: Element, R, L> ops::BitXor<R> for CheckSortedDisjoint<T, L>
 where
     L: Iterator<Item = RangeInclusive<T>>,
     R: SortedDisjoint<T>,
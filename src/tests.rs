@@ -2,6 +2,7 @@
 #![cfg(not(target_arch = "wasm32"))]
 
 use super::*;
+use crate::Element as _;
 use itertools::Itertools;
 use quickcheck_macros::quickcheck;
 use rand::{rngs::StdRng, SeedableRng};
@@ -22,7 +23,7 @@ use tests_common::{How, MemorylessIter, MemorylessRange};
 // use thousands::Separable;
 use core::ops::BitAndAssign;
 
-type I32SafeLen = <i32 as crate::Integer>::SafeLen;
+type I32SafeLen = <i32 as crate::Element>::SafeLen;
 
 #[test]
 fn insert_255u8() {
@@ -31,10 +32,11 @@ fn insert_255u8() {
 }
 
 #[test]
-#[should_panic]
 fn insert_max_u128() {
     let a = RangeSetBlaze::<u128>::from_iter([u128::MAX]);
     println!("a: {a}");
+    assert_eq!(a.len(), UIntPlusOne::UInt(1));
+    assert_eq!(a.first(), Some(u128::MAX));
 }
 
 #[test]
@@ -834,14 +836,14 @@ fn check_traits() {
 fn integer_coverage() {
     syntactic_for! { ty in [i8, u8, isize, usize,  i16, u16, i32, u32, i64, u64, isize, usize, i128, u128] {
         $(
-            let len = <$ty as Integer>::SafeLen::one();
+            let len = <$ty as crate::Element>::SafeLen::one();
             let a = $ty::zero();
             assert_eq!($ty::safe_len_to_f64(len), 1.0);
             assert_eq!($ty::add_len_less_one(a,len), a);
             assert_eq!($ty::sub_len_less_one(a,len), a);
             assert_eq!($ty::f64_to_safe_len(1.0), len);
             assert!($ty::safe_max_value()<=$ty::max_value());
-            assert!(<$ty as Integer>::safe_max_value()<=$ty::max_value());
+            assert!(<$ty as crate::Element>::safe_max_value()<=$ty::max_value());
 
         )*
     }};
@@ -1023,7 +1025,7 @@ fn lib_coverage_0() {
             a.append(&mut b);
 
             // assert_eq!(a.len(), 5usize);
-            assert_eq!(b.len(), <$ty as Integer>::SafeLen::zero());
+            assert_eq!(b.len(), <$ty as crate::Element>::SafeLen::zero());
 
             assert!(a.contains(1));
             assert!(a.contains(2));
@@ -1047,31 +1049,31 @@ fn lib_coverage_0() {
 }
 
 #[test]
-#[should_panic]
 fn lib_coverage_2() {
     let v = RangeSetBlaze::<u128>::new();
-    v.contains(u128::MAX);
+    assert!(!v.contains(u128::MAX));
 }
 
 #[test]
-#[should_panic]
 fn lib_coverage_3() {
-    let mut v = RangeSetBlaze::<u128>::new();
-    v.remove(u128::MAX);
+    let mut v = RangeSetBlaze::<u128>::from_iter([u128::MAX - 1..=u128::MAX]);
+    assert!(v.remove(u128::MAX));
+    assert_eq!(v, RangeSetBlaze::from_iter([u128::MAX - 1]));
 }
 
 #[test]
-#[should_panic]
 fn lib_coverage_4() {
-    let mut v = RangeSetBlaze::<u128>::new();
-    v.split_off(u128::MAX);
+    let mut v = RangeSetBlaze::<u128>::from_iter([0..=u128::MAX]);
+    let w = v.split_off(u128::MAX);
+    assert_eq!(v.len(), UIntPlusOne::UInt(u128::MAX));
+    assert_eq!(w, RangeSetBlaze::from_iter([u128::MAX]));
 }
 
 #[test]
-#[should_panic]
 fn lib_coverage_5() {
     let mut v = RangeSetBlaze::<u128>::new();
     v.internal_add(0..=u128::MAX);
+    assert_eq!(v.len(), UIntPlusOne::MaxPlusOne);
 }
 
 #[test]
@@ -1225,11 +1227,9 @@ fn sorted_disjoint_coverage_3() {
 }
 
 #[test]
-#[should_panic]
 fn sorted_disjoint_coverage_4() {
-    #[allow(clippy::reversed_empty_ranges)]
     let mut a = CheckSortedDisjoint::new([0..=i128::MAX]);
-    a.next();
+    assert_eq!(a.next(), Some(0..=i128::MAX));
 }
 
 #[test]
@@ -1489,3 +1489,58 @@ fn double_end_range() {
     assert_eq!(range.next(), Some(12));
     assert_eq!(range.next(), Some(20));
 }
+
+#[test]
+fn max_len_index_matches_brute_force() {
+    use crate::max_len_index::MaxLenIndex;
+    use rand::Rng;
+    use std::collections::BTreeMap;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut index = MaxLenIndex::new();
+    let mut expected = BTreeMap::new();
+    for _ in 0..20_000 {
+        let key = rng.gen_range(0..5_000u32);
+        if rng.gen_bool(0.6) {
+            if let std::collections::btree_map::Entry::Vacant(entry) = expected.entry(key) {
+                let len = rng.gen_range(1..1_000u32);
+                entry.insert(len);
+                index.insert(key, len);
+            }
+        } else {
+            assert_eq!(index.remove(key), expected.remove(&key));
+        }
+        let min_len = rng.gen_range(1..1_000u32);
+        let found = expected
+            .range(key..)
+            .find(|(_, len)| **len >= min_len)
+            .map(|(key, len)| (*key, *len));
+        assert_eq!(index.first_at_or_after(key, min_len), found);
+    }
+}
+
+#[test]
+fn uint_plus_one_arithmetic() {
+    use num_traits::{Bounded, NumCast, ToPrimitive};
+
+    let max = UIntPlusOne::<u128>::MaxPlusOne;
+    let (quotient, remainder) = num_integer::Integer::div_rem(&max, &UIntPlusOne::UInt(10));
+    assert_eq!(quotient * UIntPlusOne::UInt(10) + remainder, max);
+    assert_eq!(
+        num_integer::Integer::gcd(&max, &UIntPlusOne::UInt(12)),
+        UIntPlusOne::UInt(4)
+    );
+    assert!(num_integer::Integer::is_even(&max));
+    assert_eq!(UIntPlusOne::<u128>::max_value(), max);
+    assert_eq!(max.to_f64(), Some(2f64.powi(128)));
+    assert_eq!(max.to_u128(), None);
+    assert_eq!(
+        <UIntPlusOne<u128> as NumCast>::from(2f64.powi(128)),
+        Some(max)
+    );
+    assert_eq!(
+        <UIntPlusOne<u128> as NumCast>::from(7u8),
+        Some(UIntPlusOne::UInt(7))
+    );
+    assert_eq!(<UIntPlusOne<u128> as NumCast>::from(-1), None);
+}
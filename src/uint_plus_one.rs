@@ -0,0 +1,372 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign},
+};
+
+use num_integer::Integer;
+use num_traits::{Bounded, Num, NumCast, One, PrimInt, ToPrimitive, Unsigned, Zero};
+
+/// An unsigned integer that can also hold one more than its type's maximum value.
+///
+/// It is the [`Element::SafeLen`] of [`Ipv6Addr`], whose full range holds 2<sup>128</sup> addresses,
+/// one more than `u128::MAX`. Arithmetic panics if a result doesn't fit.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{RangeSetBlaze, UIntPlusOne};
+/// use std::net::Ipv6Addr;
+///
+/// let everything = RangeSetBlaze::from_iter([Ipv6Addr::UNSPECIFIED..=Ipv6Addr::from(u128::MAX)]);
+/// assert_eq!(everything.len(), UIntPlusOne::MaxPlusOne);
+/// assert_eq!(everything.len().to_string(), "340282366920938463463374607431768211456");
+///
+/// let one = RangeSetBlaze::from_iter([Ipv6Addr::LOCALHOST]);
+/// assert_eq!(one.len(), UIntPlusOne::UInt(1));
+/// ```
+///
+/// [`Element::SafeLen`]: crate::Element::SafeLen
+/// [`Ipv6Addr`]: core::net::Ipv6Addr
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UIntPlusOne<T> {
+    /// A value that fits in `T`.
+    UInt(T),
+    /// One more than `T`'s maximum value.
+    MaxPlusOne,
+}
+
+impl<T: PrimInt + Unsigned> UIntPlusOne<T> {
+    /// Returns the value as a `T`, or `None` if it is [`UIntPlusOne::MaxPlusOne`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::UIntPlusOne;
+    ///
+    /// assert_eq!(UIntPlusOne::UInt(7u8).to_uint(), Some(7));
+    /// assert_eq!(UIntPlusOne::<u8>::MaxPlusOne.to_uint(), None);
+    /// ```
+    #[must_use]
+    pub fn to_uint(self) -> Option<T> {
+        match self {
+            Self::UInt(value) => Some(value),
+            Self::MaxPlusOne => None,
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> From<T> for UIntPlusOne<T> {
+    fn from(value: T) -> Self {
+        Self::UInt(value)
+    }
+}
+
+impl<T: PrimInt + Unsigned> Default for UIntPlusOne<T> {
+    fn default() -> Self {
+        Self::UInt(T::zero())
+    }
+}
+
+impl<T: PrimInt + Unsigned> Ord for UIntPlusOne<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::UInt(a), Self::UInt(b)) => a.cmp(b),
+            (Self::UInt(_), Self::MaxPlusOne) => Ordering::Less,
+            (Self::MaxPlusOne, Self::UInt(_)) => Ordering::Greater,
+            (Self::MaxPlusOne, Self::MaxPlusOne) => Ordering::Equal,
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> PartialOrd for UIntPlusOne<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PrimInt + Unsigned> Add for UIntPlusOne<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::UInt(a), Self::UInt(b)) => {
+                if b.is_zero() {
+                    return Self::UInt(a);
+                }
+                // Adding `b - 1` first lets the sum reach exactly `T::max_value() + 1`.
+                match a.checked_add(&(b - T::one())) {
+                    Some(sum_less_one) if sum_less_one == T::max_value() => Self::MaxPlusOne,
+                    Some(sum_less_one) => Self::UInt(sum_less_one + T::one()),
+                    None => panic!("attempt to add with overflow"),
+                }
+            }
+            (Self::MaxPlusOne, Self::UInt(b)) | (Self::UInt(b), Self::MaxPlusOne) => {
+                assert!(b.is_zero(), "attempt to add with overflow");
+                Self::MaxPlusOne
+            }
+            (Self::MaxPlusOne, Self::MaxPlusOne) => panic!("attempt to add with overflow"),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> Sub for UIntPlusOne<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::UInt(a), Self::UInt(b)) => Self::UInt(
+                a.checked_sub(&b)
+                    .expect("attempt to subtract with overflow"),
+            ),
+            (Self::MaxPlusOne, Self::UInt(b)) => {
+                if b.is_zero() {
+                    Self::MaxPlusOne
+                } else {
+                    Self::UInt(T::max_value() - (b - T::one()))
+                }
+            }
+            (Self::MaxPlusOne, Self::MaxPlusOne) => Self::UInt(T::zero()),
+            (Self::UInt(_), Self::MaxPlusOne) => panic!("attempt to subtract with overflow"),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> Mul for UIntPlusOne<T> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::UInt(a), Self::UInt(b)) => {
+                if let Some(product) = a.checked_mul(&b) {
+                    return Self::UInt(product);
+                }
+                // Only two powers of two can multiply to exactly `T::max_value() + 1`.
+                let bits = T::zero().count_zeros();
+                assert!(
+                    a.count_ones() == 1
+                        && b.count_ones() == 1
+                        && a.trailing_zeros() + b.trailing_zeros() == bits,
+                    "attempt to multiply with overflow"
+                );
+                Self::MaxPlusOne
+            }
+            (Self::MaxPlusOne, Self::UInt(b)) | (Self::UInt(b), Self::MaxPlusOne) => {
+                if b.is_zero() {
+                    Self::UInt(T::zero())
+                } else {
+                    assert!(b.is_one(), "attempt to multiply with overflow");
+                    Self::MaxPlusOne
+                }
+            }
+            (Self::MaxPlusOne, Self::MaxPlusOne) => panic!("attempt to multiply with overflow"),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> AddAssign for UIntPlusOne<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: PrimInt + Unsigned> SubAssign for UIntPlusOne<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: PrimInt + Unsigned> Zero for UIntPlusOne<T> {
+    fn zero() -> Self {
+        Self::UInt(T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::UInt(T::zero())
+    }
+}
+
+impl<T: PrimInt + Unsigned> One for UIntPlusOne<T> {
+    fn one() -> Self {
+        Self::UInt(T::one())
+    }
+}
+
+impl<T: PrimInt + Unsigned + fmt::Display> fmt::Display for UIntPlusOne<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UInt(value) => write!(f, "{value}"),
+            Self::MaxPlusOne => {
+                // The maximum of each unsigned type ends in 5, so adding one changes only its last digit.
+                let mut digits = [0u8; 40];
+                let mut len = 0;
+                let ten = T::from(10).expect("10 fits in every unsigned type");
+                let mut rest = T::max_value();
+                while !rest.is_zero() {
+                    let digit = (rest % ten).to_u8().expect("a digit fits in a u8");
+                    digits[len] = b'0' + digit;
+                    len += 1;
+                    rest = rest / ten;
+                }
+                digits[0] += 1;
+                for &digit in digits[..len].iter().rev() {
+                    write!(f, "{}", char::from(digit))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> Div for UIntPlusOne<T> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.div_rem(&other).0
+    }
+}
+
+impl<T: PrimInt + Unsigned> Rem for UIntPlusOne<T> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(&other).1
+    }
+}
+
+impl<T: PrimInt + Unsigned> MulAssign for UIntPlusOne<T> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T: PrimInt + Unsigned> DivAssign for UIntPlusOne<T> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T: PrimInt + Unsigned> RemAssign for UIntPlusOne<T> {
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+impl<T: PrimInt + Unsigned> Num for UIntPlusOne<T> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, T::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(Self::UInt)
+    }
+}
+
+impl<T: PrimInt + Unsigned> Integer for UIntPlusOne<T> {
+    fn div_floor(&self, other: &Self) -> Self {
+        *self / *other
+    }
+
+    fn mod_floor(&self, other: &Self) -> Self {
+        *self % *other
+    }
+
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+        while !b.is_zero() {
+            (a, b) = (b, a % b);
+        }
+        a
+    }
+
+    fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        *self / self.gcd(other) * *other
+    }
+
+    fn is_multiple_of(&self, other: &Self) -> bool {
+        if other.is_zero() {
+            return self.is_zero();
+        }
+        (*self % *other).is_zero()
+    }
+
+    fn is_even(&self) -> bool {
+        match self {
+            Self::UInt(value) => (*value & T::one()).is_zero(),
+            Self::MaxPlusOne => true,
+        }
+    }
+
+    fn is_odd(&self) -> bool {
+        !self.is_even()
+    }
+
+    fn div_rem(&self, other: &Self) -> (Self, Self) {
+        match (*self, *other) {
+            (Self::UInt(a), Self::UInt(b)) => (Self::UInt(a / b), Self::UInt(a % b)),
+            (Self::MaxPlusOne, Self::UInt(b)) => {
+                // Divide `T::max_value()` and then add the one back to the remainder.
+                let (quotient, remainder) = (T::max_value() / b, T::max_value() % b);
+                if remainder + T::one() < b {
+                    (Self::UInt(quotient), Self::UInt(remainder + T::one()))
+                } else {
+                    (Self::UInt(quotient) + Self::one(), Self::zero())
+                }
+            }
+            (Self::UInt(a), Self::MaxPlusOne) => (Self::zero(), Self::UInt(a)),
+            (Self::MaxPlusOne, Self::MaxPlusOne) => (Self::one(), Self::zero()),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> Bounded for UIntPlusOne<T> {
+    fn min_value() -> Self {
+        Self::zero()
+    }
+
+    fn max_value() -> Self {
+        Self::MaxPlusOne
+    }
+}
+
+impl<T: PrimInt + Unsigned> ToPrimitive for UIntPlusOne<T> {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_uint()?.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_uint()?.to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_uint()?.to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.to_uint()?.to_u128()
+    }
+
+    fn to_f32(&self) -> Option<f32> {
+        self.to_f64().map(|value| value as f32)
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        match self {
+            Self::UInt(value) => value.to_f64(),
+            Self::MaxPlusOne => Some(T::max_value().to_f64()? + 1.0),
+        }
+    }
+}
+
+impl<T: PrimInt + Unsigned> NumCast for UIntPlusOne<T> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        let max = T::max_value().to_u128()?;
+        match n.to_u128() {
+            Some(value) if value <= max => <T as NumCast>::from(value).map(Self::UInt),
+            Some(value) => (value - 1 == max).then_some(Self::MaxPlusOne),
+            // Only a float can stand for 2^128, one more than `u128::MAX`.
+            None => (n.to_f64()? == max as f64 + 1.0).then_some(Self::MaxPlusOne),
+        }
+    }
+}
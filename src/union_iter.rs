@@ -9,7 +9,7 @@ use itertools::Itertools;
 
 use crate::{
     unsorted_disjoint::{AssumeSortedStarts, UnsortedDisjoint},
-    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Integer, NotIter, SortedDisjoint,
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Element, NotIter, SortedDisjoint,
     SortedStarts,
 };
 
@@ -54,7 +54,7 @@ use crate::{
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct UnionIter<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedStarts<T>,
 {
     pub(crate) iter: I,
@@ -63,7 +63,7 @@ where
 
 impl<T, I> UnionIter<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedStarts<T>,
 {
     /// Creates a new [`UnionIter`] from zero or more [`SortedDisjoint`] iterators. See [`UnionIter`] for more details and examples.
@@ -75,19 +75,19 @@ where
     }
 }
 
-impl<T: Integer, const N: usize> From<[T; N]> for UnionIter<T, SortedRangeInclusiveVec<T>> {
+impl<T: Element, const N: usize> From<[T; N]> for UnionIter<T, SortedRangeInclusiveVec<T>> {
     fn from(arr: [T; N]) -> Self {
         arr.as_slice().into()
     }
 }
 
-impl<T: Integer> From<&[T]> for UnionIter<T, SortedRangeInclusiveVec<T>> {
+impl<T: Element> From<&[T]> for UnionIter<T, SortedRangeInclusiveVec<T>> {
     fn from(slice: &[T]) -> Self {
         slice.iter().cloned().collect()
     }
 }
 
-impl<T: Integer, const N: usize> From<[RangeInclusive<T>; N]>
+impl<T: Element, const N: usize> From<[RangeInclusive<T>; N]>
     for UnionIter<T, SortedRangeInclusiveVec<T>>
 {
     fn from(arr: [RangeInclusive<T>; N]) -> Self {
@@ -95,7 +95,7 @@ impl<T: Integer, const N: usize> From<[RangeInclusive<T>; N]>
     }
 }
 
-impl<T: Integer> From<&[RangeInclusive<T>]> for UnionIter<T, SortedRangeInclusiveVec<T>> {
+impl<T: Element> From<&[RangeInclusive<T>]> for UnionIter<T, SortedRangeInclusiveVec<T>> {
     fn from(slice: &[RangeInclusive<T>]) -> Self {
         slice.iter().cloned().collect()
     }
@@ -103,7 +103,7 @@ impl<T: Integer> From<&[RangeInclusive<T>]> for UnionIter<T, SortedRangeInclusiv
 
 type SortedRangeInclusiveVec<T> = AssumeSortedStarts<T, vec::IntoIter<RangeInclusive<T>>>;
 
-impl<T: Integer> FromIterator<T> for UnionIter<T, SortedRangeInclusiveVec<T>> {
+impl<T: Element> FromIterator<T> for UnionIter<T, SortedRangeInclusiveVec<T>> {
     fn from_iter<I>(iter: I) -> Self
     where
         I: IntoIterator<Item = T>,
@@ -112,7 +112,7 @@ impl<T: Integer> FromIterator<T> for UnionIter<T, SortedRangeInclusiveVec<T>> {
     }
 }
 
-impl<T: Integer> FromIterator<RangeInclusive<T>> for UnionIter<T, SortedRangeInclusiveVec<T>> {
+impl<T: Element> FromIterator<RangeInclusive<T>> for UnionIter<T, SortedRangeInclusiveVec<T>> {
     fn from_iter<I>(iter: I) -> Self
     where
         I: IntoIterator<Item = RangeInclusive<T>>,
@@ -123,7 +123,7 @@ impl<T: Integer> FromIterator<RangeInclusive<T>> for UnionIter<T, SortedRangeInc
 
 impl<T, I> From<UnsortedDisjoint<T, I>> for UnionIter<T, SortedRangeInclusiveVec<T>>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>, // Any iterator is OK, because we will sort
 {
     fn from(unsorted_disjoint: UnsortedDisjoint<T, I>) -> Self {
@@ -137,9 +137,9 @@ where
     }
 }
 
-impl<T: Integer, I> FusedIterator for UnionIter<T, I> where I: SortedStarts<T> + FusedIterator {}
+impl<T: Element, I> FusedIterator for UnionIter<T, I> where I: SortedStarts<T> + FusedIterator {}
 
-impl<T: Integer, I> Iterator for UnionIter<T, I>
+impl<T: Element, I> Iterator for UnionIter<T, I>
 where
     I: SortedStarts<T>,
 {
@@ -168,7 +168,7 @@ where
             let (current_start, current_end) = current_range.into_inner();
             debug_assert!(current_start <= start); // real assert
             if start <= current_end
-                || (current_end < T::safe_max_value() && start <= current_end + T::one())
+                || (current_end < T::safe_max_value() && start <= current_end.add_one())
             {
                 self.option_range = Some(current_start..=max(current_end, end));
                 continue;
@@ -192,7 +192,7 @@ where
     }
 }
 
-impl<T: Integer, I> ops::Not for UnionIter<T, I>
+impl<T: Element, I> ops::Not for UnionIter<T, I>
 where
     I: SortedStarts<T>,
 {
@@ -203,7 +203,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitOr<R> for UnionIter<T, L>
+impl<T: Element, R, L> ops::BitOr<R> for UnionIter<T, L>
 where
     L: SortedStarts<T>,
     R: SortedDisjoint<T>,
@@ -217,7 +217,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::Sub<R> for UnionIter<T, L>
+impl<T: Element, R, L> ops::Sub<R> for UnionIter<T, L>
 where
     L: SortedStarts<T>,
     R: SortedDisjoint<T>,
@@ -229,7 +229,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitXor<R> for UnionIter<T, L>
+impl<T: Element, R, L> ops::BitXor<R> for UnionIter<T, L>
 where
     L: SortedStarts<T>,
     R: SortedDisjoint<T>,
@@ -242,7 +242,7 @@ where
     }
 }
 
-impl<T: Integer, R, L> ops::BitAnd<R> for UnionIter<T, L>
+impl<T: Element, R, L> ops::BitAnd<R> for UnionIter<T, L>
 where
     L: SortedStarts<T>,
     R: SortedDisjoint<T>,
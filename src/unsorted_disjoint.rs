@@ -1,4 +1,8 @@
-use crate::{Integer, RangeSetBlaze, SortedDisjoint, SortedStarts};
+use crate::{
+    sorted_disjoint::check_next_start, Element, RangeSetBlaze, RangeSetError, SortedDisjoint,
+    SortedStarts,
+};
+use alloc::vec::{self, Vec};
 use core::{
     cmp::{max, min},
     iter::FusedIterator,
@@ -9,40 +13,38 @@ use num_traits::Zero;
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub(crate) struct UnsortedDisjoint<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     iter: I,
     option_range: Option<RangeInclusive<T>>,
     min_value_plus_2: T,
-    two: T,
 }
 
 impl<T, I> From<I> for UnsortedDisjoint<T, I::IntoIter>
 where
-    T: Integer,
+    T: Element,
     I: IntoIterator<Item = RangeInclusive<T>>, // Any iterator is fine
 {
     fn from(into_iter: I) -> Self {
         UnsortedDisjoint {
             iter: into_iter.into_iter(),
             option_range: None,
-            min_value_plus_2: T::min_value() + T::one() + T::one(),
-            two: T::one() + T::one(),
+            min_value_plus_2: T::min_value().add_one().add_one(),
         }
     }
 }
 
 impl<T, I> FusedIterator for UnsortedDisjoint<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>> + FusedIterator,
 {
 }
 
 impl<T, I> Iterator for UnsortedDisjoint<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     type Item = RangeInclusive<T>;
@@ -69,8 +71,9 @@ where
             };
 
             let (self_start, self_end) = self_range.into_inner();
-            if (next_start >= self.min_value_plus_2 && self_end <= next_start - self.two)
-                || (self_start >= self.min_value_plus_2 && next_end <= self_start - self.two)
+            if (next_start >= self.min_value_plus_2 && self_end <= next_start.sub_one().sub_one())
+                || (self_start >= self.min_value_plus_2
+                    && next_end <= self_start.sub_one().sub_one())
             {
                 let result = Some(self_start..=self_end);
                 self.option_range = Some(next_start..=next_end);
@@ -98,14 +101,14 @@ where
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub(crate) struct SortedDisjointWithLenSoFar<T, I>
 where
-    T: Integer,
+    T: Element,
     I: SortedDisjoint<T>,
 {
     iter: I,
-    len: <T as Integer>::SafeLen,
+    len: <T as Element>::SafeLen,
 }
 
-impl<T: Integer, I> From<I> for SortedDisjointWithLenSoFar<T, I::IntoIter>
+impl<T: Element, I> From<I> for SortedDisjointWithLenSoFar<T, I::IntoIter>
 where
     I: IntoIterator<Item = RangeInclusive<T>>,
     I::IntoIter: SortedDisjoint<T>,
@@ -113,26 +116,26 @@ where
     fn from(into_iter: I) -> Self {
         SortedDisjointWithLenSoFar {
             iter: into_iter.into_iter(),
-            len: <T as Integer>::SafeLen::zero(),
+            len: <T as Element>::SafeLen::zero(),
         }
     }
 }
 
-impl<T: Integer, I> SortedDisjointWithLenSoFar<T, I>
+impl<T: Element, I> SortedDisjointWithLenSoFar<T, I>
 where
     I: SortedDisjoint<T>,
 {
-    pub fn len_so_far(&self) -> <T as Integer>::SafeLen {
+    pub fn len_so_far(&self) -> <T as Element>::SafeLen {
         self.len
     }
 }
 
-impl<T: Integer, I> FusedIterator for SortedDisjointWithLenSoFar<T, I> where
+impl<T: Element, I> FusedIterator for SortedDisjointWithLenSoFar<T, I> where
     I: SortedDisjoint<T> + FusedIterator
 {
 }
 
-impl<T: Integer, I> Iterator for SortedDisjointWithLenSoFar<T, I>
+impl<T: Element, I> Iterator for SortedDisjointWithLenSoFar<T, I>
 where
     I: SortedDisjoint<T>,
 {
@@ -159,20 +162,20 @@ where
 /// Gives any iterator of ranges the [`SortedStarts`] trait without any checking.
 pub struct AssumeSortedStarts<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     pub(crate) iter: I,
 }
 
-impl<T: Integer, I> SortedStarts<T> for AssumeSortedStarts<T, I> where
+impl<T: Element, I> SortedStarts<T> for AssumeSortedStarts<T, I> where
     I: Iterator<Item = RangeInclusive<T>>
 {
 }
 
 impl<T, I> AssumeSortedStarts<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     /// Construct [`AssumeSortedStarts`] from a range iterator.
@@ -205,14 +208,14 @@ where
 
 impl<T, I> FusedIterator for AssumeSortedStarts<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>> + FusedIterator,
 {
 }
 
 impl<T, I> Iterator for AssumeSortedStarts<T, I>
 where
-    T: Integer,
+    T: Element,
     I: Iterator<Item = RangeInclusive<T>>,
 {
     type Item = RangeInclusive<T>;
@@ -225,3 +228,151 @@ where
         self.iter.size_hint()
     }
 }
+
+/// Gives the [`SortedStarts`] trait to any iterator of ranges. The iterator will panic
+/// if/when it finds that the ranges are not actually sorted by start. To get an error instead,
+/// use [`CheckSortedStarts::try_new`].
+///
+/// Unlike [`CheckSortedDisjoint`], the ranges may overlap or touch.
+///
+/// [`CheckSortedDisjoint`]: crate::CheckSortedDisjoint
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::prelude::*;
+///
+/// let a = CheckSortedStarts::new([-10..=-5, -7..=2, 4..=4]).into_range_set_blaze();
+/// assert_eq!(a.to_string(), "-10..=2, 4..=4");
+/// ```
+///
+/// Here the ranges are not sorted by start, so the iterator will panic.
+/// ```should_panic
+/// use range_set_blaze::prelude::*;
+///
+/// let a = CheckSortedStarts::new([-7..=2, -10..=-5]).into_range_set_blaze();
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CheckSortedStarts<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    iter: I,
+    prev_start: Option<T>,
+    // Set by `try_new`, which has already checked every range.
+    checked: bool,
+}
+
+impl<T: Element, I> SortedStarts<T> for CheckSortedStarts<T, I> where
+    I: Iterator<Item = RangeInclusive<T>>
+{
+}
+
+impl<T, I> CheckSortedStarts<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    /// Creates a new [`CheckSortedStarts`] from an iterator of ranges. See [`CheckSortedStarts`] for details and examples.
+    pub fn new<J: IntoIterator<IntoIter = I>>(iter: J) -> Self {
+        CheckSortedStarts {
+            iter: iter.into_iter(),
+            prev_start: None,
+            checked: false,
+        }
+    }
+
+    /// Create a [`RangeSetBlaze`] from a [`CheckSortedStarts`] iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ranges are not sorted by start, or if a range is backwards or greater than
+    /// [`Element::safe_max_value`].
+    pub fn into_range_set_blaze(self) -> RangeSetBlaze<T>
+    where
+        Self: Sized,
+    {
+        RangeSetBlaze::from_sorted_starts(self)
+    }
+}
+
+impl<T> CheckSortedStarts<T, vec::IntoIter<RangeInclusive<T>>>
+where
+    T: Element,
+{
+    /// Creates a new [`CheckSortedStarts`] from an iterator of ranges, checking all the ranges up front, so
+    /// that bad input returns an error instead of panicking later.
+    ///
+    /// As with [`CheckSortedDisjoint::try_new`](crate::CheckSortedDisjoint::try_new), the checked ranges are
+    /// held in a `Vec` and aren't checked again.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RangeSetError`] for the first range that is backwards, greater than
+    /// [`Element::safe_max_value`], or starts before the range before it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::prelude::*;
+    /// use range_set_blaze::RangeSetError;
+    ///
+    /// let a = CheckSortedStarts::try_new([1..=5, 2..=3])?.into_range_set_blaze();
+    /// assert_eq!(a.to_string(), "1..=5");
+    ///
+    /// let err = CheckSortedStarts::try_new([2..=3, 1..=5]).unwrap_err();
+    /// assert_eq!(err, RangeSetError::NotSortedStarts { prev_start: 2, range: 1..=5 });
+    /// # Ok::<(), RangeSetError<i32>>(())
+    /// ```
+    pub fn try_new<J>(iter: J) -> Result<Self, RangeSetError<T>>
+    where
+        J: IntoIterator<Item = RangeInclusive<T>>,
+    {
+        let mut prev_start = None;
+        let ranges = iter
+            .into_iter()
+            .map(|range| {
+                check_next_start(prev_start, &range)?;
+                prev_start = Some(*range.start());
+                Ok(range)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            checked: true,
+            ..Self::new(ranges)
+        })
+    }
+}
+
+impl<T, I> FusedIterator for CheckSortedStarts<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>> + FusedIterator,
+{
+}
+
+impl<T, I> Iterator for CheckSortedStarts<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.iter.next()?;
+        if self.checked {
+            return Some(range);
+        }
+        if let Err(err) = check_next_start(self.prev_start, &range) {
+            panic!("{err}");
+        }
+        self.prev_start = Some(*range.start());
+        Some(range)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,316 @@
+use core::{cell::RefCell, fmt, iter::FusedIterator, ops::RangeInclusive};
+
+use crate::{
+    sorted_disjoint::check_next, Element, RangeSetBlaze, RangeSetError, SortedDisjoint,
+    SortedStarts,
+};
+
+/// Checks that an iterator of ranges is sorted and disjoint, yielding each range as `Ok` and the first
+/// problem as an `Err`, instead of panicking like [`CheckSortedDisjoint`].
+///
+/// After an error, the iterator returns `None`. Use [`ValidateSortedDisjoint::try_union`] and
+/// [`ValidateSortedDisjoint::try_intersection`] to combine two validated inputs.
+///
+/// [`CheckSortedDisjoint`]: crate::CheckSortedDisjoint
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{RangeSetError, ValidateSortedDisjoint};
+///
+/// let mut a = ValidateSortedDisjoint::new([1..=2, 5..=9, 7..=10]);
+/// assert_eq!(a.next(), Some(Ok(1..=2)));
+/// assert_eq!(a.next(), Some(Ok(5..=9)));
+/// let err = a.next().unwrap().unwrap_err();
+/// assert_eq!(err.index(), 2);
+/// assert_eq!(err.previous(), Some(5..=9));
+/// assert_eq!(err.range(), 7..=10);
+/// assert_eq!(err.reason(), &RangeSetError::NotSortedDisjoint { prev_end: 9, range: 7..=10 });
+/// assert_eq!(a.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ValidateSortedDisjoint<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    iter: I,
+    index: usize,
+    previous: Option<RangeInclusive<T>>,
+    done: bool,
+}
+
+impl<T, I> ValidateSortedDisjoint<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    /// Creates a new [`ValidateSortedDisjoint`] from an iterator of ranges. See [`ValidateSortedDisjoint`] for details and examples.
+    pub fn new<J: IntoIterator<IntoIter = I>>(iter: J) -> Self {
+        Self {
+            iter: iter.into_iter(),
+            index: 0,
+            previous: None,
+            done: false,
+        }
+    }
+
+    /// Unions two validated inputs into a [`RangeSetBlaze`], stopping at the first error in either.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`SortedDisjointError`] found in either input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::ValidateSortedDisjoint;
+    ///
+    /// let a = ValidateSortedDisjoint::new([1..=2, 10..=20]);
+    /// let b = ValidateSortedDisjoint::new([2..=5]);
+    /// assert_eq!(a.try_union(b)?.to_string(), "1..=5, 10..=20");
+    ///
+    /// let a = ValidateSortedDisjoint::new([1..=2, 10..=20]);
+    /// let b = ValidateSortedDisjoint::new([2..=5, 3..=4]);
+    /// assert_eq!(a.try_union(b).unwrap_err().index(), 1);
+    /// # Ok::<(), range_set_blaze::SortedDisjointError<i32>>(())
+    /// ```
+    pub fn try_union<J>(
+        self,
+        other: ValidateSortedDisjoint<T, J>,
+    ) -> Result<RangeSetBlaze<T>, SortedDisjointError<T>>
+    where
+        J: Iterator<Item = RangeInclusive<T>>,
+    {
+        try_combine(self, other, |a, b| {
+            RangeSetBlaze::from_sorted_disjoint(a.union(b))
+        })
+    }
+
+    /// Intersects two validated inputs into a [`RangeSetBlaze`], stopping at the first error in either.
+    ///
+    /// Every range of both inputs is checked, even those after the intersection is known.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`SortedDisjointError`] found in either input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::ValidateSortedDisjoint;
+    ///
+    /// let a = ValidateSortedDisjoint::new([1..=2, 10..=20]);
+    /// let b = ValidateSortedDisjoint::new([2..=15]);
+    /// assert_eq!(a.try_intersection(b)?.to_string(), "2..=2, 10..=15");
+    ///
+    /// #[allow(clippy::reversed_empty_ranges)]
+    /// let a = ValidateSortedDisjoint::new([1..=2, 30..=20]);
+    /// let b = ValidateSortedDisjoint::new([2..=15]);
+    /// assert_eq!(a.try_intersection(b).unwrap_err().range(), 30..=20);
+    /// # Ok::<(), range_set_blaze::SortedDisjointError<i32>>(())
+    /// ```
+    pub fn try_intersection<J>(
+        self,
+        other: ValidateSortedDisjoint<T, J>,
+    ) -> Result<RangeSetBlaze<T>, SortedDisjointError<T>>
+    where
+        J: Iterator<Item = RangeInclusive<T>>,
+    {
+        try_combine(self, other, |a, b| {
+            RangeSetBlaze::from_sorted_disjoint(a.intersection(b))
+        })
+    }
+}
+
+// Runs `combine` on the valid ranges of `a` and `b`, which stop at the first error in either, then checks
+// whatever ranges `combine` didn't read.
+fn try_combine<T, I, J, F>(
+    mut a: ValidateSortedDisjoint<T, I>,
+    mut b: ValidateSortedDisjoint<T, J>,
+    combine: F,
+) -> Result<RangeSetBlaze<T>, SortedDisjointError<T>>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+    J: Iterator<Item = RangeInclusive<T>>,
+    F: FnOnce(UntilError<'_, T, I>, UntilError<'_, T, J>) -> RangeSetBlaze<T>,
+{
+    let error = RefCell::new(None);
+    let set = combine(
+        UntilError::new(&mut a, &error),
+        UntilError::new(&mut b, &error),
+    );
+    if let Some(error) = error.into_inner() {
+        return Err(error);
+    }
+    for result in a.by_ref().chain(b.by_ref()) {
+        result?;
+    }
+    Ok(set)
+}
+
+impl<T, I> FusedIterator for ValidateSortedDisjoint<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+}
+
+impl<T, I> Iterator for ValidateSortedDisjoint<T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    type Item = Result<RangeInclusive<T>, SortedDisjointError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let Some(range) = self.iter.next() else {
+            self.done = true;
+            return None;
+        };
+        let prev_end = self.previous.as_ref().map(|previous| *previous.end());
+        if let Err(reason) = check_next(prev_end, &range) {
+            self.done = true;
+            return Some(Err(SortedDisjointError {
+                index: self.index,
+                previous: self.previous.take(),
+                range,
+                reason,
+            }));
+        }
+        self.index += 1;
+        self.previous = Some(range.clone());
+        Some(Ok(range))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+// The valid ranges of a `ValidateSortedDisjoint`. The first error from any of the iterators sharing `error`
+// is stored there and ends them all.
+struct UntilError<'a, T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    iter: &'a mut ValidateSortedDisjoint<T, I>,
+    error: &'a RefCell<Option<SortedDisjointError<T>>>,
+}
+
+impl<'a, T, I> UntilError<'a, T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    fn new(
+        iter: &'a mut ValidateSortedDisjoint<T, I>,
+        error: &'a RefCell<Option<SortedDisjointError<T>>>,
+    ) -> Self {
+        Self { iter, error }
+    }
+}
+
+impl<T, I> Iterator for UntilError<'_, T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.borrow().is_some() {
+            return None;
+        }
+        match self.iter.next()? {
+            Ok(range) => Some(range),
+            Err(error) => {
+                *self.error.borrow_mut() = Some(error);
+                None
+            }
+        }
+    }
+}
+
+impl<T, I> SortedStarts<T> for UntilError<'_, T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+}
+impl<T, I> SortedDisjoint<T> for UntilError<'_, T, I>
+where
+    T: Element,
+    I: Iterator<Item = RangeInclusive<T>>,
+{
+}
+
+/// The error yielded by [`ValidateSortedDisjoint`] when its input isn't sorted and disjoint.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::ValidateSortedDisjoint;
+///
+/// let err = ValidateSortedDisjoint::new([5..=9, 1..=2]).find_map(Result::err).unwrap();
+/// assert_eq!(
+///     err.to_string(),
+///     "range 1..=2 at index 1 is invalid: range 1..=2 doesn't start after the previous range, which ends at 9"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortedDisjointError<T: Element> {
+    index: usize,
+    previous: Option<RangeInclusive<T>>,
+    range: RangeInclusive<T>,
+    reason: RangeSetError<T>,
+}
+
+impl<T: Element> SortedDisjointError<T> {
+    /// Returns the zero-based position of the offending range in the input.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the range before the offending range, if any.
+    #[must_use]
+    pub fn previous(&self) -> Option<RangeInclusive<T>> {
+        self.previous.clone()
+    }
+
+    /// Returns the offending range.
+    #[must_use]
+    pub fn range(&self) -> RangeInclusive<T> {
+        self.range.clone()
+    }
+
+    /// Returns what is wrong with the offending range.
+    #[must_use]
+    pub const fn reason(&self) -> &RangeSetError<T> {
+        &self.reason
+    }
+}
+
+impl<T: Element> fmt::Display for SortedDisjointError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "range {:?} at index {} is invalid: {}",
+            self.range, self.index, self.reason
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Element> std::error::Error for SortedDisjointError<T> {}
@@ -0,0 +1,483 @@
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    ops::{self, RangeInclusive},
+};
+
+use num_traits::Zero;
+
+use crate::{
+    BitAndMerge, BitOrMerge, BitSubMerge, BitXOrTee, Element, Integer, NotIter, RangeSetBlaze,
+    SortedDisjoint, SortedStarts,
+};
+
+const MAGIC: [u8; 4] = *b"RSBV";
+const VERSION: u8 = 1;
+const HEADER_LEN: usize = 16;
+
+/// A read-only view of a [`RangeSetBlaze`] stored as bytes, for example, in a memory-mapped file.
+///
+/// The view borrows the bytes and never copies or allocates. [`RangeSetView::new`] validates them
+/// once, after which [`RangeSetView::contains`] is O(log n) and [`RangeSetView::ranges`] is a
+/// [`SortedDisjoint`] iterator that can take part in set expressions with other sets.
+///
+/// Create the bytes with [`RangeSetBlaze::to_view_bytes`]. The format is a 16-byte header followed by the ranges:
+///
+/// | Bytes | Contents |
+/// |-------|----------|
+/// | 0..4 | The magic bytes `RSBV` |
+/// | 4 | The format version, currently 1 |
+/// | 5 | The size of the integer type in bytes |
+/// | 6 | 1 if the integer type is signed, otherwise 0 |
+/// | 7 | Reserved, 0 |
+/// | 8..16 | The number of ranges, as a little-endian `u64` |
+/// | 16.. | Each range's start and (inclusive) end, little-endian, sorted and disjoint |
+///
+/// All integers are read with `from_le_bytes`, so the bytes need no particular alignment.
+///
+/// # Examples
+///
+/// ```
+/// use range_set_blaze::{prelude::*, RangeSetView};
+///
+/// let bytes = RangeSetBlaze::from_iter([100u32..=199, 500..=599]).to_view_bytes();
+/// let view = RangeSetView::<u32>::new(&bytes)?;
+/// assert!(view.contains(150));
+/// assert!(!view.contains(200));
+/// assert_eq!(view.len(), 200);
+///
+/// let allowed = RangeSetBlaze::from_iter([0u32..=120]);
+/// assert_eq!((view.ranges() & allowed.ranges()).to_string(), "100..=120");
+/// # Ok::<(), range_set_blaze::ViewError>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct RangeSetView<'a, T: LeBytes> {
+    // The bytes after the header.
+    pairs: &'a [u8],
+    ranges_len: usize,
+    len: <T as Element>::SafeLen,
+}
+
+impl<'a, T: LeBytes> RangeSetView<'a, T> {
+    /// Validates `bytes` and wraps them in a view.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ViewError`] if the header doesn't match `T`, if the length doesn't match the header,
+    /// or if the ranges aren't sorted, disjoint, and within [`Element::safe_max_value`](crate::Element::safe_max_value).
+    ///
+    /// # Performance
+    ///
+    /// Validation reads every range once, so it takes O(n) time, where n is the number of ranges.
+    /// It doesn't allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{RangeSetBlaze, RangeSetView, ViewError};
+    ///
+    /// let bytes = RangeSetBlaze::from_iter([1i16..=3]).to_view_bytes();
+    /// assert!(RangeSetView::<i16>::new(&bytes).is_ok());
+    /// assert_eq!(
+    ///     RangeSetView::<u16>::new(&bytes).unwrap_err(),
+    ///     ViewError::TypeMismatch { size: 2, signed: true }
+    /// );
+    /// assert_eq!(
+    ///     RangeSetView::<i16>::new(&bytes[..19]).unwrap_err(),
+    ///     ViewError::LengthMismatch { ranges: 1, bytes: 3 }
+    /// );
+    /// ```
+    pub fn new(bytes: &'a [u8]) -> Result<Self, ViewError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(ViewError::TooShort { len: bytes.len() });
+        }
+        let (header, pairs) = bytes.split_at(HEADER_LEN);
+        if header[0..4] != MAGIC {
+            return Err(ViewError::BadMagic);
+        }
+        if header[4] != VERSION {
+            return Err(ViewError::UnsupportedVersion(header[4]));
+        }
+        let (size, signed) = (header[5], header[6] != 0);
+        if usize::from(size) != T::SIZE || signed != T::SIGNED {
+            return Err(ViewError::TypeMismatch { size, signed });
+        }
+        let mut count = [0; 8];
+        count.copy_from_slice(&header[8..16]);
+        let ranges = u64::from_le_bytes(count);
+        let ranges_len = usize::try_from(ranges)
+            .ok()
+            .filter(|&ranges_len| Some(pairs.len()) == ranges_len.checked_mul(2 * T::SIZE))
+            .ok_or(ViewError::LengthMismatch {
+                ranges,
+                bytes: pairs.len(),
+            })?;
+
+        let mut view = Self {
+            pairs,
+            ranges_len,
+            len: <T as Element>::SafeLen::zero(),
+        };
+        let mut previous_end: Option<T> = None;
+        for (index, range) in view.ranges().enumerate() {
+            let (start, end) = range.clone().into_inner();
+            if start > end || end > T::safe_max_value() {
+                return Err(ViewError::BadRange { index });
+            }
+            if let Some(previous_end) = previous_end {
+                if previous_end >= start || previous_end + T::one() == start {
+                    return Err(ViewError::NotSortedDisjoint { index });
+                }
+            }
+            previous_end = Some(end);
+            view.len += T::safe_len(&range);
+        }
+        Ok(view)
+    }
+
+    /// Returns `true` if the view contains `value`.
+    ///
+    /// # Performance
+    ///
+    /// This is O(log n), where n is the number of ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{RangeSetBlaze, RangeSetView};
+    ///
+    /// let bytes = RangeSetBlaze::from_iter([1u64..=3, 10..=12]).to_view_bytes();
+    /// let view = RangeSetView::<u64>::new(&bytes).unwrap();
+    /// assert!(view.contains(11));
+    /// assert!(!view.contains(4));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        assert!(
+            value <= T::safe_max_value(),
+            "value must be <= T::safe_max_value()"
+        );
+        let (mut low, mut high) = (0, self.ranges_len);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.start(mid) <= value {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low != 0 && value <= self.end(low - 1)
+    }
+
+    /// Returns the number of elements in the view.
+    #[must_use]
+    pub const fn len(&self) -> <T as Element>::SafeLen {
+        self.len
+    }
+
+    /// Returns `true` if the view contains no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges_len == 0
+    }
+
+    /// Returns the number of sorted & disjoint ranges in the view.
+    #[must_use]
+    pub const fn ranges_len(&self) -> usize {
+        self.ranges_len
+    }
+
+    /// Gets a [`SortedDisjoint`] iterator over the ranges in the view, reading them from the bytes as it goes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{prelude::*, RangeSetView};
+    ///
+    /// let a_bytes = RangeSetBlaze::from_iter([1u8..=3, 10..=12]).to_view_bytes();
+    /// let b_bytes = RangeSetBlaze::from_iter([3u8..=10]).to_view_bytes();
+    /// let a = RangeSetView::<u8>::new(&a_bytes).unwrap();
+    /// let b = RangeSetView::<u8>::new(&b_bytes).unwrap();
+    /// assert_eq!((a.ranges() - b.ranges()).to_string(), "1..=2, 11..=12");
+    /// ```
+    pub fn ranges(&self) -> ViewRangesIter<'a, T> {
+        ViewRangesIter {
+            pairs: self.pairs,
+            front: 0,
+            back: self.ranges_len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Copies the view into a new [`RangeSetBlaze`].
+    #[must_use]
+    pub fn to_range_set_blaze(&self) -> RangeSetBlaze<T> {
+        RangeSetBlaze {
+            len: self.len,
+            btree_map: self.ranges().map(RangeInclusive::into_inner).collect(),
+        }
+    }
+
+    fn start(&self, index: usize) -> T {
+        read_pair::<T>(self.pairs, index).0
+    }
+
+    fn end(&self, index: usize) -> T {
+        read_pair::<T>(self.pairs, index).1
+    }
+}
+
+impl<T: LeBytes> RangeSetBlaze<T> {
+    /// Writes the set in the byte format read by [`RangeSetView`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use range_set_blaze::{RangeSetBlaze, RangeSetView};
+    ///
+    /// let set = RangeSetBlaze::from_iter([-10i32..=10, 100..=100]);
+    /// let bytes = set.to_view_bytes();
+    /// assert_eq!(bytes.len(), 16 + 2 * 2 * 4);
+    /// assert_eq!(RangeSetView::<i32>::new(&bytes).unwrap().to_range_set_blaze(), set);
+    /// ```
+    #[must_use]
+    pub fn to_view_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + self.ranges_len() * 2 * T::SIZE);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&[VERSION, T::SIZE as u8, u8::from(T::SIGNED), 0]);
+        bytes.extend_from_slice(&(self.ranges_len() as u64).to_le_bytes());
+        for (&start, &end) in &self.btree_map {
+            start.write_le(&mut bytes);
+            end.write_le(&mut bytes);
+        }
+        bytes
+    }
+}
+
+fn read_pair<T: LeBytes>(pairs: &[u8], index: usize) -> (T, T) {
+    let offset = index * 2 * T::SIZE;
+    (
+        T::read_le(&pairs[offset..offset + T::SIZE]),
+        T::read_le(&pairs[offset + T::SIZE..offset + 2 * T::SIZE]),
+    )
+}
+
+impl<T: LeBytes> fmt::Debug for RangeSetView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+impl<T: LeBytes> fmt::Display for RangeSetView<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ranges().to_string())
+    }
+}
+
+/// The error type returned by [`RangeSetView::new`] when the bytes aren't a valid view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ViewError {
+    /// The bytes are too short to hold the header.
+    TooShort {
+        /// The number of bytes given.
+        len: usize,
+    },
+    /// The bytes don't start with the magic bytes `RSBV`.
+    BadMagic,
+    /// The header has a format version that this crate can't read.
+    UnsupportedVersion(u8),
+    /// The header describes an integer type other than the view's.
+    TypeMismatch {
+        /// The size, in bytes, of the integer type in the header.
+        size: u8,
+        /// Whether the integer type in the header is signed.
+        signed: bool,
+    },
+    /// The number of bytes after the header doesn't match the number of ranges in the header.
+    LengthMismatch {
+        /// The number of ranges in the header.
+        ranges: u64,
+        /// The number of bytes after the header.
+        bytes: usize,
+    },
+    /// A range's start is greater than its end, or its end is greater than [`Element::safe_max_value`](crate::Element::safe_max_value).
+    BadRange {
+        /// The index of the range.
+        index: usize,
+    },
+    /// A range doesn't start after the previous range's end plus one.
+    NotSortedDisjoint {
+        /// The index of the range.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort { len } => write!(f, "{len} bytes is too short for the header"),
+            Self::BadMagic => write!(f, "the bytes don't start with the magic bytes"),
+            Self::UnsupportedVersion(version) => write!(f, "unsupported format version {version}"),
+            Self::TypeMismatch { size, signed } => write!(
+                f,
+                "the header describes a {}-byte {} integer type",
+                size,
+                if *signed { "signed" } else { "unsigned" }
+            ),
+            Self::LengthMismatch { ranges, bytes } => write!(
+                f,
+                "the header promises {ranges} ranges, but {bytes} bytes follow it"
+            ),
+            Self::BadRange { index } => write!(f, "range {index} is invalid"),
+            Self::NotSortedDisjoint { index } => {
+                write!(f, "range {index} isn't after the previous range")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ViewError {}
+
+/// An [`Integer`] that can be read from and written to little-endian bytes, for [`RangeSetView`].
+pub trait LeBytes: Integer {
+    /// The size of the integer type in bytes.
+    const SIZE: usize;
+    /// Whether the integer type is signed.
+    const SIGNED: bool;
+
+    /// Reads a value from exactly [`LeBytes::SIZE`] little-endian bytes.
+    fn read_le(bytes: &[u8]) -> Self;
+
+    /// Appends the value's little-endian bytes to `out`.
+    fn write_le(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_le_bytes {
+    ($($t:ty => $signed:expr),*) => {$(
+        impl LeBytes for $t {
+            const SIZE: usize = core::mem::size_of::<$t>();
+            const SIGNED: bool = $signed;
+
+            #[inline]
+            fn read_le(bytes: &[u8]) -> Self {
+                let mut array = [0; core::mem::size_of::<$t>()];
+                array.copy_from_slice(bytes);
+                <$t>::from_le_bytes(array)
+            }
+
+            #[inline]
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_le_bytes!(
+    i8 => true, u8 => false, i16 => true, u16 => false, i32 => true, u32 => false,
+    i64 => true, u64 => false, i128 => true, u128 => false, isize => true, usize => false
+);
+
+/// An iterator over the ranges of a [`RangeSetView`].
+///
+/// This `struct` is created by [`RangeSetView::ranges`]. See it for an example.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ViewRangesIter<'a, T: LeBytes> {
+    pairs: &'a [u8],
+    front: usize,
+    back: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T: LeBytes> FusedIterator for ViewRangesIter<'_, T> {}
+
+impl<T: LeBytes> Iterator for ViewRangesIter<'_, T> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<RangeInclusive<T>> {
+        if self.front == self.back {
+            return None;
+        }
+        let (start, end) = read_pair::<T>(self.pairs, self.front);
+        self.front += 1;
+        Some(start..=end)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T: LeBytes> DoubleEndedIterator for ViewRangesIter<'_, T> {
+    fn next_back(&mut self) -> Option<RangeInclusive<T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let (start, end) = read_pair::<T>(self.pairs, self.back);
+        Some(start..=end)
+    }
+}
+
+impl<T: LeBytes> ExactSizeIterator for ViewRangesIter<'_, T> {}
+
+impl<T: LeBytes> SortedStarts<T> for ViewRangesIter<'_, T> {}
+impl<T: LeBytes> SortedDisjoint<T> for ViewRangesIter<'_, T> {}
+
+impl<T: LeBytes> ops::Not for ViewRangesIter<'_, T> {
+    type Output = NotIter<T, Self>;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+impl<T: LeBytes, R> ops::BitOr<R> for ViewRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitOrMerge<T, Self, R>;
+
+    fn bitor(self, other: R) -> Self::Output {
+        SortedDisjoint::union(self, other)
+    }
+}
+
+impl<T: LeBytes, R> ops::BitAnd<R> for ViewRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitAndMerge<T, Self, R>;
+
+    fn bitand(self, other: R) -> Self::Output {
+        SortedDisjoint::intersection(self, other)
+    }
+}
+
+impl<T: LeBytes, R> ops::Sub<R> for ViewRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitSubMerge<T, Self, R>;
+
+    fn sub(self, other: R) -> Self::Output {
+        SortedDisjoint::difference(self, other)
+    }
+}
+
+impl<T: LeBytes, R> ops::BitXor<R> for ViewRangesIter<'_, T>
+where
+    R: SortedDisjoint<T>,
+{
+    type Output = BitXOrTee<T, Self, R>;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn bitxor(self, other: R) -> Self::Output {
+        SortedDisjoint::symmetric_difference(self, other)
+    }
+}
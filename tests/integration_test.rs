@@ -13,7 +13,8 @@ use rand::SeedableRng;
 #[cfg(feature = "rog-experimental")]
 use range_set_blaze::Rog;
 use range_set_blaze::{
-    prelude::*, AssumeSortedStarts, Integer, NotIter, RangesIter, SortedStarts, UnionIter,
+    prelude::*, AssumeSortedStarts, Element, Integer, NotIter, RangesIter, SortedStarts,
+    UIntPlusOne, UnionIter,
 };
 use std::cmp::Ordering;
 #[cfg(feature = "rog-experimental")]
@@ -25,7 +26,7 @@ use std::{collections::BTreeSet, ops::BitOr};
 use syntactic_for::syntactic_for;
 use tests_common::{k_sets, width_to_range, How, MemorylessIter, MemorylessRange};
 
-type I32SafeLen = <i32 as range_set_blaze::Integer>::SafeLen;
+type I32SafeLen = <i32 as range_set_blaze::Element>::SafeLen;
 
 #[test]
 fn insert_255u8() {
@@ -34,9 +35,9 @@ fn insert_255u8() {
 }
 
 #[test]
-#[should_panic]
 fn insert_max_u128() {
-    let _ = RangeSetBlaze::<u128>::from_iter([u128::MAX]);
+    let a = RangeSetBlaze::<u128>::from_iter([u128::MAX]);
+    assert_eq!(a.len(), UIntPlusOne::UInt(1));
 }
 
 #[test]
@@ -574,23 +575,23 @@ fn tricky_case1() {
 
     let a = !RangeSetBlaze::from_iter([1i128..=0]);
     println!("tc1 '{a}', {}", a.len());
-    assert_eq!(a.len(), u128::MAX);
+    assert_eq!(a.len(), UIntPlusOne::MaxPlusOne);
     let a = !RangeSetBlaze::from_iter([1u128..=0]);
     println!("tc1 '{a}', {}", a.len());
-    assert_eq!(a.len(), u128::MAX);
+    assert_eq!(a.len(), UIntPlusOne::MaxPlusOne);
 }
 
 // should fail
 #[test]
-#[should_panic]
 fn tricky_case2() {
-    let _a = RangeSetBlaze::from_iter([-1..=i128::MAX]);
+    let a = RangeSetBlaze::from_iter([-1..=i128::MAX]);
+    assert_eq!(a.len(), UIntPlusOne::UInt(1 << 127) + UIntPlusOne::UInt(1));
 }
 
 #[test]
-#[should_panic]
 fn tricky_case3() {
-    let _a = RangeSetBlaze::from_iter([0..=u128::MAX]);
+    let a = RangeSetBlaze::from_iter([0..=u128::MAX]);
+    assert_eq!(a.len(), UIntPlusOne::MaxPlusOne);
 }
 
 #[test]
@@ -863,7 +864,7 @@ fn doc_test_len() {
     ]);
     assert_eq!(
         v.len(),
-        340_282_366_920_938_463_463_374_607_431_768_211_455u128
+        UIntPlusOne::UInt(340_282_366_920_938_463_463_374_607_431_768_211_455u128)
     );
 }
 
@@ -1146,10 +1147,10 @@ fn not_iter_example() {
 
 #[test]
 fn len_demo() {
-    let len: <u8 as Integer>::SafeLen = RangeSetBlaze::from_iter([0u8..=255]).len();
+    let len: <u8 as Element>::SafeLen = RangeSetBlaze::from_iter([0u8..=255]).len();
     assert_eq!(len, 256);
 
-    assert_eq!(<u8 as Integer>::safe_len(&(0..=255)), 256);
+    assert_eq!(<u8 as Element>::safe_len(&(0..=255)), 256);
 }
 
 #[test]
@@ -1858,3 +1859,1746 @@ fn test_rog_range_doc() {
         vec![Rog::Gap(0..=255)]
     );
 }
+
+#[test]
+fn minkowski_sum_and_difference() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let a: RangeSetBlaze<i8> = (0..rng.gen_range(0..4))
+            .map(|_| {
+                let start = rng.gen::<i8>();
+                start..=start.saturating_add(rng.gen_range(0..20))
+            })
+            .collect();
+        let b: RangeSetBlaze<i8> = (0..rng.gen_range(0..4))
+            .map(|_| {
+                let start = rng.gen::<i8>();
+                start..=start.saturating_add(rng.gen_range(0..20))
+            })
+            .collect();
+
+        let sum: RangeSetBlaze<i8> = a
+            .iter()
+            .cartesian_product(b.iter())
+            .filter_map(|(x, y)| x.checked_add(y))
+            .collect();
+        assert_eq!(a.minkowski_sum(&b), sum);
+        assert_eq!(b.minkowski_sum(&a), sum);
+
+        let difference: RangeSetBlaze<i8> = a
+            .iter()
+            .cartesian_product(b.iter())
+            .filter_map(|(x, y)| x.checked_sub(y))
+            .collect();
+        assert_eq!(a.minkowski_difference(&b), difference);
+    }
+
+    let a = RangeSetBlaze::from_iter([0u8..=5, 200..=255]);
+    let b = RangeSetBlaze::from_iter([3u8..=4, 100..=100]);
+    assert_eq!(
+        a.minkowski_sum(&b).to_string(),
+        "3..=9, 100..=105, 203..=255"
+    );
+    assert_eq!(
+        a.minkowski_difference(&b).to_string(),
+        "0..=2, 100..=155, 196..=252"
+    );
+    assert!(a.minkowski_sum(&RangeSetBlaze::new()).is_empty());
+}
+
+#[test]
+fn convert_between_integer_types() {
+    use rand::Rng;
+    use range_set_blaze::SignBias;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let a: RangeSetBlaze<i16> = (0..rng.gen_range(0..20))
+            .map(|_| {
+                let start = rng.gen_range(-400i16..400);
+                start..=start + rng.gen_range(0..50)
+            })
+            .collect();
+
+        let expected: RangeSetBlaze<i32> = a.iter().map(i32::from).collect();
+        assert_eq!(a.convert::<i32>(), expected);
+
+        let expected: RangeSetBlaze<u8> = a.iter().filter_map(|x| u8::try_from(x).ok()).collect();
+        assert_eq!(a.clamp_convert::<u8>(), expected);
+        match a.try_convert::<u8>() {
+            Ok(b) => assert_eq!(b, expected),
+            Err(err) => {
+                assert!(err.range().any(|x| u8::try_from(x).is_err()));
+                assert!(a.iter().any(|x| u8::try_from(x).is_err()));
+            }
+        }
+
+        let b = a.bias_convert();
+        let expected: RangeSetBlaze<u16> = a.iter().map(|x| x.bias()).collect();
+        assert_eq!(b, expected);
+        assert_eq!(b.bias_convert(), a);
+    }
+
+    // u128 and i128 never contain their max value, and neither does the biased set.
+    let a = RangeSetBlaze::from_iter([i128::MIN..=i128::MAX - 1]);
+    assert_eq!(
+        a.bias_convert(),
+        RangeSetBlaze::from_iter([0..=u128::MAX - 1])
+    );
+    let a = RangeSetBlaze::from_iter([0u64..=u64::MAX]);
+    assert_eq!(
+        a.clamp_convert::<u128>(),
+        RangeSetBlaze::from_iter([0..=u64::MAX as u128])
+    );
+    assert!(RangeSetBlaze::from_iter([0u128..=u128::MAX - 1])
+        .try_convert::<u64>()
+        .is_err());
+}
+
+#[test]
+fn periodic() {
+    use range_set_blaze::Periodic;
+
+    // Compare against brute force over every small case for i8.
+    for start in (-128i16..=127).step_by(17) {
+        for until in (-128i16..=127).step_by(23) {
+            for period in [1usize, 2, 3, 7, 100, 255, 256, 300] {
+                for on_len in [0usize, 1, 2, 6, 7, 256] {
+                    let (start, until) = (start as i8, until as i8);
+                    let expected: RangeSetBlaze<i8> = (start..=until)
+                        .filter(|x| (*x as i16 - start as i16) as usize % period < on_len)
+                        .collect();
+                    let actual = Periodic::new(start, period, on_len, until);
+                    assert!(actual.clone().equal(expected.ranges()));
+                    let actual = CheckSortedDisjoint::new(actual).into_range_set_blaze();
+                    assert_eq!(actual, expected);
+                }
+            }
+        }
+    }
+
+    // Near the end of the largest types.
+    let max = u128::MAX - 1;
+    let a = Periodic::new(
+        max - 10,
+        UIntPlusOne::UInt(4),
+        UIntPlusOne::UInt(2),
+        u128::MAX,
+    );
+    assert_eq!(
+        a.to_string(),
+        format!(
+            "{}..={}, {}..={}, {}..={}",
+            max - 10,
+            max - 9,
+            max - 6,
+            max - 5,
+            max - 2,
+            max - 1
+        )
+    );
+    let a = Periodic::new(
+        i128::MIN,
+        UIntPlusOne::UInt(u128::MAX),
+        UIntPlusOne::UInt(3),
+        i128::MAX,
+    );
+    assert_eq!(
+        a.to_string(),
+        format!(
+            "{}..={}, {}..={}",
+            i128::MIN,
+            i128::MIN + 2,
+            i128::MAX,
+            i128::MAX
+        )
+    );
+    let a = Periodic::new(
+        i128::MIN,
+        UIntPlusOne::MaxPlusOne,
+        UIntPlusOne::UInt(3),
+        i128::MAX,
+    );
+    assert_eq!(a.to_string(), format!("{}..={}", i128::MIN, i128::MIN + 2));
+    assert!(Periodic::new(5u8, 1, 1, 4).equal(CheckSortedDisjoint::new([])));
+
+    // Offsets and set operations.
+    let a = Periodic::new(0u16, 10, 3, 50).with_offsets([0, 2, 45, 60]);
+    assert_eq!(a.to_string(), "0..=4, 10..=14, 20..=24, 30..=34, 40..=47, 50..=50");
+    let a = !Periodic::new(0u8, 2, 1, u8::MAX);
+    assert!(a.equal(Periodic::new(1u8, 2, 1, u8::MAX)));
+    let a = Periodic::new(0u8, 2, 1, 10) & Periodic::arithmetic(0u8, 3, 10);
+    assert_eq!(a.to_string(), "0..=0, 6..=6");
+}
+
+#[test]
+#[should_panic]
+fn periodic_zero_period() {
+    let _ = range_set_blaze::Periodic::new(0u8, 0, 1, 10);
+}
+
+#[test]
+fn from_predicate_and_monotone() {
+    use rand::Rng;
+    use range_set_blaze::Periodic;
+
+    // When the pieces never get shorter, galloping can't skip a piece, so the result is exact.
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let mut expected = RangeSetBlaze::<u16>::new();
+        let mut start = 0u32;
+        let mut len = rng.gen_range(1..50);
+        let mut value = rng.gen::<bool>();
+        while start <= u16::MAX as u32 {
+            let end = (start + len - 1).min(u16::MAX as u32);
+            if value {
+                expected.ranges_insert(start as u16..=end as u16);
+            }
+            start = end + 1;
+            len += rng.gen_range(0..20);
+            value = !value;
+        }
+        let mut calls = 0;
+        let actual = RangeSetBlaze::from_predicate(0..=u16::MAX, |x| {
+            calls += 1;
+            expected.contains(x)
+        });
+        assert_eq!(actual, expected);
+        assert!(calls < 65_536 / 4);
+    }
+
+    // Pieces of the same length.
+    for period in [2usize, 10, 64, 1000] {
+        let expected: RangeSetBlaze<i32> =
+            Periodic::new(-5000, period, period / 2, 5000).into_range_set_blaze();
+        let actual = RangeSetBlaze::from_predicate(-5000..=5000, |x| expected.contains(x));
+        assert_eq!(actual, expected);
+        let actual = RangeSetBlaze::from_predicate(-1234..=3210, |x| expected.contains(x));
+        assert_eq!(
+            actual,
+            &expected & &RangeSetBlaze::from_iter([-1234..=3210])
+        );
+    }
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = 5..=4;
+    assert!(RangeSetBlaze::from_predicate(empty, |_| true).is_empty());
+    let a = RangeSetBlaze::from_predicate(0..=u128::MAX - 1, |x| x > 7 && x < u128::MAX - 3);
+    assert_eq!(a, RangeSetBlaze::from_iter([8..=u128::MAX - 4]));
+
+    // Every threshold, in both directions, over all of i8.
+    for threshold in i8::MIN..=i8::MAX {
+        let mut calls = 0;
+        let a = RangeSetBlaze::from_monotone(i8::MIN..=i8::MAX, |x| {
+            calls += 1;
+            x >= threshold
+        });
+        assert_eq!(a, RangeSetBlaze::from_iter([threshold..=i8::MAX]));
+        assert!(calls <= 10);
+        let a = RangeSetBlaze::from_monotone(i8::MIN..=i8::MAX, |x| x < threshold);
+        assert_eq!(a, RangeSetBlaze::from_iter(i8::MIN..threshold));
+    }
+    let a = RangeSetBlaze::from_monotone(i128::MIN..=i128::MAX - 1, |x| x > -5);
+    assert_eq!(a, RangeSetBlaze::from_iter([-4..=i128::MAX - 1]));
+    assert!(RangeSetBlaze::from_monotone(0u8..=9, |_| false).is_empty());
+}
+
+#[test]
+fn cursor_random_walk() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let set: RangeSetBlaze<u8> = (0..rng.gen_range(0..10))
+            .map(|_| {
+                let start = rng.gen_range(0..250u8);
+                start..=start + rng.gen_range(0..5)
+            })
+            .collect();
+        let ranges = set.ranges().collect_vec();
+        let value = rng.gen();
+        // The ghost position is index `ranges.len()`.
+        let mut index = ranges
+            .iter()
+            .position(|range| value <= *range.end())
+            .unwrap_or(ranges.len());
+        let mut cursor = set.cursor_at(value);
+        for _ in 0..30 {
+            assert_eq!(cursor.peek_range(), ranges.get(index).cloned());
+            // Favor runs of moves in one direction.
+            if rng.gen_bool(0.8) {
+                cursor.move_next();
+                index = (index + 1) % (ranges.len() + 1);
+            } else {
+                cursor.move_prev();
+                index = (index + ranges.len()) % (ranges.len() + 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn cursor_mut_random_edits() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let mut set: RangeSetBlaze<u8> = (0..rng.gen_range(0..10))
+            .map(|_| {
+                let start = rng.gen_range(0..250u8);
+                start..=start + rng.gen_range(0..5)
+            })
+            .collect();
+        let mut expected = set.clone();
+        let mut cursor = set.cursor_mut_at(rng.gen());
+        for _ in 0..20 {
+            match (rng.gen_range(0..5), cursor.peek_range()) {
+                (0, _) => cursor.move_next(),
+                (1, _) => cursor.move_prev(),
+                (2, Some(range)) => {
+                    assert_eq!(cursor.remove_current_range(), Some(range.clone()));
+                    expected = &expected - &RangeSetBlaze::from_iter([range]);
+                }
+                (3, Some(range)) => {
+                    let at = rng.gen_range(range.clone());
+                    let removed = cursor.split_current(at);
+                    assert_eq!(cursor.peek_range(), Some(at..=*range.end()));
+                    if at == *range.start() {
+                        assert_eq!(removed, None);
+                    } else {
+                        let removed = removed.unwrap();
+                        assert_eq!(removed, *range.start()..=at - 1);
+                        expected = &expected - &RangeSetBlaze::from_iter([removed]);
+                    }
+                }
+                (4, current) => {
+                    // Fill part of the gap before the current range (or after the last range).
+                    cursor.move_prev();
+                    let gap_start = cursor.peek_range().map_or(0, |prev| *prev.end() as u16 + 1);
+                    cursor.move_next();
+                    let gap_end = current.as_ref().map_or(255, |c| *c.start() as i16 - 1);
+                    if gap_start as i16 <= gap_end {
+                        let start = rng.gen_range(gap_start as i16..=gap_end) as u8;
+                        let end = rng.gen_range(start as i16..=gap_end) as u8;
+                        cursor.insert_before(start..=end);
+                        expected.ranges_insert(start..=end);
+                        if let Some(current) = current {
+                            assert!(cursor.peek_range().unwrap().contains(current.start()));
+                        } else {
+                            assert_eq!(cursor.peek_range(), None);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        assert_eq!(set, expected);
+        assert_eq!(set.len(), expected.iter().count());
+    }
+}
+
+#[test]
+#[should_panic]
+fn cursor_mut_insert_after_overlap() {
+    let mut set = RangeSetBlaze::from_iter([1..=3, 10..=12]);
+    let mut cursor = set.cursor_mut_at(1);
+    cursor.insert_after(3..=5);
+}
+
+#[test]
+fn range_allocator_matches_brute_force() {
+    use rand::Rng;
+    use range_set_blaze::{FitPolicy, RangeAllocator};
+
+    // The lowest aligned start in `range` that leaves room for `len` integers, found by brute force.
+    fn fits(range: &RangeInclusive<u8>, len: usize, align: usize) -> Option<u8> {
+        range.clone().find(|&start| {
+            (start as usize).is_multiple_of(align)
+                && start as usize + len - 1 <= *range.end() as usize
+        })
+    }
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for policy in [FitPolicy::FirstFit, FitPolicy::BestFit, FitPolicy::NextFit] {
+        for _ in 0..100 {
+            let mut free: RangeSetBlaze<u8> = (0..rng.gen_range(0..10))
+                .map(|_| {
+                    let start = rng.gen::<u8>();
+                    start..=start.saturating_add(rng.gen_range(0..40))
+                })
+                .collect();
+            let mut allocator = RangeAllocator::new(free.clone(), policy);
+            let mut allocated = Vec::new();
+            let mut next_fit = 0u8;
+            for _ in 0..30 {
+                if allocated.is_empty() || rng.gen_bool(0.6) {
+                    let len = rng.gen_range(1..20usize);
+                    let align = [1usize, 2, 4, 3, 16][rng.gen_range(0..5)];
+                    let candidates = free
+                        .ranges()
+                        .filter_map(|r| fits(&r, len, align).map(|s| (r, s)));
+                    let expected = match policy {
+                        FitPolicy::FirstFit => candidates.map(|(_, s)| s).next(),
+                        FitPolicy::BestFit => candidates
+                            .min_by_key(|(r, s)| (r.len(), *s))
+                            .map(|(_, s)| s),
+                        FitPolicy::NextFit => free
+                            .ranges()
+                            .filter_map(|r| {
+                                let r = (*r.start()).max(next_fit)..=*r.end();
+                                fits(&r, len, align)
+                            })
+                            .next()
+                            .or_else(|| candidates.map(|(_, s)| s).next()),
+                    };
+                    let actual = allocator.allocate(len, align);
+                    assert_eq!(actual.as_ref().map(|r| *r.start()), expected);
+                    if let Some(range) = actual {
+                        assert_eq!(range.len(), len);
+                        free = &free - &RangeSetBlaze::from_iter([range.clone()]);
+                        next_fit = range.end().wrapping_add(1);
+                        allocated.push(range);
+                    }
+                } else {
+                    let range = allocated.swap_remove(rng.gen_range(0..allocated.len()));
+                    allocator.free(range.clone()).unwrap();
+                    free.ranges_insert(range.clone());
+                    assert_eq!(allocator.free(range.clone()).unwrap_err().range(), range);
+                }
+                assert_eq!(allocator.free_ranges(), &free);
+                assert_eq!(allocator.free_ranges().len(), free.iter().count());
+            }
+        }
+    }
+}
+
+#[test]
+fn range_allocator_skips_candidates_that_do_not_fit_once_aligned() {
+    use range_set_blaze::{FitPolicy, RangeAllocator};
+
+    for policy in [FitPolicy::FirstFit, FitPolicy::BestFit, FitPolicy::NextFit] {
+        let free = RangeSetBlaze::from_iter([1u32..=4, 8..=11]);
+        let mut allocator = RangeAllocator::new(free, policy);
+        assert_eq!(allocator.allocate(4, 4), Some(8..=11), "{policy:?}");
+    }
+}
+
+#[test]
+fn indexed_range_set_blaze_matches_brute_force() {
+    use rand::Rng;
+    use range_set_blaze::IndexedRangeSetBlaze;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..50 {
+        let mut expected: RangeSetBlaze<u8> = (0..rng.gen_range(0..5))
+            .map(|_| {
+                let start = rng.gen::<u8>();
+                start..=start.saturating_add(rng.gen_range(0..10))
+            })
+            .collect();
+        let mut set = IndexedRangeSetBlaze::from(expected.clone());
+        for _ in 0..50 {
+            let start = rng.gen::<u8>();
+            let range = start..=start.saturating_add(rng.gen_range(0..10));
+            if rng.gen_bool(0.5) {
+                let changed = !(&RangeSetBlaze::from_iter([range.clone()]) - &expected).is_empty();
+                assert_eq!(set.ranges_insert(range.clone()), changed);
+                expected.ranges_insert(range);
+            } else {
+                let changed = !(&RangeSetBlaze::from_iter([range.clone()]) & &expected).is_empty();
+                assert_eq!(set.ranges_remove(range.clone()), changed);
+                expected = &expected - &RangeSetBlaze::from_iter([range]);
+            }
+            assert_eq!(set.as_range_set_blaze(), &expected);
+            assert_eq!(set.as_range_set_blaze().len(), expected.iter().count());
+
+            let ranges: Vec<_> = expected.ranges().collect();
+            let gaps: Vec<_> = ranges
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| *a.end() + 1..=*b.start() - 1)
+                .collect();
+            // max_by_key returns the last maximum, so reverse to break ties by lowest start.
+            let longest =
+                |v: &Vec<RangeInclusive<u8>>| v.iter().rev().max_by_key(|r| r.len()).cloned();
+            assert_eq!(set.longest_range(), longest(&ranges));
+            assert_eq!(set.longest_gap(), longest(&gaps));
+
+            let n = rng.gen_range(0..12);
+            let mut long: Vec<_> = ranges.iter().filter(|r| r.len() > n).cloned().collect();
+            long.sort_by_key(|r| (r.len(), *r.start()));
+            assert_eq!(set.ranges_longer_than(n).collect::<Vec<_>>(), long);
+
+            let histogram = ranges.iter().map(|r| r.len()).counts();
+            let histogram: Vec<_> = histogram.into_iter().sorted().collect();
+            assert_eq!(set.len_histogram().collect::<Vec<_>>(), histogram);
+        }
+    }
+}
+
+#[test]
+fn neighbors_match_brute_force() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let set: RangeSetBlaze<i8> = (0..rng.gen_range(0..6))
+            .map(|_| {
+                let start = rng.gen::<i8>();
+                start..=start.saturating_add(rng.gen_range(0..20))
+            })
+            .collect();
+        let members: Vec<i8> = set.iter().collect();
+        let missing: Vec<i8> = (i8::MIN..=i8::MAX).filter(|v| !set.contains(*v)).collect();
+        for value in i8::MIN..=i8::MAX {
+            let next = members.iter().copied().find(|&v| v >= value);
+            let prev = members.iter().copied().rev().find(|&v| v <= value);
+            assert_eq!(set.next_at_or_after(value), next);
+            assert_eq!(set.prev_at_or_before(value), prev);
+            assert_eq!(
+                set.next_after(value),
+                members.iter().copied().find(|&v| v > value)
+            );
+            assert_eq!(
+                set.prev_before(value),
+                members.iter().copied().rev().find(|&v| v < value)
+            );
+            let nearest = members
+                .iter()
+                .copied()
+                .min_by_key(|&v| (i16::from(v) - i16::from(value)).abs());
+            assert_eq!(set.nearest(value), nearest);
+            assert_eq!(
+                set.next_missing_at_or_after(value),
+                missing.iter().copied().find(|&v| v >= value)
+            );
+            assert_eq!(
+                set.prev_missing_at_or_before(value),
+                missing.iter().copied().rev().find(|&v| v <= value)
+            );
+        }
+
+        let mut queries: Vec<i8> = (0..rng.gen_range(0..20)).map(|_| rng.gen()).collect();
+        queries.sort_unstable();
+        let expected: Vec<_> = queries.iter().map(|&q| set.next_at_or_after(q)).collect();
+        let actual: Vec<_> = set.next_at_or_after_sorted(queries.clone()).collect();
+        assert_eq!(actual, expected);
+        let expected: Vec<_> = queries.iter().map(|&q| set.prev_at_or_before(q)).collect();
+        let actual: Vec<_> = set.prev_at_or_before_sorted(queries.clone()).collect();
+        assert_eq!(actual, expected);
+        let expected: Vec<_> = queries.iter().map(|&q| set.nearest(q)).collect();
+        let actual: Vec<_> = set.nearest_sorted(queries).collect();
+        assert_eq!(actual, expected);
+    }
+}
+
+#[test]
+#[should_panic(expected = "values must be sorted")]
+fn neighbors_sorted_unsorted_values() {
+    let set = RangeSetBlaze::from_iter([10..=19]);
+    let _ = set.nearest_sorted([5, 3]).count();
+}
+
+#[test]
+fn contains_sorted_matches_contains() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let set: RangeSetBlaze<u16> = (0..rng.gen_range(0..40))
+            .map(|_| {
+                let start = rng.gen_range(0..1000);
+                start..=start + rng.gen_range(0..20)
+            })
+            .collect();
+        let mut values: Vec<u16> = (0..rng.gen_range(0..60))
+            .map(|_| rng.gen_range(0..1100))
+            .collect();
+        values.sort_unstable();
+        let expected: Vec<_> = values.iter().map(|&v| set.contains(v)).collect();
+        let members: Vec<_> = values
+            .iter()
+            .copied()
+            .filter(|&v| set.contains(v))
+            .collect();
+        let non_members: Vec<_> = values
+            .iter()
+            .copied()
+            .filter(|&v| !set.contains(v))
+            .collect();
+
+        assert_eq!(
+            set.contains_sorted(values.clone()).collect::<Vec<_>>(),
+            expected
+        );
+        let galloping = set.contains_sorted(values.clone()).galloping();
+        assert_eq!(galloping.collect::<Vec<_>>(), expected);
+        assert_eq!(
+            set.filter_members(values.clone()).collect::<Vec<_>>(),
+            members
+        );
+        let galloping = set.filter_members(values.clone()).galloping();
+        assert_eq!(galloping.collect::<Vec<_>>(), members);
+        assert_eq!(
+            set.filter_non_members(values.clone()).collect::<Vec<_>>(),
+            non_members
+        );
+        let galloping = set.filter_non_members(values).galloping();
+        assert_eq!(galloping.collect::<Vec<_>>(), non_members);
+    }
+}
+
+#[test]
+#[should_panic(expected = "values must be sorted")]
+fn contains_sorted_unsorted_values() {
+    let set = RangeSetBlaze::from_iter([10..=19]);
+    let _ = set.contains_sorted([15, 12]).count();
+}
+
+#[test]
+fn skewed_set_operations_match_merge() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let random_set = |rng: &mut StdRng, count: usize| -> RangeSetBlaze<u8> {
+        (0..count)
+            .map(|_| {
+                let start = rng.gen::<u8>();
+                start..=start.saturating_add(rng.gen_range(0..4))
+            })
+            .collect()
+    };
+    for _ in 0..500 {
+        let small_count = rng.gen_range(0..4);
+        let large_count = rng.gen_range(0..200);
+        let small = random_set(&mut rng, small_count);
+        let large = random_set(&mut rng, large_count);
+        // Subsets and supersets, so that `is_subset` sees both answers.
+        let inside = &large - &random_set(&mut rng, 3);
+        let outside = &small | &random_set(&mut rng, 100);
+        for (a, b) in [
+            (&small, &large),
+            (&large, &small),
+            (&inside, &large),
+            (&small, &outside),
+            (&outside, &small),
+        ] {
+            assert_eq!(a & b, (a.ranges() & b.ranges()).into_range_set_blaze());
+            assert_eq!(a - b, (a.ranges() - b.ranges()).into_range_set_blaze());
+            assert_eq!(a.is_subset(b), a.ranges().is_subset(b.ranges()));
+            assert_eq!(a.is_disjoint(b), a.ranges().is_disjoint(b.ranges()));
+            assert_eq!((a & b).len(), (a & b).iter().count());
+            assert_eq!((a - b).len(), (a - b).iter().count());
+        }
+    }
+    let full = RangeSetBlaze::from_iter([0u8..=255]);
+    let ends = RangeSetBlaze::from_iter([0u8, 255]);
+    let many: RangeSetBlaze<u8> = (0u8..=255).step_by(2).collect();
+    assert_eq!(&ends & &many, RangeSetBlaze::from_iter([0u8]));
+    assert_eq!(&ends - &many, RangeSetBlaze::from_iter([255u8]));
+    assert_eq!(&full - &many, (1u8..=255).step_by(2).collect());
+    assert!(ends.is_subset(&(&many | &ends)));
+    assert!(!full.is_subset(&many));
+}
+
+#[test]
+fn frozen_range_set_matches_range_set_blaze() {
+    use rand::Rng;
+    use range_set_blaze::FrozenRangeSet;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let a: RangeSetBlaze<u8> = (0..rng.gen_range(0..40))
+            .map(|_| {
+                let start = rng.gen::<u8>();
+                start..=start.saturating_add(rng.gen_range(0..6))
+            })
+            .collect();
+        let b: RangeSetBlaze<u8> = (0..rng.gen_range(0..10))
+            .map(|_| {
+                let start = rng.gen::<u8>();
+                start..=start.saturating_add(rng.gen_range(0..30))
+            })
+            .collect();
+        let frozen_a = a.freeze();
+        let frozen_b = FrozenRangeSet::from(&b).eytzinger();
+        assert!(!frozen_a.is_eytzinger());
+        assert!(frozen_b.is_eytzinger());
+        for value in 0..=255 {
+            assert_eq!(frozen_a.contains(value), a.contains(value));
+            assert_eq!(
+                frozen_a.clone().eytzinger().contains(value),
+                a.contains(value)
+            );
+            assert_eq!(frozen_b.contains(value), b.contains(value));
+        }
+        assert_eq!(frozen_a.len(), a.len());
+        assert_eq!(frozen_a.is_empty(), a.is_empty());
+        assert_eq!(frozen_a.ranges_len(), a.ranges_len());
+        assert!(frozen_a.ranges().eq(a.ranges()));
+        assert!(frozen_a.ranges().rev().eq(a.ranges().rev()));
+        assert_eq!(frozen_a.to_string(), a.to_string());
+
+        assert_eq!(&frozen_a | &frozen_b, &a | &b);
+        assert_eq!(&frozen_a & &frozen_b, &a & &b);
+        assert_eq!(&frozen_a ^ &frozen_b, &a ^ &b);
+        assert_eq!(&frozen_a - &frozen_b, &a - &b);
+        assert_eq!(!&frozen_a, !&a);
+        assert_eq!(frozen_a.clone().thaw(), a);
+        assert_eq!(RangeSetBlaze::from(frozen_b), b);
+    }
+}
+
+#[test]
+fn range_set_view_round_trip_and_validation() {
+    use rand::Rng;
+    use range_set_blaze::{RangeSetView, ViewError};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let set: RangeSetBlaze<i16> = (0..rng.gen_range(0..20))
+            .map(|_| {
+                let start = rng.gen_range(-300..300);
+                start..=start + rng.gen_range(0..10)
+            })
+            .collect();
+        let bytes = set.to_view_bytes();
+        // Shift by one byte so the ranges are misaligned.
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(&bytes);
+        let view = RangeSetView::<i16>::new(&shifted[1..]).unwrap();
+        assert_eq!(view.len(), set.len());
+        assert_eq!(view.ranges_len(), set.ranges_len());
+        assert_eq!(view.is_empty(), set.is_empty());
+        assert!(view.ranges().eq(set.ranges()));
+        assert!(view.ranges().rev().eq(set.ranges().rev()));
+        assert_eq!(view.to_range_set_blaze(), set);
+        assert_eq!(view.to_string(), set.to_string());
+        for value in -320..320 {
+            assert_eq!(view.contains(value), set.contains(value));
+        }
+        let other = RangeSetBlaze::from_iter([-100i16..=100]);
+        assert_eq!(
+            (view.ranges() ^ other.ranges()).into_range_set_blaze(),
+            &set ^ &other
+        );
+    }
+
+    let bytes = RangeSetBlaze::from_iter([1u8..=3, 10..=12]).to_view_bytes();
+    assert_eq!(
+        RangeSetView::<u8>::new(&bytes[..10]).unwrap_err(),
+        ViewError::TooShort { len: 10 }
+    );
+    let mut bad = bytes.clone();
+    bad[0] = b'X';
+    assert_eq!(
+        RangeSetView::<u8>::new(&bad).unwrap_err(),
+        ViewError::BadMagic
+    );
+    let mut bad = bytes.clone();
+    bad[4] = 2;
+    assert_eq!(
+        RangeSetView::<u8>::new(&bad).unwrap_err(),
+        ViewError::UnsupportedVersion(2)
+    );
+    assert_eq!(
+        RangeSetView::<i8>::new(&bytes).unwrap_err(),
+        ViewError::TypeMismatch {
+            size: 1,
+            signed: false
+        }
+    );
+    let mut bad = bytes.clone();
+    bad[8] = 3;
+    assert_eq!(
+        RangeSetView::<u8>::new(&bad).unwrap_err(),
+        ViewError::LengthMismatch {
+            ranges: 3,
+            bytes: 4
+        }
+    );
+    let mut bad = bytes.clone();
+    bad[17] = 0; // 1..=0
+    assert_eq!(
+        RangeSetView::<u8>::new(&bad).unwrap_err(),
+        ViewError::BadRange { index: 0 }
+    );
+    let mut bad = bytes.clone();
+    bad[18] = 4; // 1..=3, 4..=12 touch
+    assert_eq!(
+        RangeSetView::<u8>::new(&bad).unwrap_err(),
+        ViewError::NotSortedDisjoint { index: 1 }
+    );
+    assert_eq!(
+        ViewError::NotSortedDisjoint { index: 1 }.to_string(),
+        "range 1 isn't after the previous range"
+    );
+}
+
+#[test]
+fn elias_fano_matches_range_set_blaze() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let max_len = if rng.gen_bool(0.5) { 1 } else { 20 };
+        let set: RangeSetBlaze<i16> = (0..rng.gen_range(0..50))
+            .map(|_| {
+                let start = rng.gen_range(-1000..1000);
+                start..=start + rng.gen_range(0..max_len)
+            })
+            .collect();
+        let compressed = set.to_elias_fano();
+        assert_eq!(compressed.len(), set.len());
+        assert_eq!(compressed.is_empty(), set.is_empty());
+        assert_eq!(compressed.ranges_len(), set.ranges_len());
+        assert_eq!(compressed.size_in_bytes(), set.compressed_size_estimate());
+        assert!(compressed.ranges().eq(set.ranges()));
+        assert_eq!(compressed.to_range_set_blaze(), set);
+        let members: Vec<i16> = set.iter().collect();
+        for value in -1100..1100 {
+            assert_eq!(compressed.contains(value), set.contains(value));
+            let rank = members.partition_point(|&member| member < value);
+            assert_eq!(compressed.rank(value), rank);
+        }
+        for (position, &member) in members.iter().enumerate() {
+            assert_eq!(compressed.select(position), Some(member));
+        }
+        assert_eq!(compressed.select(members.len()), None);
+    }
+
+    let set = RangeSetBlaze::from_iter([
+        i128::MIN..=i128::MIN + 1,
+        0..=0,
+        i128::MAX - 1..=i128::MAX - 1,
+    ]);
+    let compressed = set.to_elias_fano();
+    assert!(compressed.ranges().eq(set.ranges()));
+    assert!(compressed.contains(i128::MIN + 1));
+    assert!(compressed.contains(i128::MAX - 1));
+    assert!(!compressed.contains(1));
+    assert_eq!(compressed.rank(i128::MAX - 1), UIntPlusOne::UInt(3));
+    assert_eq!(compressed.select(UIntPlusOne::UInt(3)), Some(i128::MAX - 1));
+
+    let set = RangeSetBlaze::from_iter([0..=u128::MAX - 1]);
+    let compressed = set.to_elias_fano();
+    assert_eq!(compressed.len(), UIntPlusOne::UInt(u128::MAX));
+    assert_eq!(
+        compressed.select(UIntPlusOne::UInt(u128::MAX - 1)),
+        Some(u128::MAX - 1)
+    );
+    assert_eq!(
+        compressed.rank(u128::MAX - 1),
+        UIntPlusOne::UInt(u128::MAX - 1)
+    );
+    assert!(compressed.ranges().eq(set.ranges()));
+
+    let set = RangeSetBlaze::from_iter([0..=u128::MAX]);
+    let compressed = set.to_elias_fano();
+    assert_eq!(compressed.len(), UIntPlusOne::MaxPlusOne);
+    assert!(compressed.contains(u128::MAX));
+    assert_eq!(compressed.rank(u128::MAX), UIntPlusOne::UInt(u128::MAX));
+    assert_eq!(
+        compressed.select(UIntPlusOne::UInt(u128::MAX)),
+        Some(u128::MAX)
+    );
+    assert_eq!(compressed.select(UIntPlusOne::MaxPlusOne), None);
+    assert!(compressed.ranges().eq(set.ranges()));
+}
+
+#[test]
+fn hybrid_range_set_matches_range_set_blaze() {
+    use rand::Rng;
+    use range_set_blaze::HybridRangeSet;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..20 {
+        let mut hybrid = HybridRangeSet::<i32>::new();
+        let mut expected = RangeSetBlaze::<i32>::new();
+        // Sparse values around chunk boundaries, so chunks switch to bitmaps and back.
+        let center = rng.gen_range(-3..3) << 16;
+        let mut inserted = Vec::new();
+        for step in 0..6000 {
+            let value = center + rng.gen_range(-70_000..70_000);
+            if step < 4000 || rng.gen_bool(0.2) {
+                if rng.gen_bool(0.05) {
+                    let range = value..=value + rng.gen_range(0..200_000);
+                    assert_eq!(
+                        hybrid.ranges_insert(range.clone()),
+                        expected.ranges_insert(range)
+                    );
+                } else {
+                    assert_eq!(hybrid.insert(value), expected.insert(value));
+                }
+                inserted.push(value);
+            } else {
+                let value = inserted.swap_remove(rng.gen_range(0..inserted.len()));
+                assert_eq!(hybrid.remove(value), expected.remove(value));
+            }
+            if step % 500 == 0 {
+                assert_eq!(hybrid.to_range_set_blaze(), expected);
+            }
+        }
+        assert_eq!(hybrid.len(), expected.len());
+        assert_eq!(hybrid.to_range_set_blaze(), expected);
+        for _ in 0..1000 {
+            let value = center + rng.gen_range(-300_000..300_000);
+            assert_eq!(hybrid.contains(value), expected.contains(value));
+        }
+
+        let other: HybridRangeSet<i32> = (0..rng.gen_range(0..3000))
+            .map(|_| center + rng.gen_range(-100_000..100_000))
+            .collect();
+        let other_expected = other.to_range_set_blaze();
+        assert_eq!(
+            (&hybrid | &other).to_range_set_blaze(),
+            &expected | &other_expected
+        );
+        assert_eq!(
+            (&hybrid & &other).to_range_set_blaze(),
+            &expected & &other_expected
+        );
+        assert_eq!(
+            (&hybrid ^ &other).to_range_set_blaze(),
+            &expected ^ &other_expected
+        );
+        assert_eq!(
+            (&hybrid - &other).to_range_set_blaze(),
+            &expected - &other_expected
+        );
+        assert_eq!((!&hybrid).to_range_set_blaze(), !&expected);
+        assert_eq!(HybridRangeSet::from(&expected), hybrid);
+    }
+
+    // A chunk switches to a bitmap and back as its runs cross the thresholds.
+    let mut hybrid: HybridRangeSet<u16> = (0..=u16::MAX).step_by(2).collect();
+    assert_eq!(hybrid.bitmap_chunks_len(), 1);
+    for value in (0..=u16::MAX).step_by(2).skip(400) {
+        hybrid.remove(value);
+    }
+    assert_eq!(hybrid.bitmap_chunks_len(), 0);
+    assert_eq!(hybrid.len(), 400);
+
+    let full = HybridRangeSet::from_iter([0u128..=u128::MAX - 1]);
+    assert_eq!(full.len(), UIntPlusOne::UInt(u128::MAX));
+    assert_eq!(full.to_string(), format!("0..={}", u128::MAX - 1));
+    let full = HybridRangeSet::from_iter([0u128..=u128::MAX]);
+    assert_eq!(full.len(), UIntPlusOne::MaxPlusOne);
+}
+
+#[cfg(feature = "roaring")]
+#[test]
+fn roaring_conversions_match_range_set_blaze() {
+    use rand::Rng;
+    use range_set_blaze::{RoaringRangesIter, RoaringTreemapRangesIter};
+    use roaring::{RoaringBitmap, RoaringTreemap};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..40 {
+        let mut bitmap = RoaringBitmap::new();
+        let mut treemap = RoaringTreemap::new();
+        for _ in 0..rng.gen_range(0..20) {
+            let start = rng.gen_range(0..200_000u32);
+            let end = start.saturating_add(rng.gen_range(0..70_000));
+            bitmap.insert_range(start..=end);
+            let base = u64::from(u32::MAX) - 100_000;
+            treemap.insert_range(base + u64::from(start)..=base + u64::from(end));
+        }
+        if rng.gen_bool(0.2) {
+            bitmap.insert_range(u32::MAX - 5..=u32::MAX);
+            treemap.insert(u64::MAX);
+        }
+
+        let expected = RangeSetBlaze::from_iter(bitmap.iter());
+        let set = RangeSetBlaze::from(&bitmap);
+        assert_eq!(set, expected);
+        assert!(CheckSortedDisjoint::new(RoaringRangesIter::new(&bitmap)).equal(expected.ranges()));
+        assert_eq!(RoaringBitmap::from(&set), bitmap);
+
+        let expected = RangeSetBlaze::from_iter(treemap.iter());
+        let set = RangeSetBlaze::from(&treemap);
+        assert_eq!(set, expected);
+        assert!(
+            CheckSortedDisjoint::new(RoaringTreemapRangesIter::new(&treemap))
+                .equal(expected.ranges())
+        );
+        assert_eq!(RoaringTreemap::from(&set), treemap);
+
+        let other = RangeSetBlaze::from_iter([1000u32..=150_000, 190_000..=u32::MAX - 3]);
+        let bitmap_set = RangeSetBlaze::from(&bitmap);
+        assert_eq!(&other | &bitmap, &other | &bitmap_set);
+        assert_eq!(&other & &bitmap, &other & &bitmap_set);
+        assert_eq!(&other - &bitmap, &other - &bitmap_set);
+        assert_eq!(&other ^ &bitmap, &other ^ &bitmap_set);
+        assert!((!RoaringRangesIter::new(&bitmap)).equal((!&bitmap_set).ranges()));
+
+        let other = RangeSetBlaze::from_iter([u64::from(u32::MAX)..=u64::from(u32::MAX) + 50_000]);
+        let treemap_set = RangeSetBlaze::from(&treemap);
+        assert_eq!(&other | &treemap, &other | &treemap_set);
+        assert_eq!(&other & &treemap, &other & &treemap_set);
+        assert_eq!(&other - &treemap, &other - &treemap_set);
+        assert_eq!(&other ^ &treemap, &other ^ &treemap_set);
+    }
+}
+
+#[cfg(all(feature = "rangemap", feature = "range-collections"))]
+#[test]
+fn rangemap_and_range_collections_match_range_set_blaze() {
+    use rand::Rng;
+    use range_collections::RangeSet2;
+    use range_set_blaze::{RangeCollectionsRangesIter, RangeMapRangesIter};
+    use rangemap::RangeInclusiveSet;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..500 {
+        let ranges: Vec<RangeInclusive<u8>> = (0..rng.gen_range(0..8))
+            .map(|_| {
+                let start = rng.gen::<u8>();
+                start..=start.saturating_add(rng.gen_range(0..40))
+            })
+            .collect();
+        let expected = RangeSetBlaze::from_iter(ranges.iter().cloned());
+        let other = RangeSetBlaze::from_iter([10u8..=60, 200..=255]);
+
+        let legacy = RangeInclusiveSet::from_iter(ranges.iter().cloned());
+        assert_eq!(RangeSetBlaze::from(&legacy), expected);
+        assert_eq!(RangeInclusiveSet::from(&expected), legacy);
+        let iter = CheckSortedDisjoint::new(RangeMapRangesIter::new(&legacy));
+        assert!(iter.equal(expected.ranges()));
+        let union = other.ranges() | RangeMapRangesIter::new(&legacy);
+        assert!(union.equal((&other | &expected).ranges()));
+        let intersection = other.ranges() & RangeMapRangesIter::new(&legacy);
+        assert!(intersection.equal((&other & &expected).ranges()));
+
+        let legacy = ranges.iter().fold(RangeSet2::empty(), |acc, range| {
+            let (start, end) = range.clone().into_inner();
+            let range = match end.checked_add(1) {
+                Some(end) => RangeSet2::from(start..end),
+                None => RangeSet2::from(start..),
+            };
+            acc | range
+        });
+        assert_eq!(RangeSetBlaze::from(&legacy), expected);
+        assert_eq!(RangeSet2::from(&expected), legacy);
+        let iter = CheckSortedDisjoint::new(RangeCollectionsRangesIter::new(&legacy));
+        assert!(iter.equal(expected.ranges()));
+        let difference = RangeCollectionsRangesIter::new(&legacy) - other.ranges();
+        assert!(difference.equal((&expected - &other).ranges()));
+        let symmetric_difference = other.ranges() ^ RangeCollectionsRangesIter::new(&legacy);
+        assert!(symmetric_difference.equal((&other ^ &expected).ranges()));
+    }
+}
+
+#[test]
+fn bitmap_words_round_trip() {
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..500 {
+        let word_len = rng.gen_range(0..6);
+        let words: Vec<u64> = (0..word_len)
+            .map(|_| match rng.gen_range(0..4) {
+                0 => 0,
+                1 => u64::MAX,
+                _ => rng.gen(),
+            })
+            .collect();
+        let base = rng.gen_range(-1000i32..1000);
+        let set = RangeSetBlaze::from_bitmap_words(&words, base);
+        let expected = RangeSetBlaze::from_iter(
+            (0..word_len * 64)
+                .filter(|bit| words[bit / 64] >> (bit % 64) & 1 == 1)
+                .map(|bit| base + bit as i32),
+        );
+        assert_eq!(set, expected);
+        if word_len > 0 {
+            assert_eq!(
+                set.to_bitmap_words(base..base + word_len as i32 * 64),
+                words
+            );
+        }
+
+        let start = rng.gen_range(-1100i32..1100);
+        let end = start + rng.gen_range(0..400);
+        let words = set.to_bitmap_words(start..=end);
+        assert_eq!(words.len(), (end - start) as usize / 64 + 1);
+        let bounds = RangeSetBlaze::from_iter([start..=end]);
+        assert_eq!(
+            RangeSetBlaze::from_bitmap_words(&words, start),
+            &set & &bounds
+        );
+    }
+
+    let set = RangeSetBlaze::from_bitmap_words(&[u64::MAX; 4], 0u8);
+    assert_eq!(set, RangeSetBlaze::from_iter([0u8..=255]));
+    assert_eq!(set.to_bitmap_words(..), [u64::MAX; 4]);
+    let result =
+        std::panic::catch_unwind(|| RangeSetBlaze::from_bitmap_words(&[0, 0, 0, 0, 1], 0u8));
+    assert!(result.is_err());
+    assert!(RangeSetBlaze::from_bitmap_words(&[0, 0, 0, 0, 0], 0u8).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "bounds must hold at most usize::MAX values")]
+fn bitmap_words_full_u128_bounds() {
+    let _ = RangeSetBlaze::from_iter([5u128..=7]).to_bitmap_words(..);
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn bit_slice_round_trip() {
+    use bitvec::prelude::*;
+    use rand::Rng;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..500 {
+        let bits: BitVec<u8, Msb0> = (0..rng.gen_range(0..300))
+            .map(|_| rng.gen_bool(0.7))
+            .collect();
+        let base = rng.gen_range(0..1000u64);
+        let set = RangeSetBlaze::from_bit_slice(&bits, base);
+        let expected = RangeSetBlaze::from_iter(bits.iter_ones().map(|bit| base + bit as u64));
+        assert_eq!(set, expected);
+        if !bits.is_empty() {
+            let round_trip = set.to_bit_vec(base..base + bits.len() as u64);
+            assert_eq!(round_trip, bits);
+        }
+    }
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+#[should_panic(expected = "bounds must hold at most usize::MAX values")]
+fn bit_vec_full_u128_bounds() {
+    let _ = RangeSetBlaze::from_iter([5u128..=7]).to_bit_vec(..);
+}
+
+#[cfg(feature = "arrow")]
+#[test]
+fn arrow_columns_match_range_set_blaze() {
+    use arrow_array::{Array, ArrayRef, Int32Array, RecordBatch, StructArray};
+    use rand::Rng;
+    use range_set_blaze::{ArrowRangeError, ArrowRangesIter, NullPolicy};
+    use std::sync::Arc;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let rows: Vec<(Option<i32>, Option<i32>)> = (0..rng.gen_range(0..20))
+            .map(|_| {
+                let start = rng.gen_range(-500..500);
+                let end = start + rng.gen_range(-5..50);
+                (
+                    (!rng.gen_bool(0.1)).then_some(start),
+                    (!rng.gen_bool(0.1)).then_some(end),
+                )
+            })
+            .collect();
+        let starts = Int32Array::from(rows.iter().map(|row| row.0).collect::<Vec<_>>());
+        let ends = Int32Array::from(rows.iter().map(|row| row.1).collect::<Vec<_>>());
+
+        let skip = RangeSetBlaze::from_iter(rows.iter().filter_map(|row| Some(row.0?..=row.1?)));
+        let unbounded = RangeSetBlaze::from_iter(
+            rows.iter()
+                .map(|row| row.0.unwrap_or(i32::MIN)..=row.1.unwrap_or(i32::MAX)),
+        );
+        assert_eq!(
+            RangeSetBlaze::from_arrow_arrays(&starts, &ends, NullPolicy::Skip),
+            Ok(skip.clone())
+        );
+        assert_eq!(
+            RangeSetBlaze::from_arrow_arrays(&starts, &ends, NullPolicy::Unbounded),
+            Ok(unbounded)
+        );
+        let first_null = rows
+            .iter()
+            .position(|row| row.0.is_none() || row.1.is_none());
+        let strict = RangeSetBlaze::from_arrow_arrays(&starts, &ends, NullPolicy::Error);
+        match first_null {
+            Some(row) => assert_eq!(strict, Err(ArrowRangeError::Null { row })),
+            None => assert_eq!(strict, Ok(skip.clone())),
+        }
+
+        let (set_starts, set_ends) = skip.to_arrow_arrays();
+        assert_eq!(
+            RangeSetBlaze::from_arrow_arrays(&set_starts, &set_ends, NullPolicy::Error),
+            Ok(skip.clone())
+        );
+        let array = skip.to_arrow_struct_array();
+        assert_eq!(array.len(), skip.ranges_len());
+        assert_eq!(
+            RangeSetBlaze::from_arrow_struct_array(&array, NullPolicy::Error),
+            Ok(skip.clone())
+        );
+
+        let batch = RecordBatch::from(array);
+        let other = RangeSetBlaze::from_iter([-100..=100, 300..=400]);
+        let rows = ArrowRangesIter::from_record_batch(&batch, "start", "end").unwrap();
+        assert!((rows | other.ranges()).equal((&skip | &other).ranges()));
+        let rows = ArrowRangesIter::try_new(&set_starts, &set_ends).unwrap();
+        assert!((rows - other.ranges()).equal((&skip - &other).ranges()));
+    }
+
+    let starts = Int32Array::from(vec![1, 2]);
+    let ends = Int32Array::from(vec![3]);
+    assert_eq!(
+        RangeSetBlaze::<i32>::from_arrow_arrays(&starts, &ends, NullPolicy::Skip),
+        Err(ArrowRangeError::LengthMismatch { starts: 2, ends: 1 })
+    );
+    let batch = RecordBatch::try_from_iter([("start", Arc::new(starts) as ArrayRef)]).unwrap();
+    assert_eq!(
+        ArrowRangesIter::<i32>::from_record_batch(&batch, "start", "end").err(),
+        Some(ArrowRangeError::MissingColumn("end".to_string()))
+    );
+    assert_eq!(
+        ArrowRangesIter::<i64>::from_record_batch(&batch, "start", "start").err(),
+        Some(ArrowRangeError::ColumnType("start".to_string()))
+    );
+    let array = StructArray::from(batch);
+    assert_eq!(
+        RangeSetBlaze::<i32>::from_arrow_struct_array(&array, NullPolicy::Skip),
+        Err(ArrowRangeError::MissingColumn("end".to_string()))
+    );
+}
+
+#[test]
+fn cidrs_cover_set_minimally() {
+    use rand::Rng;
+    use range_set_blaze::{Cidr, CidrError};
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..500 {
+        let set = RangeSetBlaze::from_iter((0..rng.gen_range(0..6)).map(|_| {
+            let start = rng.gen::<u32>();
+            let len_bits = rng.gen_range(0..20);
+            start..=start.saturating_add(rng.gen_range(0..1 << len_bits))
+        }));
+        let cidrs: Vec<Cidr<u32>> = set.to_cidrs().collect();
+        assert_eq!(RangeSetBlaze::<u32>::from_cidrs(cidrs.iter().copied()), set);
+        for (a, b) in cidrs.iter().tuple_windows() {
+            assert!(a.range().end() < b.range().start());
+            // Two sibling blocks would make one block with a shorter prefix.
+            let siblings = a.prefix_len() == b.prefix_len()
+                && a.prefix_len() > 0
+                && Cidr::new(a.network(), a.prefix_len() - 1).is_ok()
+                && a.range().end() + 1 == *b.range().start();
+            assert!(!siblings, "{a} and {b} should be one block");
+        }
+        for cidr in &cidrs {
+            assert_eq!(cidr.to_string().parse::<Cidr<u32>>(), Ok(*cidr));
+        }
+    }
+
+    let all = RangeSetBlaze::from_iter([0u32..=u32::MAX]);
+    assert_eq!(
+        all.to_cidrs().map(|cidr| cidr.to_string()).collect_vec(),
+        ["0.0.0.0/0"]
+    );
+    let set = RangeSetBlaze::from_iter([1u128..=u128::MAX - 1]);
+    assert_eq!(set.to_cidrs().count(), 254);
+    let first: Cidr<u128> = "::1/128".parse().unwrap();
+    assert_eq!(set.to_cidrs().next(), Some(first));
+    let set = RangeSetBlaze::<u128>::from_cidrs(["2001:db8::/32".parse::<Cidr<u128>>().unwrap()]);
+    assert_eq!(set.len(), UIntPlusOne::UInt(1u128 << 96));
+
+    for bad in [
+        "10.0.0.0",
+        "10.0.0.0/",
+        "10.0.0.0/+8",
+        "10.0.0/8",
+        "::/8",
+        "10.0.0.0/8/8",
+    ] {
+        assert_eq!(bad.parse::<Cidr<u32>>(), Err(CidrError::Syntax), "{bad}");
+    }
+    assert_eq!(
+        "10.0.0.1/8".parse::<Cidr<u32>>(),
+        Err(CidrError::HostBitsSet)
+    );
+    assert_eq!(
+        "10.0.0.0/33".parse::<Cidr<u32>>(),
+        Err(CidrError::PrefixTooLong { prefix_len: 33 })
+    );
+    assert_eq!(
+        "::/129".parse::<Cidr<u128>>(),
+        Err(CidrError::PrefixTooLong { prefix_len: 129 })
+    );
+}
+
+#[test]
+fn ipv4_addr_cidrs_match_u32_cidrs() {
+    use rand::Rng;
+    use range_set_blaze::Cidr;
+    use std::net::Ipv4Addr;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let set = RangeSetBlaze::from_iter((0..rng.gen_range(0..6)).map(|_| {
+            let start = rng.gen::<u32>();
+            let len_bits = rng.gen_range(0..20);
+            start..=start.saturating_add(rng.gen_range(0..1 << len_bits))
+        }));
+        let addrs: RangeSetBlaze<Ipv4Addr> = set
+            .ranges()
+            .map(|range| Ipv4Addr::from(*range.start())..=Ipv4Addr::from(*range.end()))
+            .collect();
+        let cidrs: Vec<Cidr<u32>> = addrs.to_cidrs().collect();
+        assert_eq!(cidrs, set.to_cidrs().collect_vec());
+        assert_eq!(RangeSetBlaze::<Ipv4Addr>::from_cidrs(cidrs), addrs);
+    }
+
+    let all = RangeSetBlaze::from_iter([Ipv4Addr::UNSPECIFIED..=Ipv4Addr::BROADCAST]);
+    assert_eq!(
+        all.to_cidrs().map(|cidr| cidr.to_string()).collect_vec(),
+        ["0.0.0.0/0"]
+    );
+    let cidr: Cidr<u32> = "192.168.0.0/16".parse().unwrap();
+    let set = RangeSetBlaze::<Ipv4Addr>::from_cidrs([cidr]);
+    assert_eq!(set.to_string(), "192.168.0.0..=192.168.255.255");
+}
+
+#[test]
+fn ipv6_addr_cidrs_match_u128_cidrs() {
+    use rand::Rng;
+    use range_set_blaze::Cidr;
+    use std::net::Ipv6Addr;
+
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..200 {
+        let set = RangeSetBlaze::from_iter((0..rng.gen_range(0..6)).map(|_| {
+            let start = rng.gen::<u128>();
+            let len_bits = rng.gen_range(0..100);
+            start..=start.saturating_add(rng.gen_range(0..1 << len_bits))
+        }));
+        let addrs: RangeSetBlaze<Ipv6Addr> = set
+            .ranges()
+            .map(|range| Ipv6Addr::from(*range.start())..=Ipv6Addr::from(*range.end()))
+            .collect();
+        let cidrs: Vec<Cidr<u128>> = addrs.to_cidrs().collect();
+        assert_eq!(cidrs, set.to_cidrs().collect_vec());
+        assert_eq!(RangeSetBlaze::<Ipv6Addr>::from_cidrs(cidrs), addrs);
+    }
+
+    let all = RangeSetBlaze::from_iter([Ipv6Addr::UNSPECIFIED..=Ipv6Addr::from(u128::MAX)]);
+    assert_eq!(
+        all.to_cidrs().map(|cidr| cidr.to_string()).collect_vec(),
+        ["::/0"]
+    );
+    let cidr: Cidr<u128> = "2001:db8::/32".parse().unwrap();
+    let set = RangeSetBlaze::<Ipv6Addr>::from_cidrs([cidr]);
+    assert_eq!(set.to_string(), "2001:db8::..=2001:db8:ffff:ffff:ffff:ffff:ffff:ffff");
+}
+
+#[test]
+fn ip_address_sets() {
+    use range_set_blaze::ParseRangeSetError;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    let set = RangeSetBlaze::from_iter([
+        Ipv4Addr::new(1, 2, 3, 4)..=Ipv4Addr::new(1, 2, 3, 255),
+        Ipv4Addr::new(1, 2, 4, 0)..=Ipv4Addr::new(1, 2, 4, 9),
+        Ipv4Addr::new(10, 0, 0, 0)..=Ipv4Addr::new(10, 0, 0, 0),
+    ]);
+    assert_eq!(set.to_string(), "1.2.3.4..=1.2.4.9, 10.0.0.0..=10.0.0.0");
+    assert_eq!(set.len(), 263);
+    assert_eq!(
+        set.to_string().parse::<RangeSetBlaze<Ipv4Addr>>(),
+        Ok(set.clone())
+    );
+    assert_eq!(
+        (!set.clone()).to_string(),
+        "0.0.0.0..=1.2.3.3, 1.2.4.10..=9.255.255.255, 10.0.0.1..=255.255.255.255"
+    );
+    assert!(set.contains(Ipv4Addr::new(1, 2, 3, 200)));
+    assert_eq!(set.first(), Some(Ipv4Addr::new(1, 2, 3, 4)));
+    assert_eq!(set.iter().nth(262), Some(Ipv4Addr::new(10, 0, 0, 0)));
+    let everything = RangeSetBlaze::from_iter([Ipv4Addr::UNSPECIFIED..=Ipv4Addr::BROADCAST]);
+    assert_eq!(everything.len(), 1 << 32);
+    assert!((!everything.clone()).is_empty());
+
+    // Every IPv6 address can be used, and the full range has 2^128 addresses.
+    let max = Ipv6Addr::from(u128::MAX);
+    let everything = RangeSetBlaze::from_iter([Ipv6Addr::UNSPECIFIED..=max]);
+    assert_eq!(everything.len(), UIntPlusOne::MaxPlusOne);
+    assert_eq!(
+        everything.to_string(),
+        "::..=ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff"
+    );
+    assert_eq!(
+        everything.to_string().parse::<RangeSetBlaze<Ipv6Addr>>(),
+        Ok(everything.clone())
+    );
+    assert!((!everything.clone()).is_empty());
+    let top = RangeSetBlaze::from_iter([max]);
+    assert_eq!(top.len(), UIntPlusOne::UInt(1));
+    assert_eq!(top.last(), Some(max));
+    assert_eq!((&everything - &top).len(), UIntPlusOne::UInt(u128::MAX));
+    assert_eq!(!(&everything - &top), top);
+    assert_eq!(&everything ^ &top, !top.clone());
+    let mut set = RangeSetBlaze::from_iter([Ipv6Addr::LOCALHOST]);
+    set |= top.clone();
+    assert_eq!(set.to_string(), format!("::1..=::1, {max}..={max}"));
+    assert_eq!(set.pop_last(), Some(max));
+    assert_eq!(set.len(), UIntPlusOne::UInt(1));
+
+    assert_eq!(
+        "1.2.3.4..=1.2.3".parse::<RangeSetBlaze<Ipv4Addr>>(),
+        Err(ParseRangeSetError::Element("1.2.3".to_string()))
+    );
+    assert_eq!(
+        "1.2.3.4..=1.2.3.3".parse::<RangeSetBlaze<Ipv4Addr>>(),
+        Err(ParseRangeSetError::Backwards(
+            "1.2.3.4..=1.2.3.3".to_string()
+        ))
+    );
+    assert_eq!(
+        u128::MAX.to_string().parse::<RangeSetBlaze<u128>>(),
+        Ok(RangeSetBlaze::from_iter([u128::MAX]))
+    );
+    assert_eq!(
+        "".parse::<RangeSetBlaze<Ipv4Addr>>(),
+        Ok(RangeSetBlaze::new())
+    );
+}
+
+#[test]
+fn char_sets_skip_surrogates() {
+    let before = '\u{D7FF}';
+    let after = '\u{E000}';
+    let set = RangeSetBlaze::from_iter(['\u{D700}'..=before, after..='\u{E0FF}']);
+    assert_eq!(set.ranges_len(), 1);
+    assert_eq!(set.len(), 0x200);
+    assert_eq!(set.iter().nth(0xFF), Some(before));
+    assert_eq!(set.iter().nth(0x100), Some(after));
+    assert_eq!(set.iter().rev().nth(0x100), Some(before));
+    assert_eq!(RangeSetBlaze::from_iter([before, after]).ranges_len(), 1);
+    let mut set2 = RangeSetBlaze::from_iter([after]);
+    set2.insert(before);
+    assert_eq!(set2.ranges_len(), 1);
+    assert_eq!(set2.pop_first(), Some(before));
+    assert_eq!(set2.ranges_len(), 1);
+    assert_eq!(set2.first(), Some(after));
+
+    let everything = RangeSetBlaze::from_iter(['\0'..=char::MAX]);
+    assert_eq!(everything.len(), 0x11_0000 - 0x800);
+    assert_eq!(everything.to_string(), r"'\0'..='\u{10ffff}'");
+    assert!((!everything.clone()).is_empty());
+
+    let classes = RangeSetBlaze::from_iter(['a'..='z', '\n'..='\n', '\u{7f}'..='\u{7f}']);
+    assert_eq!(
+        classes.to_string(),
+        r"'\n'..='\n', 'a'..='z', '\u{7f}'..='\u{7f}'"
+    );
+    let not_classes = !classes.clone();
+    assert_eq!(
+        not_classes.to_string(),
+        r"'\0'..='\t', '\u{b}'..='`', '{'..='~', '\u{80}'..='\u{10ffff}'"
+    );
+    assert_eq!(&not_classes | &classes, everything);
+    let surrogate_side = RangeSetBlaze::from_iter([after..=char::MAX]);
+    assert_eq!((!surrogate_side).to_string(), r"'\0'..='\u{d7ff}'");
+    assert_eq!(
+        (classes.ranges() | CheckSortedDisjoint::from([before..=after])).to_string(),
+        r"'\n'..='\n', 'a'..='z', '\u{7f}'..='\u{7f}', '\u{d7ff}'..='\u{e000}'"
+    );
+}
+
+#[test]
+fn newtype_elements() {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Day(i32);
+
+    impl Element for Day {
+        type SafeLen = <i32 as Element>::SafeLen;
+
+        fn safe_len(range: &RangeInclusive<Self>) -> Self::SafeLen {
+            i32::safe_len(&(range.start().0..=range.end().0))
+        }
+        fn min_value() -> Self {
+            Self(i32::MIN)
+        }
+        fn max_value() -> Self {
+            Self(i32::MAX)
+        }
+        fn add_one(self) -> Self {
+            Self(self.0 + 1)
+        }
+        fn sub_one(self) -> Self {
+            Self(self.0 - 1)
+        }
+    }
+
+    let work = RangeSetBlaze::from_iter([Day(1)..=Day(5), Day(8)..=Day(12), Day(6)..=Day(6)]);
+    let leave = RangeSetBlaze::from_iter([Day(3)..=Day(4), Day(12)..=Day(12)]);
+    assert_eq!(work.to_string(), "Day(1)..=Day(6), Day(8)..=Day(12)");
+    assert_eq!(
+        (&work - &leave).to_string(),
+        "Day(1)..=Day(2), Day(5)..=Day(6), Day(8)..=Day(11)"
+    );
+    assert_eq!((&work - &leave).len(), 8);
+    assert_eq!((!&work).first(), Some(Day(i32::MIN)));
+    assert_eq!(work.iter().rev().nth(5), Some(Day(6)));
+    assert_eq!(
+        (work.ranges() & leave.ranges()).to_string(),
+        "Day(3)..=Day(4), Day(12)..=Day(12)"
+    );
+}
+
+#[test]
+fn full_range_128_bit() {
+    let everything = RangeSetBlaze::from_iter([0..=u128::MAX]);
+    assert_eq!(everything.len(), UIntPlusOne::MaxPlusOne);
+    assert!((!everything.clone()).is_empty());
+    assert_eq!(!RangeSetBlaze::<u128>::new(), everything);
+    let top = RangeSetBlaze::from_iter([u128::MAX]);
+    assert_eq!(!&top, RangeSetBlaze::from_iter([0..=u128::MAX - 1]));
+    assert_eq!((!&top).len(), UIntPlusOne::UInt(u128::MAX));
+    assert_eq!(everything.iter().next_back(), Some(u128::MAX));
+    assert_eq!(everything.iter().rev().nth(1), Some(u128::MAX - 1));
+
+    let mut set = RangeSetBlaze::from_iter([0..=u128::MAX - 1]);
+    assert!(set.insert(u128::MAX));
+    assert_eq!(set, everything);
+    assert_eq!(set.pop_last(), Some(u128::MAX));
+    assert_eq!(set.len(), UIntPlusOne::UInt(u128::MAX));
+
+    let everything = RangeSetBlaze::from_iter([i128::MIN..=i128::MAX]);
+    assert_eq!(everything.len(), UIntPlusOne::MaxPlusOne);
+    assert!((!everything.clone()).is_empty());
+    assert!(everything.contains(i128::MAX));
+    let halves =
+        RangeSetBlaze::from_iter([i128::MIN..=-1]) | RangeSetBlaze::from_iter([0..=i128::MAX]);
+    assert_eq!(halves, everything);
+    assert_eq!(
+        everything.to_string().parse::<RangeSetBlaze<i128>>(),
+        Ok(everything.clone())
+    );
+
+    let max = UIntPlusOne::<u128>::MaxPlusOne;
+    assert_eq!(max / UIntPlusOne::UInt(2), UIntPlusOne::UInt(1 << 127));
+    assert_eq!(max % UIntPlusOne::UInt(3), UIntPlusOne::UInt(1));
+    assert_eq!(max.to_string(), "340282366920938463463374607431768211456");
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn fallible_construction() {
+    use range_set_blaze::RangeSetError;
+
+    let a = RangeSetBlaze::try_from_iter([5..=6, 1..=2, 3..=4]).unwrap();
+    assert_eq!(a, RangeSetBlaze::from_iter([1..=6]));
+    let mut a = a;
+    assert_eq!(a.try_insert(7), Ok(true));
+    assert_eq!(a.try_insert(7), Ok(false));
+    assert_eq!(a.try_ranges_insert(10..=12), Ok(true));
+    let empty = 12..=10;
+    assert_eq!(a.try_ranges_insert(empty), Ok(false));
+    assert_eq!(a.to_string(), "1..=7, 10..=12");
+
+    let a = CheckSortedDisjoint::try_new([1..=2, 4..=5]).unwrap();
+    assert_eq!(a.to_string(), "1..=2, 4..=5");
+    assert!(
+        CheckSortedDisjoint::try_new(Vec::<RangeInclusive<u8>>::new())
+            .unwrap()
+            .is_empty()
+    );
+    for (ranges, expected) in [
+        (
+            vec![1..=2, 3..=5],
+            RangeSetError::NotSortedDisjoint {
+                prev_end: 2,
+                range: 3..=5,
+            },
+        ),
+        (
+            vec![4..=5, 1..=2],
+            RangeSetError::NotSortedDisjoint {
+                prev_end: 5,
+                range: 1..=2,
+            },
+        ),
+        (
+            vec![1..=2, 255..=255, 0..=0],
+            RangeSetError::NotSortedDisjoint {
+                prev_end: 255,
+                range: 0..=0,
+            },
+        ),
+        (vec![1..=2, 6..=5], RangeSetError::Backwards(6..=5)),
+    ] {
+        assert_eq!(
+            CheckSortedDisjoint::<u8, _>::try_new(ranges).unwrap_err(),
+            expected
+        );
+    }
+    let err = CheckSortedDisjoint::try_new([6..=5]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "range 6..=5 has a start greater than its end"
+    );
+    let result = std::panic::catch_unwind(|| CheckSortedDisjoint::new([1..=2, 2..=3]).count());
+    assert!(result.is_err());
+
+    // A custom element type whose length type can't count its full range.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Small(u8);
+
+    impl Element for Small {
+        type SafeLen = u8;
+
+        fn safe_len(range: &RangeInclusive<Self>) -> u8 {
+            range.end().0 - range.start().0 + 1
+        }
+        fn min_value() -> Self {
+            Self(0)
+        }
+        fn max_value() -> Self {
+            Self(u8::MAX)
+        }
+        fn safe_max_value() -> Self {
+            Self(u8::MAX - 1)
+        }
+        fn add_one(self) -> Self {
+            Self(self.0 + 1)
+        }
+        fn sub_one(self) -> Self {
+            Self(self.0 - 1)
+        }
+    }
+
+    let top = Small(u8::MAX);
+    assert_eq!(
+        RangeSetBlaze::try_from_iter([Small(0)..=Small(3), Small(250)..=top]),
+        Err(RangeSetError::TooLarge(top))
+    );
+    let empty = top..=Small(1);
+    let mut set = RangeSetBlaze::try_from_iter([Small(0)..=Small(3), empty.clone()]).unwrap();
+    assert_eq!(set.try_insert(top), Err(RangeSetError::TooLarge(top)));
+    assert_eq!(
+        set.try_ranges_insert(Small(9)..=top),
+        Err(RangeSetError::TooLarge(top))
+    );
+    assert_eq!(set.try_ranges_insert(empty), Ok(false));
+    assert_eq!(set.len(), 4);
+    assert_eq!(
+        CheckSortedDisjoint::try_new([Small(0)..=top]).unwrap_err(),
+        RangeSetError::TooLarge(top)
+    );
+    assert_eq!(
+        RangeSetError::TooLarge(top).to_string(),
+        "Small(255) is greater than the largest usable value"
+    );
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn validate_sorted_disjoint() {
+    use range_set_blaze::{RangeSetError, ValidateSortedDisjoint};
+
+    let valid: Vec<_> = ValidateSortedDisjoint::new([1..=2, 4..=5, 10..=10]).collect();
+    assert_eq!(valid, vec![Ok(1..=2), Ok(4..=5), Ok(10..=10)]);
+
+    for (ranges, index, previous, reason) in [
+        (
+            vec![1..=2, 3..=5],
+            1,
+            Some(1..=2),
+            RangeSetError::NotSortedDisjoint {
+                prev_end: 2,
+                range: 3..=5,
+            },
+        ),
+        (
+            vec![1..=2, 4..=5, 0..=0],
+            2,
+            Some(4..=5),
+            RangeSetError::NotSortedDisjoint {
+                prev_end: 5,
+                range: 0..=0,
+            },
+        ),
+        (vec![6..=5], 0, None, RangeSetError::Backwards(6..=5)),
+        (
+            vec![0..=255u8, 0..=0],
+            1,
+            Some(0..=255),
+            RangeSetError::NotSortedDisjoint {
+                prev_end: 255,
+                range: 0..=0,
+            },
+        ),
+    ] {
+        let range = ranges[index].clone();
+        let mut iter = ValidateSortedDisjoint::new(ranges);
+        for _ in 0..index {
+            assert!(iter.next().unwrap().is_ok());
+        }
+        let err = iter.next().unwrap().unwrap_err();
+        assert_eq!(err.index(), index);
+        assert_eq!(err.previous(), previous);
+        assert_eq!(err.range(), range);
+        assert_eq!(err.reason(), &reason);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    // The combinators agree with the panicking versions on valid input.
+    let mut rng = StdRng::seed_from_u64(0);
+    for _ in 0..100 {
+        let [a, b]: [RangeSetBlaze<i32>; 2] = k_sets(2, 5, &(0..=200), 0.5, How::None, &mut rng)
+            .try_into()
+            .unwrap();
+        let union = ValidateSortedDisjoint::new(a.ranges())
+            .try_union(ValidateSortedDisjoint::new(b.ranges()))
+            .unwrap();
+        assert_eq!(union, &a | &b);
+        let intersection = ValidateSortedDisjoint::new(a.ranges())
+            .try_intersection(ValidateSortedDisjoint::new(b.ranges()))
+            .unwrap();
+        assert_eq!(intersection, &a & &b);
+    }
+
+    // Errors in either input are reported, even after the result is known.
+    let err = ValidateSortedDisjoint::new([1..=2, 20..=30])
+        .try_union(ValidateSortedDisjoint::new([5..=6, 5..=9]))
+        .unwrap_err();
+    assert_eq!(err.index(), 1);
+    assert_eq!(err.range(), 5..=9);
+    let err = ValidateSortedDisjoint::new([1..=2])
+        .try_intersection(ValidateSortedDisjoint::new([5..=6, 100..=200, 150..=160]))
+        .unwrap_err();
+    assert_eq!(err.index(), 2);
+    assert_eq!(err.previous(), Some(100..=200));
+    let err = ValidateSortedDisjoint::new([1..=2, 0..=0])
+        .try_intersection(ValidateSortedDisjoint::new(Vec::new()))
+        .unwrap_err();
+    assert_eq!(err.range(), 0..=0);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn check_sorted_starts() {
+    use range_set_blaze::RangeSetError;
+
+    let a = CheckSortedStarts::new([1..=5, 1..=2, 3..=9, 20..=20]);
+    assert_eq!(
+        RangeSetBlaze::from_sorted_starts(a),
+        RangeSetBlaze::from_iter([1..=9, 20..=20])
+    );
+    let a = CheckSortedStarts::try_new([1..=5, 1..=2, 6..=7]).unwrap();
+    assert_eq!(a.into_range_set_blaze().to_string(), "1..=7");
+    assert_eq!(
+        CheckSortedStarts::try_new([1..=5, 0..=7]).unwrap_err(),
+        RangeSetError::NotSortedStarts {
+            prev_start: 1,
+            range: 0..=7
+        }
+    );
+    assert_eq!(
+        CheckSortedStarts::try_new([1..=5, 3..=2]).unwrap_err(),
+        RangeSetError::Backwards(3..=2)
+    );
+    let result = std::panic::catch_unwind(|| CheckSortedStarts::new([4..=5, 3..=3]).count());
+    assert!(result.is_err());
+}
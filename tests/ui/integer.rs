@@ -2,7 +2,7 @@ use range_set_blaze::RangeSetBlaze;
 
 fn _some_fn() {
     let _integer_set = RangeSetBlaze::from_iter([1, 2, 3, 5]);
-    let _char_set = RangeSetBlaze::from_iter(['a', 'b', 'c', 'd']);
+    let _float_set = RangeSetBlaze::from_iter([1.0, 2.0, 3.0, 5.0]);
 }
 
 fn main() {}
@@ -5,13 +5,13 @@ wasm_bindgen_test_configure!(run_in_browser);
 use core::ops::RangeInclusive;
 use itertools::Itertools;
 use range_set_blaze::{
-    prelude::*, AssumeSortedStarts, Integer, NotIter, RangesIter, SortedStarts, UnionIter,
+    prelude::*, AssumeSortedStarts, Element, Integer, NotIter, RangesIter, SortedStarts, UnionIter,
 };
 use std::cmp::Ordering;
 use std::{collections::BTreeSet, ops::BitOr};
 use syntactic_for::syntactic_for;
 
-type I32SafeLen = <i32 as range_set_blaze::Integer>::SafeLen;
+type I32SafeLen = <i32 as range_set_blaze::Element>::SafeLen;
 
 #[wasm_bindgen_test]
 fn insert_255u8() {
@@ -928,10 +928,10 @@ fn not_iter_example() {
 
 #[wasm_bindgen_test]
 fn len_demo() {
-    let len: <u8 as Integer>::SafeLen = RangeSetBlaze::from_iter([0u8..=255]).len();
+    let len: <u8 as Element>::SafeLen = RangeSetBlaze::from_iter([0u8..=255]).len();
     assert_eq!(len, 256);
 
-    assert_eq!(<u8 as Integer>::safe_len(&(0..=255)), 256);
+    assert_eq!(<u8 as Element>::safe_len(&(0..=255)), 256);
 }
 
 #[wasm_bindgen_test]